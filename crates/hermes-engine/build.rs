@@ -23,7 +23,14 @@ fn main() {
     cmake_config
         .generator("Ninja")
         .define("CMAKE_BUILD_TYPE", "Release")
-        .define("HERMES_ENABLE_DEBUGGER", "OFF")
+        .define(
+            "HERMES_ENABLE_DEBUGGER",
+            if cfg!(feature = "debugger") {
+                "ON"
+            } else {
+                "OFF"
+            },
+        )
         .define("HERMES_ENABLE_INTL", "OFF")
         .define("HERMES_BUILD_APPLE_FRAMEWORK", "OFF")
         .define("HERMES_BUILD_SHARED_JSI", "OFF")
@@ -49,8 +56,22 @@ fn main() {
     let installed_include = dst.join("include");
     let hermes_public_include = hermes_src.join("include");
 
-    // Build the cxx bridge (header-only, no C++ files to compile)
-    cxx_build::bridge("src/bridge.rs")
+    // Build the cxx bridge (wrapper.h contains inline functions, bridge.cc the Rust callback glue)
+    let mut bridges = vec!["src/bridge.rs"];
+    if cfg!(feature = "debugger") {
+        bridges.push("src/debugger/bridge.rs");
+        bridges.push("src/inspector/bridge.rs");
+    }
+    let mut bridge_build = cxx_build::bridges(bridges);
+    bridge_build.file("src/bridge.cc");
+    if cfg!(feature = "debugger") {
+        // Debugger APIs in the Hermes headers are only declared with the define
+        bridge_build
+            .file("src/debugger/bridge.cc")
+            .file("src/inspector/bridge.cc")
+            .define("HERMES_ENABLE_DEBUGGER", None);
+    }
+    bridge_build
         .include(&hermes_api_include)
         .include(&jsi_include)
         .include(&installed_include)
@@ -179,6 +200,20 @@ fn main() {
         println!("cargo:rustc-link-lib=static=compileJS");
     }
 
+    // CDP agent, built with the debugger
+    if cfg!(feature = "debugger") {
+        let cdp_dir = build_dir.join("API/hermes/cdp");
+        if let Ok(entries) = std::fs::read_dir(&cdp_dir) {
+            println!("cargo:rustc-link-search=native={}", cdp_dir.display());
+            for entry in entries.flatten() {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                if let Some(lib) = name.strip_prefix("lib").and_then(|n| n.strip_suffix(".a")) {
+                    println!("cargo:rustc-link-lib=static={lib}");
+                }
+            }
+        }
+    }
+
     // Try to link the full Hermes compiler if available
     if build_dir.join("lib/libhermescompiler.a").exists() {
         println!("cargo:rustc-link-lib=static=hermescompiler");
@@ -229,4 +264,11 @@ fn main() {
 
     // Rebuild if bridge files change
     println!("cargo:rerun-if-changed=src/bridge.rs");
+    println!("cargo:rerun-if-changed=src/bridge.cc");
+    println!("cargo:rerun-if-changed=src/debugger/bridge.rs");
+    println!("cargo:rerun-if-changed=src/debugger/bridge.cc");
+    println!("cargo:rerun-if-changed=src/debugger/bridge.h");
+    println!("cargo:rerun-if-changed=src/inspector/bridge.rs");
+    println!("cargo:rerun-if-changed=src/inspector/bridge.cc");
+    println!("cargo:rerun-if-changed=src/inspector/bridge.h");
 }
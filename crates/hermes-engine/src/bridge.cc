@@ -0,0 +1,163 @@
+#include "hermes-engine/src/bridge.rs.h"
+
+#include <atomic>
+#include <mutex>
+#include <ostream>
+#include <streambuf>
+#include <vector>
+
+namespace {
+
+// Stream buffer handing filled chunks to a Rust writer
+class SinkStreamBuf : public std::streambuf {
+public:
+    explicit SinkStreamBuf(WriteSink& sink) : sink_(sink), buffer_(64 * 1024) {
+        setp(buffer_.data(), buffer_.data() + buffer_.size());
+    }
+
+protected:
+    int_type overflow(int_type ch) override {
+        if (sync() != 0) {
+            return traits_type::eof();
+        }
+        if (!traits_type::eq_int_type(ch, traits_type::eof())) {
+            *pptr() = traits_type::to_char_type(ch);
+            pbump(1);
+        }
+        return traits_type::not_eof(ch);
+    }
+
+    int sync() override {
+        auto size = static_cast<size_t>(pptr() - pbase());
+        if (size > 0) {
+            rust::Slice<const uint8_t> chunk(reinterpret_cast<const uint8_t*>(pbase()), size);
+            if (!write_sink_write(sink_, chunk)) {
+                return -1;
+            }
+        }
+        setp(buffer_.data(), buffer_.data() + buffer_.size());
+        return 0;
+    }
+
+private:
+    WriteSink& sink_;
+    std::vector<char> buffer_;
+};
+
+// CrashManager forwarding to a Rust crash::CrashManager
+class RustCrashManager : public hermes::vm::CrashManager {
+public:
+    explicit RustCrashManager(rust::Box<CrashManagerBox> manager) : manager_(std::move(manager)) {}
+
+    void registerMemory(void* mem, size_t length) override {
+        crash_manager_register_memory(*manager_, reinterpret_cast<size_t>(mem), length);
+    }
+
+    void unregisterMemory(void* mem) override {
+        crash_manager_unregister_memory(*manager_, reinterpret_cast<size_t>(mem));
+    }
+
+    void setCustomData(const char* key, const char* val) override {
+        crash_manager_set_custom_data(*manager_, bytes(key), bytes(val), false);
+    }
+
+    void removeCustomData(const char* key) override {
+        crash_manager_remove_custom_data(*manager_, bytes(key), false);
+    }
+
+    void setContextualCustomData(const char* key, const char* val) override {
+        crash_manager_set_custom_data(*manager_, bytes(key), bytes(val), true);
+    }
+
+    void removeContextualCustomData(const char* key) override {
+        crash_manager_remove_custom_data(*manager_, bytes(key), true);
+    }
+
+    CallbackKey registerCallback(CallbackFunc callback) override {
+        CallbackKey key = next_key_++;
+        crash_manager_register_callback(
+            *manager_, key, std::make_unique<CrashCallback>(CrashCallback{std::move(callback)}));
+        return key;
+    }
+
+    void unregisterCallback(CallbackKey key) override {
+        crash_manager_unregister_callback(*manager_, key);
+    }
+
+    void setHeapInfo(const HeapInformation& heapInfo) override {
+        {
+            std::lock_guard<std::mutex> lock(heap_info_mutex_);
+            heap_info_ = heapInfo;
+        }
+        crash_manager_set_heap_info(*manager_, heapInfo.used_, heapInfo.size_);
+    }
+
+    HeapInformation getHeapInfo() override {
+        std::lock_guard<std::mutex> lock(heap_info_mutex_);
+        return heap_info_;
+    }
+
+private:
+    static rust::Slice<const uint8_t> bytes(const char* text) {
+        return rust::Slice<const uint8_t>(
+            reinterpret_cast<const uint8_t*>(text), std::char_traits<char>::length(text));
+    }
+
+    rust::Box<CrashManagerBox> manager_;
+    std::atomic<CallbackKey> next_key_{0};
+    std::mutex heap_info_mutex_;
+    HeapInformation heap_info_;
+};
+
+void fatal_handler(const std::string& message) {
+    rust::Slice<const uint8_t> bytes(reinterpret_cast<const uint8_t*>(message.data()), message.size());
+    hermes_fatal_handler(bytes);
+}
+
+} // namespace
+
+void runtime_config_set_crash_manager(
+    ::hermes::vm::RuntimeConfig& config,
+    rust::Box<CrashManagerBox> manager) {
+
+    config = config.rebuild()
+        .withCrashMgr(std::make_shared<RustCrashManager>(std::move(manager)))
+        .build();
+}
+
+void install_fatal_handler() {
+    facebook::hermes::HermesRuntime::setFatalHandler(fatal_handler);
+}
+
+void instrumentation_create_heap_snapshot(
+    facebook::hermes::HermesRuntime& runtime,
+    WriteSink& sink) {
+
+    SinkStreamBuf buffer(sink);
+    std::ostream os(&buffer);
+
+    runtime.instrumentation().createSnapshotToStream(os);
+    os.flush();
+}
+
+void instrumentation_stop_heap_sampling(
+    facebook::hermes::HermesRuntime& runtime,
+    WriteSink& sink) {
+
+    SinkStreamBuf buffer(sink);
+    std::ostream os(&buffer);
+
+    runtime.instrumentation().stopHeapSampling(os);
+    os.flush();
+}
+
+void sampled_trace_to_devtools(
+    facebook::hermes::HermesRuntime& runtime,
+    WriteSink& sink) {
+
+    SinkStreamBuf buffer(sink);
+    std::ostream os(&buffer);
+
+    runtime.sampledTraceToStreamInDevToolsFormat(os);
+    os.flush();
+}
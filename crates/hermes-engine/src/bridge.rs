@@ -1,5 +1,13 @@
 // Rust/C++ bridge for Hermes Engine using cxx
 
+use crate::crash::{
+    crash_manager_register_callback, crash_manager_register_memory,
+    crash_manager_remove_custom_data, crash_manager_set_custom_data, crash_manager_set_heap_info,
+    crash_manager_unregister_callback, crash_manager_unregister_memory, hermes_fatal_handler,
+    CrashManagerBox,
+};
+use crate::stream::{write_sink_write, WriteSink};
+
 #[cxx::bridge]
 pub mod ffi {
     // Opaque C++ types
@@ -14,6 +22,10 @@ pub mod ffi {
         #[namespace = "facebook::hermes"]
         type HermesRuntime;
 
+        // Locking runtime decorator - maps to facebook::jsi::ThreadSafeRuntime
+        #[namespace = "facebook::jsi"]
+        type ThreadSafeRuntime;
+
         // PreparedJavaScript - maps to facebook::jsi::PreparedJavaScript
         #[namespace = "facebook::jsi"]
         type PreparedJavaScript;
@@ -21,16 +33,15 @@ pub mod ffi {
         // CompiledBytecode - maps to CompiledBytecode in wrapper.h
         type CompiledBytecode;
 
-        // JSI Value - maps to facebook::jsi::Value
-        // We use the same type name as jsi-rs for consistency
+        // JSI Value - shared with jsi-rs so values can be handed over without casts
         #[namespace = "facebook::jsi"]
         #[cxx_name = "Value"]
-        type JSIValue;
+        type JSIValue = jsi_rs::sys::ffi::JSIValue;
 
-        // JSI Runtime - maps to facebook::jsi::Runtime
+        // JSI Runtime - shared with jsi-rs, see JSIValue
         #[namespace = "facebook::jsi"]
         #[cxx_name = "Runtime"]
-        type JSIRuntime;
+        type JSIRuntime = jsi_rs::sys::ffi::JSIRuntime;
 
         // Create RuntimeConfig with all settings
         fn create_runtime_config(
@@ -47,8 +58,33 @@ pub mod ffi {
             enable_sample_profiling: bool,
             native_stack_gap: u32,
             max_num_registers: u32,
+            record_gc_stats: bool,
         ) -> UniquePtr<RuntimeConfig>;
 
+        // Forward the crash context of runtimes created from the config to a Rust CrashManager
+        fn runtime_config_set_crash_manager(
+            config: Pin<&mut RuntimeConfig>,
+            manager: Box<CrashManagerBox>,
+        );
+
+        // Callback registered with a CrashManager
+        type CrashCallback;
+
+        // Write the callback's crash information to a file descriptor
+        fn crash_callback_call(callback: &CrashCallback, fd: i32);
+
+        // Forward fatal VM errors to hermes_fatal_handler before Hermes aborts
+        fn install_fatal_handler();
+
+        // Settings affecting how source is compiled, as a bit set
+        fn runtime_config_compile_flags(config: &RuntimeConfig) -> u32;
+
+        // Whether the config enables sample profiling
+        fn runtime_config_sample_profiling(config: &RuntimeConfig) -> bool;
+
+        // Copy a RuntimeConfig
+        fn clone_runtime_config(config: &RuntimeConfig) -> UniquePtr<RuntimeConfig>;
+
         // Create a new Hermes runtime
         fn create_hermes_runtime(config: &RuntimeConfig) -> UniquePtr<HermesRuntime>;
 
@@ -59,6 +95,29 @@ pub mod ffi {
             source_url: &str,
         ) -> Result<UniquePtr<JSIValue>>;
 
+        // Create a new Hermes runtime whose JSI calls are serialized by a lock
+        fn create_thread_safe_hermes_runtime(
+            config: &RuntimeConfig,
+        ) -> UniquePtr<ThreadSafeRuntime>;
+
+        // Acquire the runtime lock, recursive for the thread holding it
+        fn thread_safe_runtime_lock(runtime: &ThreadSafeRuntime);
+
+        // Release the runtime lock, must happen on the thread that acquired it
+        fn thread_safe_runtime_unlock(runtime: &ThreadSafeRuntime);
+
+        // Get the locking JSI runtime (upcast ThreadSafeRuntime to jsi::Runtime base class)
+        fn get_thread_safe_jsi_runtime(
+            runtime: Pin<&mut ThreadSafeRuntime>,
+        ) -> Pin<&mut JSIRuntime>;
+
+        // Evaluate JavaScript source code on any JSI runtime
+        fn eval_js_jsi(
+            runtime: Pin<&mut JSIRuntime>,
+            source: &str,
+            source_url: &str,
+        ) -> Result<UniquePtr<JSIValue>>;
+
         // Compile JavaScript to bytecode
         fn compile_js_to_bytecode(
             source: &str,
@@ -75,6 +134,84 @@ pub mod ffi {
         // Get the underlying JSI runtime (upcast HermesRuntime to jsi::Runtime base class)
         fn get_jsi_runtime(runtime: Pin<&mut HermesRuntime>) -> Pin<&mut JSIRuntime>;
 
+        // Current heap statistics of jsi::Instrumentation
+        fn instrumentation_heap_info(
+            runtime: Pin<&mut HermesRuntime>,
+            include_expensive: bool,
+            keys: &mut Vec<String>,
+            values: &mut Vec<i64>,
+        );
+
+        // Perform a full garbage collection
+        fn instrumentation_collect_garbage(runtime: Pin<&mut HermesRuntime>, cause: &str);
+
+        // GC statistics recorded so far, JSON-encoded
+        fn instrumentation_recorded_gc_stats(runtime: Pin<&mut HermesRuntime>) -> String;
+
+        // Write a Chrome heap snapshot to the sink
+        fn instrumentation_create_heap_snapshot(
+            runtime: Pin<&mut HermesRuntime>,
+            sink: &mut WriteSink<'_>,
+        ) -> Result<()>;
+
+        // Start the sampling heap profiler
+        fn instrumentation_start_heap_sampling(
+            runtime: Pin<&mut HermesRuntime>,
+            interval: usize,
+        ) -> Result<()>;
+
+        // Stop the sampling heap profiler and write its JSON profile to the sink
+        fn instrumentation_stop_heap_sampling(
+            runtime: Pin<&mut HermesRuntime>,
+            sink: &mut WriteSink<'_>,
+        ) -> Result<()>;
+
+        // Start the process-wide sampling profiler
+        fn enable_sampling_profiler(mean_hz_freq: f64) -> Result<()>;
+
+        // Stop the process-wide sampling profiler
+        fn disable_sampling_profiler() -> Result<()>;
+
+        // Register the runtime's thread with the sampling profiler
+        fn register_for_profiling(runtime: Pin<&mut HermesRuntime>) -> Result<()>;
+
+        // Unregister the runtime's thread from the sampling profiler
+        fn unregister_for_profiling(runtime: Pin<&mut HermesRuntime>) -> Result<()>;
+
+        // Write the collected samples in Chrome DevTools .cpuprofile format to the sink
+        fn sampled_trace_to_devtools(
+            runtime: Pin<&mut HermesRuntime>,
+            sink: &mut WriteSink<'_>,
+        ) -> Result<()>;
+
+        // Samples collected for the runtime as parallel vectors, see wrapper.h for the layout
+        #[allow(clippy::too_many_arguments)]
+        fn sampled_trace_to_profile(
+            runtime: Pin<&mut HermesRuntime>,
+            timestamps: &mut Vec<u64>,
+            threads: &mut Vec<u64>,
+            depths: &mut Vec<usize>,
+            kinds: &mut Vec<u8>,
+            names: &mut Vec<String>,
+            script_ids: &mut Vec<u32>,
+            urls: &mut Vec<String>,
+            lines: &mut Vec<i64>,
+            columns: &mut Vec<i64>,
+        ) -> Result<()>;
+
+        // Start recording executed functions in all runtimes
+        fn enable_code_coverage_profiler() -> Result<()>;
+
+        // Stop recording executed functions
+        fn disable_code_coverage_profiler() -> Result<()>;
+
+        // Executed functions of all runtimes as parallel vectors of runtime ids and
+        // "moduleId:functionOffset:debugInfo" entries
+        fn get_executed_functions(
+            runtimes: &mut Vec<String>,
+            functions: &mut Vec<String>,
+        ) -> Result<()>;
+
         // Prepare JavaScript for optimized execution
         fn prepare_javascript(
             runtime: Pin<&mut HermesRuntime>,
@@ -82,6 +219,12 @@ pub mod ffi {
             source_url: &str,
         ) -> Result<SharedPtr<PreparedJavaScript>>;
 
+        // Prepare bytecode, usable with runtimes of any configuration
+        fn prepare_bytecode(
+            runtime: Pin<&mut HermesRuntime>,
+            bytecode: &CompiledBytecode,
+        ) -> Result<SharedPtr<PreparedJavaScript>>;
+
         // Evaluate prepared JavaScript
         fn evaluate_prepared_javascript(
             runtime: Pin<&mut HermesRuntime>,
@@ -97,4 +240,39 @@ pub mod ffi {
         // Get bytecode size
         fn compiled_bytecode_size(bytecode: &CompiledBytecode) -> usize;
     }
+
+    extern "Rust" {
+        // Rust writer fed by a C++ std::ostream
+        type WriteSink<'a>;
+
+        // Forward a chunk of stream output, returns false once the writer failed
+        fn write_sink_write(sink: &mut WriteSink<'_>, data: &[u8]) -> bool;
+
+        // Called with the message of a fatal VM error, right before aborting
+        fn hermes_fatal_handler(message: &[u8]);
+
+        // Rust CrashManager receiving the crash context of runtimes
+        type CrashManagerBox;
+
+        fn crash_manager_register_memory(manager: &CrashManagerBox, address: usize, length: usize);
+        fn crash_manager_unregister_memory(manager: &CrashManagerBox, address: usize);
+        fn crash_manager_set_custom_data(
+            manager: &CrashManagerBox,
+            key: &[u8],
+            value: &[u8],
+            contextual: bool,
+        );
+        fn crash_manager_remove_custom_data(
+            manager: &CrashManagerBox,
+            key: &[u8],
+            contextual: bool,
+        );
+        fn crash_manager_register_callback(
+            manager: &CrashManagerBox,
+            key: i32,
+            callback: UniquePtr<CrashCallback>,
+        );
+        fn crash_manager_unregister_callback(manager: &CrashManagerBox, key: i32);
+        fn crash_manager_set_heap_info(manager: &CrashManagerBox, used: usize, size: usize);
+    }
 }
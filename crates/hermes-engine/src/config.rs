@@ -1,6 +1,9 @@
+use std::sync::Arc;
+
 use cxx::UniquePtr;
 
 use crate::bridge::ffi;
+use crate::crash::{CrashManager, CrashManagerBox};
 
 /// Configuration for the Hermes JavaScript runtime
 pub struct RuntimeConfig {
@@ -14,6 +17,18 @@ impl RuntimeConfig {
     }
 }
 
+impl Clone for RuntimeConfig {
+    fn clone(&self) -> Self {
+        Self {
+            handle: ffi::clone_runtime_config(&self.handle),
+        }
+    }
+}
+
+// SAFETY: hermes::vm::RuntimeConfig is a plain value, read only when creating a runtime
+unsafe impl Send for RuntimeConfig {}
+unsafe impl Sync for RuntimeConfig {}
+
 impl Default for RuntimeConfig {
     fn default() -> Self {
         RuntimeConfigBuilder::new().build()
@@ -35,6 +50,8 @@ pub struct RuntimeConfigBuilder {
     enable_sample_profiling: bool,
     native_stack_gap: u32,
     max_num_registers: u32,
+    record_gc_stats: bool,
+    crash_manager: Option<Arc<dyn CrashManager>>,
 }
 
 impl RuntimeConfigBuilder {
@@ -54,6 +71,8 @@ impl RuntimeConfigBuilder {
             enable_sample_profiling: false,
             native_stack_gap: 0,  // 0 means use default
             max_num_registers: 0, // 0 means use default
+            record_gc_stats: false,
+            crash_manager: None,
         }
     }
 
@@ -130,25 +149,43 @@ impl RuntimeConfigBuilder {
         self
     }
 
+    /// Record GC statistics, read with `Instrumentation::recorded_gc_stats()`
+    pub fn record_gc_stats(mut self, enable: bool) -> Self {
+        self.record_gc_stats = enable;
+        self
+    }
+
+    /// Forward the crash context of runtimes to `manager`, see [`CrashManager`].
+    pub fn crash_manager(mut self, manager: impl CrashManager) -> Self {
+        self.crash_manager = Some(Arc::new(manager));
+        self
+    }
+
     /// Build the RuntimeConfig
     pub fn build(self) -> RuntimeConfig {
-        RuntimeConfig {
-            handle: ffi::create_runtime_config(
-                self.init_heap_size,
-                self.max_heap_size,
-                self.enable_eval,
-                self.enable_jit,
-                self.enable_es6_proxy,
-                self.enable_es6_block_scoping,
-                self.enable_intl,
-                self.enable_microtask_queue,
-                self.enable_generator,
-                self.enable_hermes_internal,
-                self.enable_sample_profiling,
-                self.native_stack_gap,
-                self.max_num_registers,
-            ),
+        let mut handle = ffi::create_runtime_config(
+            self.init_heap_size,
+            self.max_heap_size,
+            self.enable_eval,
+            self.enable_jit,
+            self.enable_es6_proxy,
+            self.enable_es6_block_scoping,
+            self.enable_intl,
+            self.enable_microtask_queue,
+            self.enable_generator,
+            self.enable_hermes_internal,
+            self.enable_sample_profiling,
+            self.native_stack_gap,
+            self.max_num_registers,
+            self.record_gc_stats,
+        );
+        if let Some(manager) = self.crash_manager {
+            ffi::runtime_config_set_crash_manager(
+                handle.pin_mut(),
+                Box::new(CrashManagerBox(manager)),
+            );
         }
+        RuntimeConfig { handle }
     }
 }
 
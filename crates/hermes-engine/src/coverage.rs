@@ -0,0 +1,856 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::Mutex;
+
+use serde_json::Value;
+
+use crate::bridge::ffi;
+use crate::error::{Error, Result};
+
+/// Number of active coverage collectors, the coverage profiler is process-wide
+static ACTIVE_COLLECTORS: Mutex<usize> = Mutex::new(0);
+
+/// Collects the JavaScript functions executed by runtimes while it is alive.
+///
+/// Wraps the Hermes code coverage profiler, which is process-wide: every runtime records
+/// the functions it runs until the last collector is dropped. Read the coverage before
+/// dropping the runtimes, a runtime's records go away with it.
+///
+/// Hermes only reports functions that ran, pass the evaluated sources to
+/// `CoverageReport::with_source()` to count the functions that never did as uncovered.
+///
+/// # Example
+/// ```no_run
+/// # use hermes_engine::{CodeCoverage, Runtime, RuntimeConfig};
+/// let coverage = CodeCoverage::start()?;
+///
+/// let mut runtime = Runtime::new(RuntimeConfig::default())?;
+/// let source = "function rule(x) { return x > 1; } rule(2);";
+/// runtime.eval(source, Some("rules.js"))?;
+///
+/// let report = coverage.report()?.with_source("rules.js", source);
+/// std::fs::write("lcov.info", report.to_lcov())?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct CodeCoverage {
+    _private: (),
+}
+
+impl CodeCoverage {
+    /// Start recording executed functions in all runtimes.
+    pub fn start() -> Result<Self> {
+        let mut active = ACTIVE_COLLECTORS.lock().unwrap();
+        if *active == 0 {
+            ffi::enable_code_coverage_profiler()?;
+        }
+        *active += 1;
+        Ok(Self { _private: () })
+    }
+
+    /// Functions executed so far by the runtimes that are still alive.
+    pub fn executed_functions(&self) -> Result<Vec<ExecutedFunction>> {
+        let mut runtimes = Vec::new();
+        let mut functions = Vec::new();
+        ffi::get_executed_functions(&mut runtimes, &mut functions)?;
+
+        runtimes
+            .into_iter()
+            .zip(functions)
+            .map(|(runtime, function)| ExecutedFunction::parse(runtime, &function))
+            .collect()
+    }
+
+    /// Coverage of the functions executed so far, grouped by source file.
+    pub fn report(&self) -> Result<CoverageReport> {
+        Ok(CoverageReport::from_functions(&self.executed_functions()?))
+    }
+}
+
+impl Drop for CodeCoverage {
+    fn drop(&mut self) {
+        let mut active = ACTIVE_COLLECTORS.lock().unwrap();
+        *active -= 1;
+        if *active == 0 {
+            let _ = ffi::disable_code_coverage_profiler();
+        }
+    }
+}
+
+/// A function reported by the code coverage profiler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutedFunction {
+    /// Identifier of the runtime that executed the function
+    pub runtime: String,
+    /// Bytecode module containing the function
+    pub module_id: u32,
+    /// Offset of the function within its module, identifies it together with `module_id`
+    pub function_offset: u32,
+    /// Source URL the function was loaded from, if known
+    pub source_url: Option<String>,
+    /// 1-based position of the function, 0 without debug information
+    pub line: u32,
+    pub column: u32,
+}
+
+impl ExecutedFunction {
+    /// Parse the `moduleId:offset:debugInfo` form reported by Hermes
+    ///
+    /// The debug info ends with `line:column`, preceded by the source URL which may contain
+    /// colons itself, or is `NULL` without debug information.
+    fn parse(runtime: String, text: &str) -> Result<Self> {
+        let invalid = || Error::internal(format!("Unexpected coverage entry: {text}"));
+
+        let mut parts = text.splitn(3, ':');
+        let module_id = parts
+            .next()
+            .and_then(|id| id.parse().ok())
+            .ok_or_else(invalid)?;
+        let function_offset = parts
+            .next()
+            .and_then(|offset| offset.parse().ok())
+            .ok_or_else(invalid)?;
+        let debug_info = parts.next().unwrap_or("");
+
+        let mut location = debug_info.rsplitn(3, ':');
+        let (column, line, file) = match (location.next(), location.next(), location.next()) {
+            (Some(column), Some(line), file) => match (column.parse(), line.parse()) {
+                (Ok(column), Ok(line)) => (column, line, file),
+                _ => (0, 0, None),
+            },
+            _ => (0, 0, None),
+        };
+
+        Ok(ExecutedFunction {
+            runtime,
+            module_id,
+            function_offset,
+            source_url: file.filter(|file| !file.is_empty()).map(str::to_string),
+            line,
+            column,
+        })
+    }
+}
+
+/// Coverage of one function in a [`CoverageReport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionCoverage {
+    pub name: String,
+    /// 1-based position of the function in its file
+    pub line: u32,
+    pub column: u32,
+    /// Number of runtimes that executed the function, 0 if it never ran
+    pub hits: u32,
+}
+
+/// Functions grouped by source file, exportable as LCOV or Istanbul JSON.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoverageReport {
+    /// Functions of each file, sorted by position
+    pub files: BTreeMap<String, Vec<FunctionCoverage>>,
+}
+
+impl CoverageReport {
+    /// Group executed functions by source URL, functions without one are skipped.
+    pub fn from_functions(functions: &[ExecutedFunction]) -> Self {
+        let mut report = CoverageReport::default();
+        for function in functions {
+            if let Some(url) = &function.source_url {
+                report.add(url, function.line, function.column);
+            }
+        }
+        report
+    }
+
+    /// Map every position of `generated` through its source map to the original sources.
+    ///
+    /// Functions of other files are kept as they are, functions without a mapping are
+    /// dropped.
+    pub fn apply_source_map(self, generated: &str, source_map: &SourceMap) -> Self {
+        let mut report = CoverageReport::default();
+        for (file, functions) in self.files {
+            for function in functions {
+                if file != generated {
+                    report.add_hits(&file, function);
+                    continue;
+                }
+
+                if let Some(original) = source_map.lookup(function.line, function.column) {
+                    let name = original.name.clone().unwrap_or(function.name);
+                    report.add_hits(
+                        &original.source,
+                        FunctionCoverage {
+                            name,
+                            line: original.line,
+                            column: original.column,
+                            hits: function.hits,
+                        },
+                    );
+                }
+            }
+        }
+        report
+    }
+
+    /// Add the functions of `source` that never ran, with 0 hits.
+    ///
+    /// Hermes only reports executed functions, so `source` is scanned for the others. Pass
+    /// each source under the URL it was evaluated with, before applying source maps. A
+    /// function counts as run when Hermes reported a position within its header, i.e.
+    /// between its first token and its body.
+    pub fn with_source(mut self, source_url: &str, source: &str) -> Self {
+        for function in scan_functions(source) {
+            let ran = self.files.get(source_url).is_some_and(|functions| {
+                functions
+                    .iter()
+                    .any(|f| (function.start..=function.body).contains(&(f.line, f.column)))
+            });
+            if !ran {
+                let (line, column) = function.start;
+                self.add_hits(source_url, anonymous(line, column, 0));
+            }
+        }
+        self
+    }
+
+    fn add(&mut self, file: &str, line: u32, column: u32) {
+        self.add_hits(file, anonymous(line, column, 1));
+    }
+
+    fn add_hits(&mut self, file: &str, function: FunctionCoverage) {
+        let functions = self.files.entry(file.to_string()).or_default();
+        match functions
+            .binary_search_by_key(&(function.line, function.column), |f| (f.line, f.column))
+        {
+            Ok(index) => functions[index].hits += function.hits,
+            Err(index) => functions.insert(index, function),
+        }
+    }
+
+    /// Export as an LCOV tracefile, e.g. for `genhtml` or coverage services.
+    ///
+    /// Only function records are written, Hermes doesn't report line coverage.
+    pub fn to_lcov(&self) -> String {
+        let mut out = String::new();
+        for (file, functions) in &self.files {
+            let _ = writeln!(out, "TN:");
+            let _ = writeln!(out, "SF:{file}");
+            for function in functions {
+                let _ = writeln!(out, "FN:{},{}", function.line, function.name);
+            }
+            for function in functions {
+                let _ = writeln!(out, "FNDA:{},{}", function.hits, function.name);
+            }
+            let hit = functions.iter().filter(|f| f.hits > 0).count();
+            let _ = writeln!(out, "FNF:{}", functions.len());
+            let _ = writeln!(out, "FNH:{hit}");
+            let _ = writeln!(out, "end_of_record");
+        }
+        out
+    }
+
+    /// Export as Istanbul's `coverage-final.json` format, e.g. for `nyc report`.
+    ///
+    /// Only function coverage is filled in, columns are converted to Istanbul's 0-based ones.
+    pub fn to_istanbul_json(&self) -> String {
+        let mut out = String::from("{");
+        for (file_index, (file, functions)) in self.files.iter().enumerate() {
+            if file_index > 0 {
+                out.push(',');
+            }
+            let file = Value::from(file.as_str());
+            let _ = write!(out, "{file}:{{\"path\":{file},\"fnMap\":{{");
+            for (index, function) in functions.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                let location = format!(
+                    "{{\"start\":{{\"line\":{line},\"column\":{column}}},\"end\":{{\"line\":{line},\"column\":{column}}}}}",
+                    line = function.line,
+                    column = function.column.saturating_sub(1),
+                );
+                let _ = write!(
+                    out,
+                    "\"{index}\":{{\"name\":{},\"decl\":{location},\"loc\":{location},\"line\":{}}}",
+                    Value::from(function.name.as_str()),
+                    function.line
+                );
+            }
+            out.push_str("},\"f\":{");
+            for (index, function) in functions.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                let _ = write!(out, "\"{index}\":{}", function.hits);
+            }
+            out.push_str("},\"statementMap\":{},\"s\":{},\"branchMap\":{},\"b\":{}}");
+        }
+        out.push('}');
+        out
+    }
+}
+
+/// Hermes doesn't report function names, source maps may provide them
+fn anonymous(line: u32, column: u32, hits: u32) -> FunctionCoverage {
+    FunctionCoverage {
+        name: format!("(anonymous_{line}_{column})"),
+        line,
+        column,
+        hits,
+    }
+}
+
+/// Original position of generated code, see `SourceMap::lookup()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OriginalPosition {
+    pub source: String,
+    /// 1-based position in `source`
+    pub line: u32,
+    pub column: u32,
+    pub name: Option<String>,
+}
+
+/// A decoded version 3 source map.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceMap {
+    /// `None` for sources that are unknown, e.g. generated code
+    sources: Vec<Option<String>>,
+    names: Vec<String>,
+    /// Segments of each generated line, sorted by generated column
+    lines: Vec<Vec<Segment>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Segment {
+    column: u32,
+    source: u32,
+    line: u32,
+    source_column: u32,
+    name: Option<u32>,
+}
+
+impl SourceMap {
+    /// Parse a source map from its JSON.
+    pub fn parse(json: &str) -> Result<Self> {
+        let map: Value = serde_json::from_str(json)
+            .map_err(|err| Error::runtime(format!("Invalid source map: {err}")))?;
+        if !map.is_object() {
+            return Err(Error::runtime("Invalid source map: expected an object"));
+        }
+
+        let source_root = match map.get("sourceRoot") {
+            None | Some(Value::Null) => "",
+            Some(root) => root
+                .as_str()
+                .ok_or_else(|| Error::runtime("Invalid source map: bad \"sourceRoot\""))?,
+        };
+        let separator = if source_root.is_empty() || source_root.ends_with('/') {
+            ""
+        } else {
+            "/"
+        };
+        // Null sources are allowed, e.g. for generated code, and kept to preserve indices
+        let sources = map
+            .get("sources")
+            .and_then(Value::as_array)
+            .and_then(|sources| {
+                sources
+                    .iter()
+                    .map(|source| match source {
+                        Value::Null => Some(None),
+                        source => Some(Some(format!(
+                            "{source_root}{separator}{}",
+                            source.as_str()?
+                        ))),
+                    })
+                    .collect::<Option<_>>()
+            })
+            .ok_or_else(|| Error::runtime("Invalid source map: bad or missing \"sources\""))?;
+        let names = match map.get("names") {
+            None | Some(Value::Null) => Some(Vec::new()),
+            Some(names) => names.as_array().and_then(|names| {
+                names
+                    .iter()
+                    .map(|name| name.as_str().map(str::to_string))
+                    .collect()
+            }),
+        }
+        .ok_or_else(|| Error::runtime("Invalid source map: bad \"names\""))?;
+        let mappings = map
+            .get("mappings")
+            .and_then(Value::as_str)
+            .ok_or_else(|| Error::runtime("Invalid source map: bad or missing \"mappings\""))?;
+
+        SourceMap::new(sources, names, mappings)
+    }
+
+    /// Build a source map from its `sources`, `names` and VLQ encoded `mappings`.
+    ///
+    /// Sources are `None` when unknown, positions mapped to them have no original position.
+    pub fn new(sources: Vec<Option<String>>, names: Vec<String>, mappings: &str) -> Result<Self> {
+        let mut lines = Vec::new();
+        // Source, original line, original column and name are relative to the previous segment
+        let mut state = [0i64; 4];
+
+        for line in mappings.split(';') {
+            let mut segments = Vec::new();
+            let mut column = 0i64;
+
+            for segment in line.split(',').filter(|segment| !segment.is_empty()) {
+                let fields = decode_vlq(segment)?;
+                column += fields[0];
+                if fields.len() < 4 {
+                    // Generated code without an original position
+                    continue;
+                }
+                for (value, delta) in state.iter_mut().zip(&fields[1..]) {
+                    *value += delta;
+                }
+
+                let to_u32 = |value: i64| {
+                    u32::try_from(value).map_err(|_| Error::internal("Invalid source map mappings"))
+                };
+                segments.push(Segment {
+                    column: to_u32(column)?,
+                    source: to_u32(state[0])?,
+                    line: to_u32(state[1])?,
+                    source_column: to_u32(state[2])?,
+                    name: if fields.len() >= 5 {
+                        Some(to_u32(state[3])?)
+                    } else {
+                        None
+                    },
+                });
+            }
+
+            segments.sort_by_key(|segment| segment.column);
+            lines.push(segments);
+        }
+
+        Ok(SourceMap {
+            sources,
+            names,
+            lines,
+        })
+    }
+
+    /// Original position of a 1-based generated position, from the closest mapping at or
+    /// before it on the same line.
+    pub fn lookup(&self, line: u32, column: u32) -> Option<OriginalPosition> {
+        let segments = self.lines.get(line.checked_sub(1)? as usize)?;
+        let column = column.saturating_sub(1);
+        let index = segments.partition_point(|segment| segment.column <= column);
+        let segment = segments.get(index.checked_sub(1)?)?;
+
+        Some(OriginalPosition {
+            source: self.sources.get(segment.source as usize)?.clone()?,
+            line: segment.line + 1,
+            column: segment.source_column + 1,
+            name: segment
+                .name
+                .and_then(|name| self.names.get(name as usize).cloned()),
+        })
+    }
+}
+
+/// Decode a Base64 VLQ segment of a source map into its signed fields
+fn decode_vlq(segment: &str) -> Result<Vec<i64>> {
+    let mut fields = Vec::with_capacity(5);
+    let mut value = 0i64;
+    let mut shift = 0;
+
+    for byte in segment.bytes() {
+        let digit = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return Err(Error::internal("Invalid source map mappings")),
+        } as i64;
+        if shift > 60 {
+            return Err(Error::internal("Invalid source map mappings"));
+        }
+
+        value |= (digit & 0b11111) << shift;
+        if digit & 0b100000 != 0 {
+            shift += 5;
+            continue;
+        }
+
+        fields.push(if value & 1 == 1 {
+            -(value >> 1)
+        } else {
+            value >> 1
+        });
+        value = 0;
+        shift = 0;
+    }
+
+    if shift != 0 || fields.is_empty() {
+        return Err(Error::internal("Invalid source map mappings"));
+    }
+    Ok(fields)
+}
+
+/// A function found by `scan_functions()`, positions are 1-based
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SourceFunction {
+    /// First token of the function, e.g. `async`, `function` or a method name
+    start: (u32, u32),
+    /// First token of its body
+    body: (u32, u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Ident,
+    Punct,
+    /// String, number, regular expression or complete template literal
+    Literal,
+    /// Template literal part followed by a `${` substitution
+    TemplateHead,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+    offset: usize,
+}
+
+impl Token<'_> {
+    fn is(&self, kind: TokenKind, text: &str) -> bool {
+        self.kind == kind && self.text == text
+    }
+}
+
+/// Find the functions declared in JavaScript source: function declarations and
+/// expressions, arrow functions and methods.
+///
+/// This is a lexical scan rather than a parse, malformed source yields partial results.
+fn scan_functions(source: &str) -> Vec<SourceFunction> {
+    use TokenKind::*;
+
+    let tokens = tokenize(source);
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(source.match_indices('\n').map(|(index, _)| index + 1))
+        .collect();
+    let position = |token: usize| {
+        let offset = tokens[token].offset;
+        let line = line_starts.partition_point(|&start| start <= offset);
+        (line as u32, (offset - line_starts[line - 1] + 1) as u32)
+    };
+
+    // Index of the matching bracket of each bracket token
+    let mut matching = vec![None; tokens.len()];
+    let mut open = Vec::new();
+    for (index, token) in tokens.iter().enumerate() {
+        if token.kind != Punct {
+            continue;
+        }
+        let opener = match token.text {
+            "(" | "[" | "{" => {
+                open.push(index);
+                continue;
+            }
+            ")" => "(",
+            "]" => "[",
+            "}" => "{",
+            _ => continue,
+        };
+        if let Some(&start) = open.last().filter(|&&start| tokens[start].text == opener) {
+            open.pop();
+            matching[start] = Some(index);
+            matching[index] = Some(start);
+        }
+    }
+
+    let token = |index: usize| tokens.get(index);
+    let is =
+        |index: usize, kind: TokenKind, text: &str| token(index).is_some_and(|t| t.is(kind, text));
+    let before = |index: usize| index.checked_sub(1);
+    let with_async = |index: usize| match before(index) {
+        Some(previous) if is(previous, Ident, "async") => previous,
+        _ => index,
+    };
+
+    let mut functions = Vec::new();
+    for (index, current) in tokens.iter().enumerate() {
+        let (start, body) = if current.is(Ident, "function") {
+            if before(index).is_some_and(|previous| is(previous, Punct, ".")) {
+                continue;
+            }
+            // Skip the `*` and name up to the parameters
+            let Some(params) =
+                (index + 1..tokens.len().min(index + 4)).find(|&i| is(i, Punct, "("))
+            else {
+                continue;
+            };
+            (with_async(index), matching[params].map(|close| close + 1))
+        } else if current.is(Punct, "=>") {
+            let start = match before(index) {
+                Some(previous) if is(previous, Punct, ")") => matching[previous],
+                Some(previous) if tokens[previous].kind == Ident => Some(previous),
+                _ => None,
+            };
+            let Some(start) = start else { continue };
+            (with_async(start), Some(index + 1))
+        } else if current.is(Punct, "(") {
+            // Methods: a property name followed by parameters and a body
+            let (Some(name), Some(close)) = (before(index), matching[index]) else {
+                continue;
+            };
+            if !is(close + 1, Punct, "{") {
+                continue;
+            }
+            let name = match tokens[name] {
+                Token {
+                    kind: Ident, text, ..
+                } => {
+                    let keyword = matches!(
+                        text,
+                        "if" | "for" | "await" | "while" | "switch" | "catch" | "with" | "function"
+                    );
+                    let declaration = before(name).is_some_and(|previous| {
+                        is(previous, Ident, "function")
+                            || is(previous, Punct, "*")
+                                && before(previous).is_some_and(|p| is(p, Ident, "function"))
+                    });
+                    if keyword || declaration {
+                        continue;
+                    }
+                    name
+                }
+                Token { kind: Literal, .. } => name,
+                Token {
+                    kind: Punct,
+                    text: "]",
+                    ..
+                } => match matching[name] {
+                    Some(start) => start,
+                    None => continue,
+                },
+                _ => continue,
+            };
+            let mut start = name;
+            while let Some(previous) = before(start).filter(|&previous| {
+                ["static", "async", "get", "set"]
+                    .iter()
+                    .any(|modifier| is(previous, Ident, modifier))
+                    || is(previous, Punct, "*")
+            }) {
+                start = previous;
+            }
+            (start, Some(close + 1))
+        } else {
+            continue;
+        };
+
+        if let Some(body) = body.filter(|&body| body < tokens.len()) {
+            functions.push(SourceFunction {
+                start: position(start),
+                body: position(body),
+            });
+        }
+    }
+    functions.sort_by_key(|function| function.start);
+    functions.dedup();
+    functions
+}
+
+/// Split JavaScript source into tokens, skipping whitespace and comments
+fn tokenize(source: &str) -> Vec<Token<'_>> {
+    use TokenKind::*;
+
+    let bytes = source.as_bytes();
+    let mut tokens: Vec<Token<'_>> = Vec::new();
+    // Brace depth at which each open template substitution started
+    let mut templates = Vec::new();
+    let mut depth = 0usize;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let start = index;
+        let byte = bytes[index];
+        let next = bytes.get(index + 1).copied();
+
+        let kind = match byte {
+            b' ' | b'\t' | b'\r' | b'\n' => {
+                index += 1;
+                continue;
+            }
+            b'/' if next == Some(b'/') => {
+                index = find(bytes, index, b"\n").unwrap_or(bytes.len());
+                continue;
+            }
+            b'/' if next == Some(b'*') => {
+                index = find(bytes, index + 2, b"*/").map_or(bytes.len(), |end| end + 2);
+                continue;
+            }
+            b'\'' | b'"' => {
+                index = skip_string(bytes, index);
+                Literal
+            }
+            b'`' => {
+                let (end, head) = skip_template(bytes, index + 1);
+                index = end;
+                if head {
+                    templates.push(depth);
+                    TemplateHead
+                } else {
+                    Literal
+                }
+            }
+            b'}' if templates.last() == Some(&depth) => {
+                templates.pop();
+                let (end, head) = skip_template(bytes, index + 1);
+                index = end;
+                if head {
+                    templates.push(depth);
+                    TemplateHead
+                } else {
+                    Literal
+                }
+            }
+            b'/' if regex_allowed(tokens.last()) => {
+                index = skip_regex(bytes, index);
+                Literal
+            }
+            b'0'..=b'9' => {
+                index = skip_number(bytes, index);
+                Literal
+            }
+            b'.' if next.is_some_and(|next| next.is_ascii_digit()) => {
+                index = skip_number(bytes, index);
+                Literal
+            }
+            _ if is_word(byte) => {
+                index = skip_word(bytes, index);
+                Ident
+            }
+            b'=' if next == Some(b'>') => {
+                index += 2;
+                Punct
+            }
+            _ => {
+                match byte {
+                    b'{' => depth += 1,
+                    b'}' => depth = depth.saturating_sub(1),
+                    _ => {}
+                }
+                index += 1;
+                Punct
+            }
+        };
+
+        tokens.push(Token {
+            kind,
+            text: &source[start..index],
+            offset: start,
+        });
+    }
+    tokens
+}
+
+/// Whether a `/` after `previous` starts a regular expression rather than a division
+fn regex_allowed(previous: Option<&Token<'_>>) -> bool {
+    let Some(previous) = previous else {
+        return true;
+    };
+    match previous.kind {
+        TokenKind::Literal => false,
+        TokenKind::TemplateHead => true,
+        TokenKind::Punct => !matches!(previous.text, ")" | "]" | "}"),
+        TokenKind::Ident => matches!(
+            previous.text,
+            "return"
+                | "typeof"
+                | "instanceof"
+                | "in"
+                | "of"
+                | "new"
+                | "delete"
+                | "void"
+                | "throw"
+                | "case"
+                | "do"
+                | "else"
+                | "yield"
+                | "await"
+        ),
+    }
+}
+
+fn is_word(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'$' | b'\\') || byte >= 0x80
+}
+
+/// Skip an identifier, non-ASCII characters are all taken as identifier characters
+fn skip_word(bytes: &[u8], mut index: usize) -> usize {
+    while index < bytes.len() && is_word(bytes[index]) {
+        index += 1;
+    }
+    index
+}
+
+fn skip_number(bytes: &[u8], mut index: usize) -> usize {
+    while index < bytes.len() && (is_word(bytes[index]) || bytes[index] == b'.') {
+        index += 1;
+    }
+    index
+}
+
+/// Skip a string literal starting at its quote, an unterminated one ends at the line
+fn skip_string(bytes: &[u8], index: usize) -> usize {
+    let quote = bytes[index];
+    let mut index = index + 1;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 2,
+            b'\n' => return index,
+            byte if byte == quote => return index + 1,
+            _ => index += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// Skip the rest of a template literal part, returns its end and whether a `${`
+/// substitution follows
+fn skip_template(bytes: &[u8], mut index: usize) -> (usize, bool) {
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 2,
+            b'`' => return (index + 1, false),
+            b'$' if bytes.get(index + 1) == Some(&b'{') => return (index + 2, true),
+            _ => index += 1,
+        }
+    }
+    (bytes.len(), false)
+}
+
+/// Skip a regular expression literal and its flags starting at its `/`
+fn skip_regex(bytes: &[u8], index: usize) -> usize {
+    let mut index = index + 1;
+    let mut class = false;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 1,
+            b'[' => class = true,
+            b']' => class = false,
+            b'/' if !class => return skip_word(bytes, index + 1),
+            b'\n' => return index,
+            _ => {}
+        }
+        index += 1;
+    }
+    bytes.len()
+}
+
+fn find(bytes: &[u8], from: usize, needle: &[u8]) -> Option<usize> {
+    bytes
+        .get(from..)?
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|position| from + position)
+}
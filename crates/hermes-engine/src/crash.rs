@@ -0,0 +1,249 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, RwLock};
+
+use cxx::UniquePtr;
+
+use crate::bridge::ffi;
+
+/// Handler called by `set_fatal_handler()`
+static FATAL_HANDLER: RwLock<Option<fn(&str)>> = RwLock::new(None);
+
+/// Call `handler` with the message of fatal VM errors, such as running out of memory or
+/// a broken internal invariant.
+///
+/// Hermes can't recover from these: the process aborts once the handler returns, so it
+/// should only record the message, e.g. in the crash reporter's metadata. The handler is
+/// process-wide and replaces the previous one.
+///
+/// # Example
+/// ```no_run
+/// hermes_engine::set_fatal_handler(|message| {
+///     eprintln!("Hermes fatal error: {message}");
+/// });
+/// ```
+pub fn set_fatal_handler(handler: fn(&str)) {
+    *FATAL_HANDLER.write().unwrap() = Some(handler);
+    ffi::install_fatal_handler();
+}
+
+/// Called by Hermes on fatal errors, right before aborting
+pub(crate) fn hermes_fatal_handler(message: &[u8]) {
+    // Don't block on the lock, the process is going down
+    let handler = match FATAL_HANDLER.try_read() {
+        Ok(handler) => *handler,
+        Err(_) => None,
+    };
+    if let Some(handler) = handler {
+        // Unwinding into Hermes is undefined behavior, it aborts right after anyway
+        let _ = panic::catch_unwind(|| handler(&String::from_utf8_lossy(message)));
+    }
+}
+
+/// Receives the crash context of runtimes, set with `RuntimeConfigBuilder::crash_manager()`.
+///
+/// Hermes keeps the crash manager informed about its state, e.g. the GC in use or the
+/// phase it is in, so that a crash reporter can attach it to reports. Methods may be
+/// called from any thread running the runtime or its GC, and should return quickly.
+///
+/// All methods do nothing by default. Panics are caught and ignored, they can't unwind
+/// into Hermes.
+///
+/// # Example
+/// ```no_run
+/// # use std::collections::HashMap;
+/// # use std::sync::{Arc, Mutex};
+/// # use hermes_engine::{CrashManager, Runtime, RuntimeConfigBuilder};
+/// #[derive(Default)]
+/// struct Annotations(Mutex<HashMap<String, String>>);
+///
+/// impl CrashManager for Annotations {
+///     fn set_custom_data(&self, key: &str, value: &str) {
+///         self.0.lock().unwrap().insert(key.to_string(), value.to_string());
+///     }
+///
+///     fn remove_custom_data(&self, key: &str) {
+///         self.0.lock().unwrap().remove(key);
+///     }
+/// }
+///
+/// let annotations = Arc::new(Annotations::default());
+/// let config = RuntimeConfigBuilder::new()
+///     .crash_manager(annotations.clone())
+///     .build();
+/// let runtime = Runtime::new(config)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub trait CrashManager: Send + Sync + 'static {
+    /// Memory worth including in crash dumps, e.g. a heap segment.
+    fn register_memory(&self, _address: usize, _length: usize) {}
+
+    fn unregister_memory(&self, _address: usize) {}
+
+    /// Key/value describing the runtime, kept until removed.
+    fn set_custom_data(&self, _key: &str, _value: &str) {}
+
+    fn remove_custom_data(&self, _key: &str) {}
+
+    /// Key/value describing what the current thread is doing, e.g. the GC phase.
+    fn set_contextual_custom_data(&self, _key: &str, _value: &str) {}
+
+    fn remove_contextual_custom_data(&self, _key: &str) {}
+
+    /// Callback writing extra crash information, to be run by the crash reporter.
+    ///
+    /// The callback refers to the runtime's internals, drop it in `unregister_callback()`
+    /// with the same key, see `CrashCallback::call()`.
+    fn register_callback(&self, _key: i32, _callback: CrashCallback) {}
+
+    fn unregister_callback(&self, _key: i32) {}
+
+    /// Latest heap usage in bytes.
+    fn set_heap_info(&self, _used: usize, _size: usize) {}
+}
+
+impl<T: CrashManager + ?Sized> CrashManager for Arc<T> {
+    fn register_memory(&self, address: usize, length: usize) {
+        (**self).register_memory(address, length)
+    }
+
+    fn unregister_memory(&self, address: usize) {
+        (**self).unregister_memory(address)
+    }
+
+    fn set_custom_data(&self, key: &str, value: &str) {
+        (**self).set_custom_data(key, value)
+    }
+
+    fn remove_custom_data(&self, key: &str) {
+        (**self).remove_custom_data(key)
+    }
+
+    fn set_contextual_custom_data(&self, key: &str, value: &str) {
+        (**self).set_contextual_custom_data(key, value)
+    }
+
+    fn remove_contextual_custom_data(&self, key: &str) {
+        (**self).remove_contextual_custom_data(key)
+    }
+
+    fn register_callback(&self, key: i32, callback: CrashCallback) {
+        (**self).register_callback(key, callback)
+    }
+
+    fn unregister_callback(&self, key: i32) {
+        (**self).unregister_callback(key)
+    }
+
+    fn set_heap_info(&self, used: usize, size: usize) {
+        (**self).set_heap_info(used, size)
+    }
+}
+
+/// Callback registered through `CrashManager::register_callback()`.
+///
+/// Only valid while registered: Hermes unregisters it before freeing what it refers to.
+pub struct CrashCallback {
+    handle: UniquePtr<ffi::CrashCallback>,
+}
+
+impl CrashCallback {
+    /// Write the callback's crash information to the file descriptor `fd`.
+    ///
+    /// # Safety
+    ///
+    /// The callback must still be registered: `CrashManager::unregister_callback()` has not
+    /// been called with its key, and the runtime that registered it is still alive. The
+    /// callback reads the runtime's internals, e.g. its GC, without any synchronization.
+    pub unsafe fn call(&self, fd: i32) {
+        ffi::crash_callback_call(&self.handle, fd);
+    }
+}
+
+// SAFETY: crash callbacks are meant to be run by crash reporters on any thread, running one
+// is unsafe and left to the caller, see `call()`
+unsafe impl Send for CrashCallback {}
+unsafe impl Sync for CrashCallback {}
+
+/// Crash manager owned by the C++ adapter
+pub(crate) struct CrashManagerBox(pub(crate) Arc<dyn CrashManager>);
+
+fn text(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+/// Run a crash manager method called by Hermes, which may be on a GC thread with no caller
+/// to hand a panic to
+fn guard(f: impl FnOnce()) {
+    let _ = panic::catch_unwind(AssertUnwindSafe(f));
+}
+
+pub(crate) fn crash_manager_register_memory(
+    manager: &CrashManagerBox,
+    address: usize,
+    length: usize,
+) {
+    guard(|| {
+        manager.0.register_memory(address, length);
+    });
+}
+
+pub(crate) fn crash_manager_unregister_memory(manager: &CrashManagerBox, address: usize) {
+    guard(|| {
+        manager.0.unregister_memory(address);
+    });
+}
+
+pub(crate) fn crash_manager_set_custom_data(
+    manager: &CrashManagerBox,
+    key: &[u8],
+    value: &[u8],
+    contextual: bool,
+) {
+    guard(|| {
+        if contextual {
+            manager
+                .0
+                .set_contextual_custom_data(&text(key), &text(value));
+        } else {
+            manager.0.set_custom_data(&text(key), &text(value));
+        }
+    });
+}
+
+pub(crate) fn crash_manager_remove_custom_data(
+    manager: &CrashManagerBox,
+    key: &[u8],
+    contextual: bool,
+) {
+    guard(|| {
+        if contextual {
+            manager.0.remove_contextual_custom_data(&text(key));
+        } else {
+            manager.0.remove_custom_data(&text(key));
+        }
+    });
+}
+
+pub(crate) fn crash_manager_register_callback(
+    manager: &CrashManagerBox,
+    key: i32,
+    callback: UniquePtr<ffi::CrashCallback>,
+) {
+    guard(|| {
+        manager
+            .0
+            .register_callback(key, CrashCallback { handle: callback });
+    });
+}
+
+pub(crate) fn crash_manager_unregister_callback(manager: &CrashManagerBox, key: i32) {
+    guard(|| {
+        manager.0.unregister_callback(key);
+    });
+}
+
+pub(crate) fn crash_manager_set_heap_info(manager: &CrashManagerBox, used: usize, size: usize) {
+    guard(|| {
+        manager.0.set_heap_info(used, size);
+    });
+}
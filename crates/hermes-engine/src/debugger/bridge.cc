@@ -0,0 +1,196 @@
+#include "hermes-engine/src/debugger/bridge.rs.h"
+
+#include <string>
+#include <utility>
+
+namespace dbg = facebook::hermes::debugger;
+
+namespace {
+
+rust::String location_url(const dbg::SourceLocation& location) {
+    return rust::String::lossy(location.fileName);
+}
+
+} // namespace
+
+PauseObserver::PauseObserver(
+    facebook::hermes::HermesRuntime& runtime,
+    rust::Box<PauseHandler> handler)
+    : runtime_(runtime), handler_(std::move(handler)) {
+
+    runtime_.getDebugger().setEventObserver(this);
+    runtime_.getDebugger().setIsDebuggerAttached(true);
+}
+
+PauseObserver::~PauseObserver() {
+    auto& debugger = runtime_.getDebugger();
+    debugger.setIsDebuggerAttached(false);
+    debugger.setEventObserver(nullptr);
+}
+
+dbg::Command PauseObserver::didPause(dbg::Debugger& debugger) {
+    switch (debugger_did_pause(*handler_, debugger)) {
+        case 1:
+            return dbg::Command::step(dbg::StepMode::Into);
+        case 2:
+            return dbg::Command::step(dbg::StepMode::Over);
+        case 3:
+            return dbg::Command::step(dbg::StepMode::Out);
+        default:
+            return dbg::Command::continueExecution();
+    }
+}
+
+std::unique_ptr<PauseObserver> create_pause_observer(
+    facebook::hermes::HermesRuntime& runtime,
+    rust::Box<PauseHandler> handler) {
+
+    return std::make_unique<PauseObserver>(runtime, std::move(handler));
+}
+
+void debug_javascript(
+    facebook::hermes::HermesRuntime& runtime,
+    rust::Str source,
+    rust::Str source_url) {
+
+    runtime.debugJavaScript(std::string(source), std::string(source_url), {});
+}
+
+dbg::Debugger& get_debugger(facebook::hermes::HermesRuntime& runtime) {
+    return runtime.getDebugger();
+}
+
+uint64_t debugger_set_breakpoint(
+    dbg::Debugger& debugger,
+    rust::Str url,
+    uint32_t line,
+    uint32_t column) {
+
+    dbg::SourceLocation location;
+    location.fileName = std::string(url);
+    location.line = line;
+    location.column = column;
+    return debugger.setBreakpoint(location);
+}
+
+void debugger_set_breakpoint_condition(
+    dbg::Debugger& debugger,
+    uint64_t breakpoint,
+    rust::Str condition) {
+
+    debugger.setBreakpointCondition(breakpoint, std::string(condition));
+}
+
+void debugger_delete_breakpoint(dbg::Debugger& debugger, uint64_t breakpoint) {
+    debugger.deleteBreakpoint(breakpoint);
+}
+
+void debugger_delete_all_breakpoints(dbg::Debugger& debugger) {
+    debugger.deleteAllBreakpoints();
+}
+
+bool debugger_breakpoint_location(
+    dbg::Debugger& debugger,
+    uint64_t breakpoint,
+    rust::String& url,
+    uint32_t& line,
+    uint32_t& column) {
+
+    auto info = debugger.getBreakpointInfo(breakpoint);
+    if (!info.resolved) {
+        return false;
+    }
+    url = location_url(info.resolvedLocation);
+    line = info.resolvedLocation.line;
+    column = info.resolvedLocation.column;
+    return true;
+}
+
+void debugger_set_pause_on_throw_mode(dbg::Debugger& debugger, uint8_t mode) {
+    switch (mode) {
+        case 1:
+            debugger.setPauseOnThrowMode(dbg::PauseOnThrowMode::Uncaught);
+            break;
+        case 2:
+            debugger.setPauseOnThrowMode(dbg::PauseOnThrowMode::All);
+            break;
+        default:
+            debugger.setPauseOnThrowMode(dbg::PauseOnThrowMode::None);
+            break;
+    }
+}
+
+void debugger_set_pause_on_script_load(dbg::Debugger& debugger, bool pause) {
+    debugger.setShouldPauseOnScriptLoad(pause);
+}
+
+uint8_t debugger_pause_reason(const dbg::Debugger& debugger) {
+    switch (debugger.getProgramState().getPauseReason()) {
+        case dbg::PauseReason::ScriptLoaded:
+            return 0;
+        case dbg::PauseReason::DebuggerStatement:
+            return 1;
+        case dbg::PauseReason::Breakpoint:
+            return 2;
+        case dbg::PauseReason::StepFinish:
+            return 3;
+        case dbg::PauseReason::Exception:
+            return 4;
+        case dbg::PauseReason::AsyncTrigger:
+            return 5;
+        default:
+            return 6;
+    }
+}
+
+uint64_t debugger_paused_breakpoint(const dbg::Debugger& debugger) {
+    return debugger.getProgramState().getBreakpoint();
+}
+
+void debugger_stack_trace(
+    const dbg::Debugger& debugger,
+    rust::Vec<rust::String>& function_names,
+    rust::Vec<rust::String>& urls,
+    rust::Vec<uint32_t>& lines,
+    rust::Vec<uint32_t>& columns) {
+
+    const auto& stack = debugger.getProgramState().getStackTrace();
+    for (uint32_t i = 0; i < stack.callFrameCount(); i++) {
+        auto frame = stack.callFrameForIndex(i);
+        function_names.push_back(rust::String::lossy(frame.functionName));
+        urls.push_back(location_url(frame.location));
+        lines.push_back(frame.location.line);
+        columns.push_back(frame.location.column);
+    }
+}
+
+void debugger_scope_sizes(
+    const dbg::Debugger& debugger,
+    uint32_t frame,
+    rust::Vec<uint32_t>& sizes) {
+
+    auto info = debugger.getProgramState().getLexicalInfo(frame);
+    for (uint32_t depth = 0; depth < info.getScopesCount(); depth++) {
+        sizes.push_back(info.getVariablesCountInScope(depth));
+    }
+}
+
+std::unique_ptr<facebook::jsi::Value> debugger_variable(
+    const dbg::Debugger& debugger,
+    uint32_t frame,
+    uint32_t scope,
+    uint32_t index,
+    rust::String& name) {
+
+    auto info = debugger.getProgramState().getVariableInfo(frame, scope, index);
+    name = rust::String::lossy(info.name);
+    return std::make_unique<facebook::jsi::Value>(std::move(info.value));
+}
+
+std::unique_ptr<facebook::jsi::Value> debugger_this(
+    const dbg::Debugger& debugger,
+    uint32_t frame) {
+
+    auto info = debugger.getProgramState().getVariableInfoForThis(frame);
+    return std::make_unique<facebook::jsi::Value>(std::move(info.value));
+}
@@ -0,0 +1,100 @@
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <hermes/DebuggerAPI.h>
+#include <hermes/hermes.h>
+#include <jsi/jsi.h>
+
+#include "rust/cxx.h"
+
+// Rust side of the observer, runs the pause callback
+struct PauseHandler;
+
+// Debugger event observer forwarding pauses to Rust, detaches itself when destroyed
+class PauseObserver : public facebook::hermes::debugger::EventObserver {
+public:
+    PauseObserver(facebook::hermes::HermesRuntime& runtime, rust::Box<PauseHandler> handler);
+    ~PauseObserver() override;
+
+    facebook::hermes::debugger::Command didPause(
+        facebook::hermes::debugger::Debugger& debugger) override;
+
+private:
+    facebook::hermes::HermesRuntime& runtime_;
+    rust::Box<PauseHandler> handler_;
+};
+
+// Attach an observer to the runtime's debugger
+std::unique_ptr<PauseObserver> create_pause_observer(
+    facebook::hermes::HermesRuntime& runtime,
+    rust::Box<PauseHandler> handler);
+
+// Evaluate source in an unoptimized form the debugger can step through
+void debug_javascript(
+    facebook::hermes::HermesRuntime& runtime,
+    rust::Str source,
+    rust::Str source_url);
+
+facebook::hermes::debugger::Debugger& get_debugger(facebook::hermes::HermesRuntime& runtime);
+
+// Breakpoint at a 1-based position of the script loaded from url, 0 when it can't be set
+uint64_t debugger_set_breakpoint(
+    facebook::hermes::debugger::Debugger& debugger,
+    rust::Str url,
+    uint32_t line,
+    uint32_t column);
+
+void debugger_set_breakpoint_condition(
+    facebook::hermes::debugger::Debugger& debugger,
+    uint64_t breakpoint,
+    rust::Str condition);
+
+void debugger_delete_breakpoint(facebook::hermes::debugger::Debugger& debugger, uint64_t breakpoint);
+
+void debugger_delete_all_breakpoints(facebook::hermes::debugger::Debugger& debugger);
+
+// Location a breakpoint resolved to, false while its script isn't loaded
+bool debugger_breakpoint_location(
+    facebook::hermes::debugger::Debugger& debugger,
+    uint64_t breakpoint,
+    rust::String& url,
+    uint32_t& line,
+    uint32_t& column);
+
+// 0 = none, 1 = uncaught, 2 = all
+void debugger_set_pause_on_throw_mode(facebook::hermes::debugger::Debugger& debugger, uint8_t mode);
+
+void debugger_set_pause_on_script_load(facebook::hermes::debugger::Debugger& debugger, bool pause);
+
+// ScriptLoaded, DebuggerStatement, Breakpoint, StepFinish, Exception, AsyncTrigger or other
+uint8_t debugger_pause_reason(const facebook::hermes::debugger::Debugger& debugger);
+
+// Breakpoint that caused the pause, 0 if none
+uint64_t debugger_paused_breakpoint(const facebook::hermes::debugger::Debugger& debugger);
+
+// Call stack of the paused program as parallel vectors, innermost frame first
+void debugger_stack_trace(
+    const facebook::hermes::debugger::Debugger& debugger,
+    rust::Vec<rust::String>& function_names,
+    rust::Vec<rust::String>& urls,
+    rust::Vec<uint32_t>& lines,
+    rust::Vec<uint32_t>& columns);
+
+// Number of variables in each scope of a frame, innermost scope first
+void debugger_scope_sizes(
+    const facebook::hermes::debugger::Debugger& debugger,
+    uint32_t frame,
+    rust::Vec<uint32_t>& sizes);
+
+std::unique_ptr<facebook::jsi::Value> debugger_variable(
+    const facebook::hermes::debugger::Debugger& debugger,
+    uint32_t frame,
+    uint32_t scope,
+    uint32_t index,
+    rust::String& name);
+
+std::unique_ptr<facebook::jsi::Value> debugger_this(
+    const facebook::hermes::debugger::Debugger& debugger,
+    uint32_t frame);
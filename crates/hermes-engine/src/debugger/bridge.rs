@@ -0,0 +1,111 @@
+// Rust/C++ bridge for the Hermes debugger API, only built with the `debugger` feature
+
+use super::{debugger_did_pause, PauseHandler};
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("hermes-engine/src/debugger/bridge.h");
+
+        // Shared with the main bridge
+        #[namespace = "facebook::hermes"]
+        type HermesRuntime = crate::bridge::ffi::HermesRuntime;
+
+        // Shared with jsi-rs
+        #[namespace = "facebook::jsi"]
+        #[cxx_name = "Value"]
+        type JSIValue = jsi_rs::sys::ffi::JSIValue;
+
+        // Debugger of a runtime - maps to facebook::hermes::debugger::Debugger
+        #[namespace = "facebook::hermes::debugger"]
+        #[cxx_name = "Debugger"]
+        type HermesDebugger;
+
+        // Event observer forwarding pauses to a PauseHandler
+        type PauseObserver;
+
+        // Attach an observer to the runtime's debugger, detached when destroyed
+        fn create_pause_observer(
+            runtime: Pin<&mut HermesRuntime>,
+            handler: Box<PauseHandler>,
+        ) -> UniquePtr<PauseObserver>;
+
+        // Evaluate source in an unoptimized form the debugger can step through
+        fn debug_javascript(
+            runtime: Pin<&mut HermesRuntime>,
+            source: &str,
+            source_url: &str,
+        ) -> Result<()>;
+
+        fn get_debugger(runtime: Pin<&mut HermesRuntime>) -> Pin<&mut HermesDebugger>;
+
+        // Breakpoint at a 1-based position of the script loaded from url, 0 when it can't be set
+        fn debugger_set_breakpoint(
+            debugger: Pin<&mut HermesDebugger>,
+            url: &str,
+            line: u32,
+            column: u32,
+        ) -> Result<u64>;
+
+        fn debugger_set_breakpoint_condition(
+            debugger: Pin<&mut HermesDebugger>,
+            breakpoint: u64,
+            condition: &str,
+        ) -> Result<()>;
+
+        fn debugger_delete_breakpoint(debugger: Pin<&mut HermesDebugger>, breakpoint: u64);
+
+        fn debugger_delete_all_breakpoints(debugger: Pin<&mut HermesDebugger>);
+
+        // Location a breakpoint resolved to, false while its script isn't loaded
+        fn debugger_breakpoint_location(
+            debugger: Pin<&mut HermesDebugger>,
+            breakpoint: u64,
+            url: &mut String,
+            line: &mut u32,
+            column: &mut u32,
+        ) -> bool;
+
+        // 0 = none, 1 = uncaught, 2 = all
+        fn debugger_set_pause_on_throw_mode(debugger: Pin<&mut HermesDebugger>, mode: u8);
+
+        fn debugger_set_pause_on_script_load(debugger: Pin<&mut HermesDebugger>, pause: bool);
+
+        // See PauseReason
+        fn debugger_pause_reason(debugger: &HermesDebugger) -> u8;
+
+        // Breakpoint that caused the pause, 0 if none
+        fn debugger_paused_breakpoint(debugger: &HermesDebugger) -> u64;
+
+        // Call stack of the paused program as parallel vectors, innermost frame first
+        fn debugger_stack_trace(
+            debugger: &HermesDebugger,
+            function_names: &mut Vec<String>,
+            urls: &mut Vec<String>,
+            lines: &mut Vec<u32>,
+            columns: &mut Vec<u32>,
+        );
+
+        // Number of variables in each scope of a frame, innermost scope first
+        fn debugger_scope_sizes(debugger: &HermesDebugger, frame: u32, sizes: &mut Vec<u32>);
+
+        fn debugger_variable(
+            debugger: &HermesDebugger,
+            frame: u32,
+            scope: u32,
+            index: u32,
+            name: &mut String,
+        ) -> UniquePtr<JSIValue>;
+
+        fn debugger_this(debugger: &HermesDebugger, frame: u32) -> UniquePtr<JSIValue>;
+    }
+
+    extern "Rust" {
+        // Pause callback of a Debugger
+        type PauseHandler;
+
+        // Called on every pause, returns 0 to continue or 1/2/3 to step into/over/out
+        fn debugger_did_pause(handler: &mut PauseHandler, debugger: Pin<&mut HermesDebugger>)
+            -> u8;
+    }
+}
@@ -0,0 +1,421 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::ops::{Deref, DerefMut};
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use cxx::UniquePtr;
+
+use crate::bridge::ffi as hermes_ffi;
+use crate::error::{Error, Result};
+use crate::jsi::sys::ffi::JSIRuntime;
+use crate::jsi::{JSRuntime, JSValue, RuntimeState};
+use crate::runtime::Runtime;
+
+mod bridge;
+
+use bridge::ffi;
+
+type PauseCallback = Box<dyn FnMut(&mut Pause<'_>)>;
+
+/// Programmatic debugger of a runtime, detached when dropped.
+///
+/// Created via `Runtime::debugger()`. Breakpoints pause JavaScript running through the
+/// debugger, which calls the `on_pause()` callback with the call stack and scopes; the
+/// callback decides how execution resumes. Without a callback every pause continues.
+///
+/// Don't combine with an [`Inspector`](crate::inspector::Inspector) on the same runtime,
+/// both observe the same Hermes debugger.
+///
+/// A panicking callback lets the script run to completion, the panic is raised again once
+/// `debug_javascript()` returns or when the debugger is dropped.
+///
+/// # Example
+/// ```no_run
+/// # use hermes_engine::{Runtime, RuntimeConfig, StepMode};
+/// let mut runtime = Runtime::new(RuntimeConfig::default())?;
+/// let mut debugger = runtime.debugger();
+///
+/// debugger.set_breakpoint("rules.js", 2, 1)?;
+/// debugger.on_pause(|pause| {
+///     let frame = &pause.stack()[0];
+///     println!("paused in {} at line {}", frame.function_name, frame.location.line);
+///     pause.resume(StepMode::Continue);
+/// });
+///
+/// debugger.debug_javascript("function rule(x) {\n  return x > 1;\n}\nrule(2);", Some("rules.js"))?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct Debugger<'a> {
+    // Detached before the runtime can go away
+    observer: UniquePtr<ffi::PauseObserver>,
+    shared: Rc<HandlerState>,
+    runtime: &'a mut Runtime,
+}
+
+/// Breakpoint set with `Debugger::set_breakpoint()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BreakpointId(u64);
+
+/// Position in a script, line and column numbers are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub url: String,
+    pub line: u32,
+    pub column: u32,
+}
+
+/// When exceptions pause execution, see `Debugger::set_pause_on_exceptions()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PauseOnExceptions {
+    #[default]
+    Never,
+    /// Only exceptions no JavaScript handler catches
+    Uncaught,
+    All,
+}
+
+/// How a paused program resumes, see `Pause::resume()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StepMode {
+    /// Run until the next breakpoint
+    #[default]
+    Continue,
+    /// Step into function calls
+    Into,
+    /// Step to the next statement of the current function
+    Over,
+    /// Step out of the current function
+    Out,
+}
+
+/// Why the program paused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseReason {
+    ScriptLoaded,
+    DebuggerStatement,
+    Breakpoint,
+    StepFinish,
+    Exception,
+    AsyncTrigger,
+    Other,
+}
+
+/// Frame of the call stack of a [`Pause`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackFrame {
+    pub function_name: String,
+    pub location: SourceLocation,
+}
+
+/// Variable of a scope, see `Pause::scopes()`.
+pub struct Variable {
+    pub name: String,
+    pub value: JSValue,
+}
+
+impl<'a> Debugger<'a> {
+    pub(crate) fn attach(runtime: &'a mut Runtime) -> Self {
+        let jsi = hermes_ffi::get_jsi_runtime(runtime.hermes());
+        // SAFETY: only used to create wrappers while the runtime is paused
+        let jsi = unsafe { jsi.get_unchecked_mut() as *mut JSIRuntime };
+
+        let shared = Rc::new(HandlerState::default());
+        let handler = Box::new(PauseHandler {
+            shared: shared.clone(),
+            runtime: jsi,
+            state: runtime.state().clone(),
+        });
+        let observer = ffi::create_pause_observer(runtime.hermes(), handler);
+
+        Self {
+            observer,
+            shared,
+            runtime,
+        }
+    }
+
+    /// Set the callback called whenever the program pauses, replacing the previous one.
+    pub fn on_pause<F: FnMut(&mut Pause<'_>) + 'static>(&mut self, callback: F) {
+        *self.shared.callback.borrow_mut() = Some(Box::new(callback));
+    }
+
+    /// Set a breakpoint at a 1-based position of the script loaded from `url`.
+    ///
+    /// The script doesn't have to be loaded yet, the breakpoint resolves once it is.
+    pub fn set_breakpoint(&mut self, url: &str, line: u32, column: u32) -> Result<BreakpointId> {
+        let id = ffi::debugger_set_breakpoint(self.hermes_debugger(), url, line, column)?;
+        if id == 0 {
+            return Err(Error::runtime(format!(
+                "Failed to set breakpoint at {url}:{line}:{column}"
+            )));
+        }
+        Ok(BreakpointId(id))
+    }
+
+    /// Only pause at `breakpoint` when the JavaScript `condition` is truthy.
+    pub fn set_breakpoint_condition(
+        &mut self,
+        breakpoint: BreakpointId,
+        condition: &str,
+    ) -> Result<()> {
+        Ok(ffi::debugger_set_breakpoint_condition(
+            self.hermes_debugger(),
+            breakpoint.0,
+            condition,
+        )?)
+    }
+
+    pub fn remove_breakpoint(&mut self, breakpoint: BreakpointId) {
+        ffi::debugger_delete_breakpoint(self.hermes_debugger(), breakpoint.0);
+    }
+
+    pub fn remove_all_breakpoints(&mut self) {
+        ffi::debugger_delete_all_breakpoints(self.hermes_debugger());
+    }
+
+    /// Location `breakpoint` resolved to, `None` while its script isn't loaded.
+    pub fn breakpoint_location(&mut self, breakpoint: BreakpointId) -> Option<SourceLocation> {
+        let mut location = SourceLocation {
+            url: String::new(),
+            line: 0,
+            column: 0,
+        };
+        ffi::debugger_breakpoint_location(
+            self.hermes_debugger(),
+            breakpoint.0,
+            &mut location.url,
+            &mut location.line,
+            &mut location.column,
+        )
+        .then_some(location)
+    }
+
+    /// Pause when exceptions are thrown.
+    pub fn set_pause_on_exceptions(&mut self, mode: PauseOnExceptions) {
+        let mode = match mode {
+            PauseOnExceptions::Never => 0,
+            PauseOnExceptions::Uncaught => 1,
+            PauseOnExceptions::All => 2,
+        };
+        ffi::debugger_set_pause_on_throw_mode(self.hermes_debugger(), mode);
+    }
+
+    /// Pause before the first statement of every script loaded.
+    pub fn set_pause_on_script_load(&mut self, pause: bool) {
+        ffi::debugger_set_pause_on_script_load(self.hermes_debugger(), pause);
+    }
+
+    /// Evaluate JavaScript in an unoptimized form, so that every statement can be paused
+    /// at and stepped through.
+    pub fn debug_javascript(&mut self, source: &str, source_url: Option<&str>) -> Result<()> {
+        let url = source_url.unwrap_or("eval");
+        let result = ffi::debug_javascript(self.runtime.hermes(), source, url);
+        self.shared.resume_panic();
+        Ok(result?)
+    }
+
+    fn hermes_debugger(&mut self) -> Pin<&mut ffi::HermesDebugger> {
+        ffi::get_debugger(self.runtime.hermes())
+    }
+}
+
+impl Deref for Debugger<'_> {
+    type Target = Runtime;
+
+    fn deref(&self) -> &Runtime {
+        self.runtime
+    }
+}
+
+impl DerefMut for Debugger<'_> {
+    fn deref_mut(&mut self) -> &mut Runtime {
+        self.runtime
+    }
+}
+
+impl Drop for Debugger<'_> {
+    fn drop(&mut self) {
+        ffi::debugger_delete_all_breakpoints(self.hermes_debugger());
+        ffi::debugger_set_pause_on_throw_mode(self.hermes_debugger(), 0);
+        ffi::debugger_set_pause_on_script_load(self.hermes_debugger(), false);
+        self.observer = UniquePtr::null();
+
+        if !std::thread::panicking() {
+            self.shared.resume_panic();
+        }
+    }
+}
+
+/// State of a paused program, passed to the `Debugger::on_pause()` callback.
+pub struct Pause<'a> {
+    debugger: Pin<&'a mut ffi::HermesDebugger>,
+    runtime: JSRuntime<'a>,
+    stack: Vec<StackFrame>,
+    step: StepMode,
+}
+
+impl<'a> Pause<'a> {
+    pub fn reason(&self) -> PauseReason {
+        match ffi::debugger_pause_reason(&self.debugger) {
+            0 => PauseReason::ScriptLoaded,
+            1 => PauseReason::DebuggerStatement,
+            2 => PauseReason::Breakpoint,
+            3 => PauseReason::StepFinish,
+            4 => PauseReason::Exception,
+            5 => PauseReason::AsyncTrigger,
+            _ => PauseReason::Other,
+        }
+    }
+
+    /// Breakpoint the program paused at.
+    pub fn breakpoint(&self) -> Option<BreakpointId> {
+        match ffi::debugger_paused_breakpoint(&self.debugger) {
+            0 => None,
+            id => Some(BreakpointId(id)),
+        }
+    }
+
+    /// Call stack, the innermost frame first.
+    pub fn stack(&self) -> &[StackFrame] {
+        &self.stack
+    }
+
+    /// Variables of each scope of the frame at `frame` in `stack()`, innermost scope first.
+    pub fn scopes(&mut self, frame: usize) -> Vec<Vec<Variable>> {
+        if frame >= self.stack.len() {
+            return Vec::new();
+        }
+
+        let mut sizes = Vec::new();
+        ffi::debugger_scope_sizes(&self.debugger, frame as u32, &mut sizes);
+
+        let mut scopes = Vec::with_capacity(sizes.len());
+        for (scope, size) in sizes.into_iter().enumerate() {
+            let mut variables = Vec::with_capacity(size as usize);
+            for index in 0..size {
+                let mut name = String::new();
+                let value = ffi::debugger_variable(
+                    &self.debugger,
+                    frame as u32,
+                    scope as u32,
+                    index,
+                    &mut name,
+                );
+                variables.push(Variable {
+                    name,
+                    value: JSValue::from_unique_ptr(&self.runtime, value),
+                });
+            }
+            scopes.push(variables);
+        }
+        scopes
+    }
+
+    /// Value of the innermost variable named `name` visible in the frame at `frame`.
+    pub fn variable(&mut self, frame: usize, name: &str) -> Option<JSValue> {
+        self.scopes(frame)
+            .into_iter()
+            .flatten()
+            .find(|variable| variable.name == name)
+            .map(|variable| variable.value)
+    }
+
+    /// `this` of the frame at `frame`.
+    pub fn this_value(&mut self, frame: usize) -> Option<JSValue> {
+        if frame >= self.stack.len() {
+            return None;
+        }
+        let value = ffi::debugger_this(&self.debugger, frame as u32);
+        Some(JSValue::from_unique_ptr(&self.runtime, value))
+    }
+
+    /// The paused runtime, e.g. to convert variables with `FromJs`.
+    pub fn runtime(&mut self) -> &mut JSRuntime<'a> {
+        &mut self.runtime
+    }
+
+    /// How to resume once the callback returns, `StepMode::Continue` by default.
+    pub fn resume(&mut self, mode: StepMode) {
+        self.step = mode;
+    }
+}
+
+#[derive(Default)]
+struct HandlerState {
+    callback: RefCell<Option<PauseCallback>>,
+    panic: RefCell<Option<Box<dyn Any + Send>>>,
+}
+
+impl HandlerState {
+    fn resume_panic(&self) {
+        if let Some(payload) = self.panic.borrow_mut().take() {
+            panic::resume_unwind(payload);
+        }
+    }
+}
+
+/// Pause callback owned by the C++ observer
+pub(crate) struct PauseHandler {
+    shared: Rc<HandlerState>,
+    runtime: *mut JSIRuntime,
+    state: Arc<RuntimeState>,
+}
+
+fn debugger_did_pause(handler: &mut PauseHandler, debugger: Pin<&mut ffi::HermesDebugger>) -> u8 {
+    let shared = &handler.shared;
+    // Keep going after a panic or when a pause happens inside the callback
+    if shared.panic.borrow().is_some() {
+        return 0;
+    }
+    let Ok(mut callback) = shared.callback.try_borrow_mut() else {
+        return 0;
+    };
+    let Some(callback) = callback.as_mut() else {
+        return 0;
+    };
+
+    let mut function_names = Vec::new();
+    let mut urls = Vec::new();
+    let mut lines = Vec::new();
+    let mut columns = Vec::new();
+    ffi::debugger_stack_trace(
+        &debugger,
+        &mut function_names,
+        &mut urls,
+        &mut lines,
+        &mut columns,
+    );
+    let stack = function_names
+        .into_iter()
+        .zip(urls)
+        .zip(lines.into_iter().zip(columns))
+        .map(|((function_name, url), (line, column))| StackFrame {
+            function_name,
+            location: SourceLocation { url, line, column },
+        })
+        .collect();
+
+    let mut pause = Pause {
+        debugger,
+        // SAFETY: the runtime is paused on this thread and outlives the callback
+        runtime: unsafe { JSRuntime::from_raw_with_state(handler.runtime, handler.state.clone()) },
+        stack,
+        step: StepMode::Continue,
+    };
+
+    match panic::catch_unwind(AssertUnwindSafe(|| callback(&mut pause))) {
+        Ok(()) => match pause.step {
+            StepMode::Continue => 0,
+            StepMode::Into => 1,
+            StepMode::Over => 2,
+            StepMode::Out => 3,
+        },
+        Err(payload) => {
+            *shared.panic.borrow_mut() = Some(payload);
+            0
+        }
+    }
+}
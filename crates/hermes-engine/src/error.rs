@@ -125,6 +125,8 @@ impl From<cxx::Exception> for Error {
     }
 }
 
+impl jsi_rs::ScopeOutput for Error {}
+
 impl From<jsi_rs::Error> for Error {
     fn from(e: jsi_rs::Error) -> Self {
         let msg = e.message();
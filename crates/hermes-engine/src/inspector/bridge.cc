@@ -0,0 +1,46 @@
+#include "hermes-engine/src/inspector/bridge.rs.h"
+
+#include <string>
+#include <utility>
+
+namespace {
+
+// Execution context reported to the client, a session serves a single runtime
+constexpr int32_t kExecutionContextId = 1;
+
+} // namespace
+
+CdpSession::CdpSession(
+    facebook::hermes::HermesRuntime& runtime,
+    rust::Box<InspectorChannel> channel)
+    : channel_(std::move(channel)),
+      debug_api_(facebook::hermes::cdp::CDPDebugAPI::create(runtime)) {
+
+    const InspectorChannel* target = &*channel_;
+    agent_ = facebook::hermes::cdp::CDPAgent::create(
+        kExecutionContextId,
+        *debug_api_,
+        [target](facebook::hermes::debugger::RuntimeTask task) {
+            inspector_enqueue(*target, std::make_unique<RuntimeTask>(RuntimeTask{std::move(task)}));
+        },
+        [target](const std::string& message) {
+            rust::Slice<const uint8_t> bytes(
+                reinterpret_cast<const uint8_t*>(message.data()), message.size());
+            inspector_send(*target, bytes);
+        });
+}
+
+void CdpSession::handle_command(rust::Str message) const {
+    agent_->handleCommand(std::string(message));
+}
+
+std::unique_ptr<CdpSession> create_cdp_session(
+    facebook::hermes::HermesRuntime& runtime,
+    rust::Box<InspectorChannel> channel) {
+
+    return std::make_unique<CdpSession>(runtime, std::move(channel));
+}
+
+void run_runtime_task(std::unique_ptr<RuntimeTask> task, facebook::hermes::HermesRuntime& runtime) {
+    task->run(runtime);
+}
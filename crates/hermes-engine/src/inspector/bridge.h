@@ -0,0 +1,41 @@
+#pragma once
+
+#include <memory>
+
+#include <hermes/AsyncDebuggerAPI.h>
+#include <hermes/cdp/CDPAgent.h>
+#include <hermes/cdp/CDPDebugAPI.h>
+#include <hermes/hermes.h>
+
+#include "rust/cxx.h"
+
+// Rust side of an inspector session, receives outbound messages and runtime tasks
+struct InspectorChannel;
+
+// Work the CDP agent needs done on the runtime's thread
+struct RuntimeTask {
+    facebook::hermes::debugger::RuntimeTask run;
+};
+
+// CDP agent of a runtime, see facebook::hermes::cdp::CDPAgent
+class CdpSession {
+public:
+    CdpSession(facebook::hermes::HermesRuntime& runtime, rust::Box<InspectorChannel> channel);
+
+    // Handle a CDP command, may be called from any thread
+    void handle_command(rust::Str message) const;
+
+private:
+    // Declared first so the agent's callbacks never outlive it
+    rust::Box<InspectorChannel> channel_;
+    std::unique_ptr<facebook::hermes::cdp::CDPDebugAPI> debug_api_;
+    std::unique_ptr<facebook::hermes::cdp::CDPAgent> agent_;
+};
+
+// Create the CDP agent, must be called on the runtime's thread
+std::unique_ptr<CdpSession> create_cdp_session(
+    facebook::hermes::HermesRuntime& runtime,
+    rust::Box<InspectorChannel> channel);
+
+// Run a task handed over through InspectorChannel on the runtime's thread
+void run_runtime_task(std::unique_ptr<RuntimeTask> task, facebook::hermes::HermesRuntime& runtime);
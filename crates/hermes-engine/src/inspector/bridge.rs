@@ -0,0 +1,51 @@
+// Rust/C++ bridge for the Hermes CDP agent, only built with the `debugger` feature
+
+use super::{inspector_enqueue, inspector_send, InspectorChannel};
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("hermes-engine/src/inspector/bridge.h");
+
+        // Shared with the main bridge
+        #[namespace = "facebook::hermes"]
+        type HermesRuntime = crate::bridge::ffi::HermesRuntime;
+
+        // Work queued by the agent for the runtime's thread
+        type RuntimeTask;
+
+        // CDP agent attached to a runtime
+        type CdpSession;
+
+        // Create the CDP agent, must be called on the runtime's thread
+        fn create_cdp_session(
+            runtime: Pin<&mut HermesRuntime>,
+            channel: Box<InspectorChannel>,
+        ) -> Result<UniquePtr<CdpSession>>;
+
+        // Handle a CDP command from the client, may be called from any thread
+        fn handle_command(self: &CdpSession, message: &str) -> Result<()>;
+
+        // Run a queued task, must be called on the runtime's thread
+        fn run_runtime_task(task: UniquePtr<RuntimeTask>, runtime: Pin<&mut HermesRuntime>);
+    }
+
+    extern "Rust" {
+        // Receiver of the agent's messages and tasks
+        type InspectorChannel;
+
+        // Forward a CDP response or event to the client, called from any thread
+        fn inspector_send(channel: &InspectorChannel, message: &[u8]);
+
+        // Queue work for the runtime's thread, called from any thread
+        fn inspector_enqueue(channel: &InspectorChannel, task: UniquePtr<RuntimeTask>);
+    }
+}
+
+// SAFETY: tasks are plain closures the agent expects to be run on another thread
+unsafe impl Send for ffi::RuntimeTask {}
+
+// SAFETY: CdpSession::handle_command is thread-safe, everything else is only used on the
+// runtime's thread by the owning Inspector
+unsafe impl Send for ffi::CdpSession {}
+unsafe impl Sync for ffi::CdpSession {}
@@ -0,0 +1,243 @@
+//! Chrome DevTools Protocol inspector, see [`Inspector`].
+
+use std::collections::VecDeque;
+use std::net::{SocketAddr, TcpListener, ToSocketAddrs};
+use std::ops::{Deref, DerefMut};
+use std::sync::{mpsc, Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use cxx::UniquePtr;
+
+use crate::error::{Error, Result};
+use crate::runtime::Runtime;
+
+mod bridge;
+mod server;
+
+use bridge::ffi;
+use server::{Server, SessionRef, Target};
+
+/// Debugger attached to a runtime, serving the Chrome DevTools Protocol over a local
+/// WebSocket.
+///
+/// Created via `Runtime::start_inspector()`. Chrome DevTools (`chrome://inspect`) and
+/// VS Code find the runtime through the `/json/list` endpoint, or connect to
+/// `websocket_url()` directly, and can then set breakpoints, step and inspect scopes.
+///
+/// The inspector gives access to the runtime. Breakpoints pause JavaScript running
+/// through it, while the runtime is idle call `run_pending_tasks()` regularly so the
+/// debugger's requests are answered. Detaching the debugger resumes a paused runtime.
+///
+/// # Example
+/// ```no_run
+/// # use hermes_engine::{Runtime, RuntimeConfig};
+/// let mut runtime = Runtime::new(RuntimeConfig::default())?;
+///
+/// let mut inspector = runtime.start_inspector("127.0.0.1:9229")?;
+/// println!("Open {}", inspector.devtools_url());
+/// inspector.wait_for_debugger()?;
+///
+/// inspector.eval("debugger; 1 + 1", Some("app.js"))?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct Inspector<'a> {
+    // Stopped before the session it forwards commands to is destroyed
+    server: Option<Server>,
+    session: UniquePtr<ffi::CdpSession>,
+    shared: Arc<Shared>,
+    target: Target,
+    runtime: &'a mut Runtime,
+}
+
+impl<'a> Inspector<'a> {
+    pub(crate) fn start(runtime: &'a mut Runtime, addr: impl ToSocketAddrs) -> Result<Self> {
+        let listener = TcpListener::bind(addr)
+            .map_err(|err| Error::runtime(format!("Failed to bind inspector: {err}")))?;
+
+        let shared = Arc::new(Shared::default());
+        let channel = Box::new(InspectorChannel {
+            shared: shared.clone(),
+        });
+        let session = ffi::create_cdp_session(runtime.hermes(), channel)?;
+
+        let target = Target {
+            // Unguessable, web pages can't connect without finding it through /json/list
+            id: uuid::Uuid::new_v4().to_string(),
+            title: "hermes-engine".to_string(),
+        };
+        let server = Server::start(
+            listener,
+            target.clone(),
+            SessionRef(&*session),
+            shared.clone(),
+        )
+        .map_err(|err| Error::runtime(format!("Failed to start inspector: {err}")))?;
+
+        Ok(Self {
+            server: Some(server),
+            session,
+            shared,
+            target,
+            runtime,
+        })
+    }
+
+    /// Address the inspector is listening on.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.server
+            .as_ref()
+            .expect("inspector server is running")
+            .local_addr()
+    }
+
+    /// WebSocket URL debuggers connect to.
+    pub fn websocket_url(&self) -> String {
+        format!("ws://{}/{}", self.local_addr(), self.target.id)
+    }
+
+    /// URL opening Chrome DevTools attached to this runtime.
+    pub fn devtools_url(&self) -> String {
+        format!(
+            "devtools://devtools/bundled/js_app.html?experiments=true&v8only=true&ws={}/{}",
+            self.local_addr(),
+            self.target.id
+        )
+    }
+
+    /// Whether a debugger is connected.
+    pub fn is_attached(&self) -> bool {
+        self.shared.state.lock().unwrap().client.is_some()
+    }
+
+    /// Block until a debugger is attached and done with its setup, e.g. setting the
+    /// breakpoints of scripts that are about to run.
+    pub fn wait_for_debugger(&mut self) -> Result<()> {
+        self.wait_until(None)
+    }
+
+    /// Like `wait_for_debugger()`, failing when no debugger is ready after `timeout`.
+    pub fn wait_for_debugger_timeout(&mut self, timeout: Duration) -> Result<()> {
+        self.wait_until(Some(Instant::now() + timeout))
+    }
+
+    fn wait_until(&mut self, deadline: Option<Instant>) -> Result<()> {
+        loop {
+            self.run_pending_tasks();
+
+            let state = self.shared.state.lock().unwrap();
+            if state.run_requested {
+                return Ok(());
+            }
+            if !state.tasks.is_empty() {
+                continue;
+            }
+
+            match deadline {
+                Some(deadline) => {
+                    let Some(wait) = deadline.checked_duration_since(Instant::now()) else {
+                        return Err(Error::runtime("Timed out waiting for a debugger"));
+                    };
+                    drop(self.shared.changed.wait_timeout(state, wait).unwrap());
+                }
+                None => drop(self.shared.changed.wait(state).unwrap()),
+            }
+        }
+    }
+
+    /// Handle the debugger's requests queued while the runtime was idle, returns how many
+    /// were handled.
+    pub fn run_pending_tasks(&mut self) -> usize {
+        let mut count = 0;
+        // Tasks may queue more tasks, the lock must not be held while they run
+        while let Some(task) = self.shared.pop_task() {
+            ffi::run_runtime_task(task, self.runtime.hermes());
+            count += 1;
+        }
+        count
+    }
+}
+
+impl Deref for Inspector<'_> {
+    type Target = Runtime;
+
+    fn deref(&self) -> &Runtime {
+        self.runtime
+    }
+}
+
+impl DerefMut for Inspector<'_> {
+    fn deref_mut(&mut self) -> &mut Runtime {
+        self.runtime
+    }
+}
+
+impl Drop for Inspector<'_> {
+    fn drop(&mut self) {
+        // Disconnects the client and joins the server threads
+        self.server = None;
+        // Destroy the agent on the runtime's thread before the tasks it queued
+        self.session = UniquePtr::null();
+        self.shared.state.lock().unwrap().tasks.clear();
+    }
+}
+
+/// State shared between the runtime's thread, the server and the agent's callbacks
+#[derive(Default)]
+struct Shared {
+    state: Mutex<State>,
+    /// Signaled when a task is queued or the debugger asks to run
+    changed: Condvar,
+}
+
+#[derive(Default)]
+struct State {
+    tasks: VecDeque<UniquePtr<ffi::RuntimeTask>>,
+    /// Messages to the connected client
+    client: Option<mpsc::Sender<String>>,
+    /// Set by `Runtime.runIfWaitingForDebugger`
+    run_requested: bool,
+}
+
+impl Shared {
+    fn pop_task(&self) -> Option<UniquePtr<ffi::RuntimeTask>> {
+        self.state.lock().unwrap().tasks.pop_front()
+    }
+
+    /// Register the client, `false` when another one is connected
+    fn attach_client(&self, sender: mpsc::Sender<String>) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if state.client.is_some() {
+            return false;
+        }
+        state.client = Some(sender);
+        true
+    }
+
+    fn detach_client(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.client = None;
+        state.run_requested = false;
+    }
+
+    fn request_run(&self) {
+        self.state.lock().unwrap().run_requested = true;
+        self.changed.notify_all();
+    }
+}
+
+/// Receiver of the agent's messages and tasks, owned by the C++ session
+pub(crate) struct InspectorChannel {
+    shared: Arc<Shared>,
+}
+
+fn inspector_send(channel: &InspectorChannel, message: &[u8]) {
+    let state = channel.shared.state.lock().unwrap();
+    if let Some(client) = &state.client {
+        let _ = client.send(String::from_utf8_lossy(message).into_owned());
+    }
+}
+
+fn inspector_enqueue(channel: &InspectorChannel, task: UniquePtr<ffi::RuntimeTask>) {
+    channel.shared.state.lock().unwrap().tasks.push_back(task);
+    channel.shared.changed.notify_all();
+}
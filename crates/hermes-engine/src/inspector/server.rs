@@ -0,0 +1,427 @@
+use std::io::{self, Cursor, ErrorKind, Read, Write};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use tungstenite::handshake::machine::TryParse;
+use tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tungstenite::http::StatusCode;
+use tungstenite::{Message, WebSocket};
+
+use super::bridge::ffi;
+use super::Shared;
+
+/// How long a client connection waits for a command before checking for outbound messages
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Upper bound of an HTTP request head
+const MAX_REQUEST_HEAD: usize = 16 * 1024;
+
+/// Pointer to the session owned by the `Inspector`, which joins the server before
+/// destroying the session
+#[derive(Clone, Copy)]
+pub(super) struct SessionRef(pub(super) *const ffi::CdpSession);
+
+// SAFETY: CdpSession is Sync and outlives the server threads, see above
+unsafe impl Send for SessionRef {}
+
+impl SessionRef {
+    fn handle_command(self, message: &str) {
+        // SAFETY: see SessionRef
+        let _ = unsafe { &*self.0 }.handle_command(message);
+    }
+}
+
+/// HTTP and WebSocket endpoint of an inspector
+///
+/// Serves the `/json/version` and `/json/list` discovery endpoints used by Chrome and
+/// VS Code, and forwards CDP messages between a single WebSocket client and the agent.
+pub(super) struct Server {
+    local_addr: SocketAddr,
+    shutdown: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Server {
+    pub(super) fn start(
+        listener: TcpListener,
+        target: Target,
+        session: SessionRef,
+        shared: Arc<Shared>,
+    ) -> io::Result<Self> {
+        let local_addr = listener.local_addr()?;
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let thread = thread::Builder::new()
+            .name("hermes-inspector".into())
+            .spawn({
+                let shutdown = shutdown.clone();
+                move || accept_loop(listener, target, session, shared, shutdown)
+            })?;
+
+        Ok(Self {
+            local_addr,
+            shutdown,
+            thread: Some(thread),
+        })
+    }
+
+    pub(super) fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // Wake up the blocking accept, through loopback when bound to all interfaces
+        let mut wake = self.local_addr;
+        if wake.ip().is_unspecified() {
+            wake.set_ip(match wake {
+                SocketAddr::V4(_) => IpAddr::V4(Ipv4Addr::LOCALHOST),
+                SocketAddr::V6(_) => IpAddr::V6(Ipv6Addr::LOCALHOST),
+            });
+        }
+        let _ = TcpStream::connect(wake);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Debugging target advertised by the discovery endpoints
+#[derive(Clone)]
+pub(super) struct Target {
+    pub(super) id: String,
+    pub(super) title: String,
+}
+
+impl Target {
+    fn list_json(&self, host: &str) -> String {
+        let ws = format!("{host}/{}", self.id);
+        serde_json::json!([{
+            "description": "hermes-engine",
+            "devtoolsFrontendUrl": format!(
+                "devtools://devtools/bundled/js_app.html?experiments=true&v8only=true&ws={ws}"
+            ),
+            "id": self.id,
+            "title": self.title,
+            "type": "node",
+            "webSocketDebuggerUrl": format!("ws://{ws}"),
+        }])
+        .to_string()
+    }
+}
+
+fn accept_loop(
+    listener: TcpListener,
+    target: Target,
+    session: SessionRef,
+    shared: Arc<Shared>,
+    shutdown: Arc<AtomicBool>,
+) {
+    let mut connections = Vec::new();
+
+    for stream in listener.incoming() {
+        if shutdown.load(Ordering::SeqCst) {
+            break;
+        }
+        let Ok(stream) = stream else {
+            continue;
+        };
+
+        let (target, shared, shutdown) = (target.clone(), shared.clone(), shutdown.clone());
+        connections.retain(|connection: &JoinHandle<()>| !connection.is_finished());
+        if let Ok(connection) = thread::Builder::new()
+            .name("hermes-inspector-client".into())
+            .spawn(move || {
+                let _ = handle_connection(stream, &target, session, &shared, &shutdown);
+            })
+        {
+            connections.push(connection);
+        }
+    }
+
+    for connection in connections {
+        let _ = connection.join();
+    }
+}
+
+fn handle_connection(
+    mut stream: TcpStream,
+    target: &Target,
+    session: SessionRef,
+    shared: &Shared,
+    shutdown: &AtomicBool,
+) -> io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    let (head, request) = read_request(&mut stream)?;
+
+    if request.headers().contains_key("Sec-WebSocket-Key") {
+        let (sender, receiver) = mpsc::channel();
+        let mut attached = false;
+        // The error type is tungstenite's
+        #[allow(clippy::result_large_err)]
+        let accept = |request: &Request, response: Response| {
+            check_request(request).map_err(|(status, message)| error_response(status, message))?;
+            if request.uri().path().trim_start_matches('/') != target.id {
+                return Err(error_response(StatusCode::NOT_FOUND, "Unknown target"));
+            }
+            attached = shared.attach_client(sender);
+            if !attached {
+                return Err(error_response(
+                    StatusCode::CONFLICT,
+                    "A debugger is already attached",
+                ));
+            }
+            Ok(response)
+        };
+
+        // The handshake reads the request again from the head already received
+        let replay = Replay {
+            head: Cursor::new(head),
+            stream,
+        };
+        let result = tungstenite::accept_hdr(replay, accept)
+            .map_err(|err| io::Error::new(ErrorKind::InvalidData, err.to_string()))
+            .and_then(|socket| {
+                socket
+                    .get_ref()
+                    .stream
+                    .set_read_timeout(Some(POLL_INTERVAL))?;
+                serve_client(socket, receiver, session, shared, shutdown)
+            });
+
+        if attached {
+            shared.detach_client();
+            // Resume a paused runtime and drop the client's breakpoints
+            session.handle_command("{\"id\":0,\"method\":\"Debugger.disable\"}");
+        }
+        return result;
+    }
+
+    if let Err((status, message)) = check_request(&request) {
+        return respond(&mut stream, status, "text/plain", message);
+    }
+
+    let host = match request
+        .headers()
+        .get("Host")
+        .and_then(|host| host.to_str().ok())
+    {
+        Some(host) => host.to_string(),
+        None => target_host(&stream),
+    };
+    match request.uri().path() {
+        "/json/version" => respond(
+            &mut stream,
+            StatusCode::OK,
+            "application/json",
+            &format!(
+                "{{\"Browser\":\"hermes-engine/{}\",\"Protocol-Version\":\"1.3\"}}",
+                env!("CARGO_PKG_VERSION")
+            ),
+        ),
+        "/json" | "/json/list" => respond(
+            &mut stream,
+            StatusCode::OK,
+            "application/json",
+            &target.list_json(&host),
+        ),
+        _ => respond(
+            &mut stream,
+            StatusCode::NOT_FOUND,
+            "text/plain",
+            "Not found",
+        ),
+    }
+}
+
+/// Reject requests a web page could have sent: through DNS rebinding, with a `Host` other
+/// than localhost or a loopback address, or cross-site, with an `Origin` from another site.
+fn check_request(request: &Request) -> Result<(), (StatusCode, &'static str)> {
+    let header = |name: &str| {
+        request
+            .headers()
+            .get(name)
+            .map(|value| value.to_str().unwrap_or_default())
+    };
+
+    if let Some(host) = header("Host") {
+        if !is_local_host(host) {
+            return Err((StatusCode::FORBIDDEN, "Host not allowed"));
+        }
+    }
+    if let Some(origin) = header("Origin") {
+        if !is_local_origin(origin) {
+            return Err((StatusCode::FORBIDDEN, "Origin not allowed"));
+        }
+    }
+    Ok(())
+}
+
+/// Whether a `Host` header names localhost or a loopback address, with an optional port
+fn is_local_host(host: &str) -> bool {
+    let name = match host.strip_prefix('[') {
+        // IPv6 literal
+        Some(rest) => match rest.split_once(']') {
+            Some((address, port)) if port.is_empty() || is_port(port) => {
+                return address.parse::<Ipv6Addr>().is_ok_and(|ip| ip.is_loopback());
+            }
+            _ => return false,
+        },
+        None => match host.rsplit_once(':') {
+            Some((name, port)) if is_port(port) => name,
+            Some(_) => return false,
+            None => host,
+        },
+    };
+    name.eq_ignore_ascii_case("localhost")
+        || name.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback())
+}
+
+fn is_port(port: &str) -> bool {
+    port.strip_prefix(':')
+        .unwrap_or(port)
+        .parse::<u16>()
+        .is_ok()
+}
+
+/// Whether an `Origin` header is the DevTools frontend or a page served from localhost or
+/// a loopback address
+fn is_local_origin(origin: &str) -> bool {
+    match origin.split_once("://") {
+        Some(("devtools", _)) => true,
+        Some((_, host)) => is_local_host(host),
+        None => false,
+    }
+}
+
+fn error_response(status: StatusCode, message: &str) -> ErrorResponse {
+    let mut response = ErrorResponse::new(Some(message.to_string()));
+    *response.status_mut() = status;
+    response
+}
+
+/// Forward messages in both directions until the client leaves or the server stops
+fn serve_client(
+    mut socket: WebSocket<Replay>,
+    outbound: mpsc::Receiver<String>,
+    session: SessionRef,
+    shared: &Shared,
+    shutdown: &AtomicBool,
+) -> io::Result<()> {
+    let into_io = |err: tungstenite::Error| match err {
+        tungstenite::Error::Io(err) => err,
+        err => io::Error::other(err),
+    };
+
+    while !shutdown.load(Ordering::SeqCst) {
+        let mut pending = false;
+        while let Ok(message) = outbound.try_recv() {
+            socket.write(Message::text(message)).map_err(into_io)?;
+            pending = true;
+        }
+        if pending {
+            socket.flush().map_err(into_io)?;
+        }
+
+        match socket.read() {
+            Ok(Message::Text(command)) => {
+                session.handle_command(command.as_str());
+                if is_run_if_waiting(command.as_str()) {
+                    shared.request_run();
+                }
+            }
+            Ok(Message::Close(_)) => break,
+            Ok(_) => {}
+            Err(tungstenite::Error::Io(err))
+                if matches!(err.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+            Err(tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed) => break,
+            Err(err) => return Err(into_io(err)),
+        }
+    }
+
+    let _ = socket.close(None);
+    let _ = socket.flush();
+    Ok(())
+}
+
+/// Whether a command is `Runtime.runIfWaitingForDebugger`, sent by clients once they are
+/// done setting up breakpoints
+fn is_run_if_waiting(command: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(command).is_ok_and(|command| {
+        command.get("method").and_then(serde_json::Value::as_str)
+            == Some("Runtime.runIfWaitingForDebugger")
+    })
+}
+
+/// Read and parse the request head, returning the bytes read so far along with it
+fn read_request(stream: &mut TcpStream) -> io::Result<(Vec<u8>, Request)> {
+    let invalid = |message: String| io::Error::new(ErrorKind::InvalidData, message);
+    let mut head = Vec::new();
+    let mut buffer = [0u8; 1024];
+    loop {
+        let read = stream.read(&mut buffer)?;
+        if read == 0 {
+            return Err(invalid("Incomplete HTTP request".into()));
+        }
+        head.extend_from_slice(&buffer[..read]);
+
+        match Request::try_parse(&head) {
+            Ok(Some((_, request))) => return Ok((head, request)),
+            Ok(None) if head.len() < MAX_REQUEST_HEAD => {}
+            Ok(None) => return Err(invalid("HTTP request head too large".into())),
+            Err(err) => return Err(invalid(err.to_string())),
+        }
+    }
+}
+
+/// Connection replaying the request head before reading from the socket
+struct Replay {
+    head: Cursor<Vec<u8>>,
+    stream: TcpStream,
+}
+
+impl Read for Replay {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.head.read(buf)? {
+            0 => self.stream.read(buf),
+            read => Ok(read),
+        }
+    }
+}
+
+impl Write for Replay {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.stream.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+fn respond(
+    stream: &mut TcpStream,
+    status: StatusCode,
+    content_type: &str,
+    body: &str,
+) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}; charset=UTF-8\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()
+}
+
+fn target_host(stream: &TcpStream) -> String {
+    stream
+        .local_addr()
+        .map(|addr| addr.to_string())
+        .unwrap_or_default()
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use std::io::Write;
+
+use serde_json::Value;
+
+use crate::bridge::ffi;
+use crate::error::{Error, Result};
+use crate::runtime::Runtime;
+use crate::stream::WriteSink;
+
+/// Heap statistics and GC control of a runtime, see `jsi::Instrumentation`.
+///
+/// Created via `Runtime::instrumentation()`.
+///
+/// # Example
+/// ```no_run
+/// # use hermes_engine::{Runtime, RuntimeConfig};
+/// let mut runtime = Runtime::new(RuntimeConfig::default())?;
+/// let mut instrumentation = runtime.instrumentation();
+///
+/// instrumentation.collect_garbage("metrics");
+/// let heap = instrumentation.heap_info(false);
+/// println!("allocated: {:?}", heap.get("hermes_allocatedBytes"));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct Instrumentation<'a> {
+    runtime: &'a mut Runtime,
+}
+
+/// GC statistics recorded over the lifetime of a runtime.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GcStats {
+    /// Implementation producing the statistics, `"hermes"`
+    pub kind: String,
+    /// Revision of the implementation's output format
+    pub version: i64,
+    /// Numeric statistics of the `heapInfo` section, keyed by name
+    pub heap_info: HashMap<String, f64>,
+    /// The statistics as reported by the runtime, JSON-encoded
+    pub json: String,
+}
+
+impl<'a> Instrumentation<'a> {
+    pub(crate) fn new(runtime: &'a mut Runtime) -> Self {
+        Self { runtime }
+    }
+
+    /// Current heap statistics, e.g. `hermes_allocatedBytes` or `hermes_heapSize`.
+    ///
+    /// `include_expensive` adds statistics that take longer to compute, such as the
+    /// malloc size estimate.
+    pub fn heap_info(&mut self, include_expensive: bool) -> HashMap<String, i64> {
+        let mut keys = Vec::new();
+        let mut values = Vec::new();
+        ffi::instrumentation_heap_info(
+            self.runtime.hermes(),
+            include_expensive,
+            &mut keys,
+            &mut values,
+        );
+        keys.into_iter().zip(values).collect()
+    }
+
+    /// Perform a full garbage collection, `cause` is reported in GC logs.
+    pub fn collect_garbage(&mut self, cause: &str) {
+        ffi::instrumentation_collect_garbage(self.runtime.hermes(), cause);
+    }
+
+    /// GC statistics collected so far.
+    ///
+    /// Only available for runtimes created with `RuntimeConfigBuilder::record_gc_stats(true)`.
+    pub fn recorded_gc_stats(&mut self) -> Result<GcStats> {
+        let output = ffi::instrumentation_recorded_gc_stats(self.runtime.hermes());
+        // The JSON object may be surrounded by plain text dumps
+        let start = output.find('{').unwrap_or(output.len());
+        let Some(end) = output[start..].rfind('}').map(|end| start + end) else {
+            return Err(Error::runtime(
+                "GC statistics are not recorded, enable RuntimeConfigBuilder::record_gc_stats",
+            ));
+        };
+        let json = output[start..=end].to_string();
+
+        let stats: Value = serde_json::from_str(&json)
+            .map_err(|err| Error::internal(format!("Invalid GC statistics: {err}")))?;
+        if !stats.is_object() {
+            return Err(Error::internal("GC statistics are not a JSON object"));
+        }
+
+        let kind = stats
+            .get("type")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        let version = stats.get("version").and_then(Value::as_f64).unwrap_or(0.0) as i64;
+
+        let heap_info = stats
+            .get("heapInfo")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+            .filter_map(|(key, value)| Some((key.clone(), value.as_f64()?)))
+            .collect();
+
+        Ok(GcStats {
+            kind,
+            version,
+            heap_info,
+            json,
+        })
+    }
+
+    /// Write a heap snapshot in Chrome's `.heapsnapshot` format.
+    ///
+    /// The snapshot is streamed to `writer` as it is produced, load it in the Memory tab
+    /// of Chrome DevTools.
+    pub fn write_heap_snapshot<W: Write>(&mut self, mut writer: W) -> Result<()> {
+        let runtime = &mut *self.runtime;
+        let mut result = Ok(());
+        WriteSink::run(&mut writer, |sink| {
+            result = ffi::instrumentation_create_heap_snapshot(runtime.hermes(), sink);
+        })
+        .map_err(|err| Error::runtime(format!("Failed to write heap snapshot: {err}")))?;
+        Ok(result?)
+    }
+
+    /// Start the sampling heap profiler, recording the stack of an allocation every
+    /// `interval` bytes on average.
+    pub fn start_heap_sampling(&mut self, interval: usize) -> Result<()> {
+        Ok(ffi::instrumentation_start_heap_sampling(
+            self.runtime.hermes(),
+            interval,
+        )?)
+    }
+
+    /// Stop the sampling heap profiler and write its profile to `writer`.
+    ///
+    /// The profile is JSON in Chrome's `.heapprofile` format.
+    pub fn stop_heap_sampling<W: Write>(&mut self, mut writer: W) -> Result<()> {
+        let runtime = &mut *self.runtime;
+        let mut result = Ok(());
+        WriteSink::run(&mut writer, |sink| {
+            result = ffi::instrumentation_stop_heap_sampling(runtime.hermes(), sink);
+        })
+        .map_err(|err| Error::runtime(format!("Failed to write heap profile: {err}")))?;
+        Ok(result?)
+    }
+}
@@ -13,6 +13,42 @@ pub use config::{RuntimeConfig, RuntimeConfigBuilder};
 mod runtime;
 pub use runtime::{CompiledBytecode, PreparedJavaScript, Runtime};
 
+mod coverage;
+pub use coverage::{
+    CodeCoverage, CoverageReport, ExecutedFunction, FunctionCoverage, OriginalPosition, SourceMap,
+};
+
+mod crash;
+pub use crash::{set_fatal_handler, CrashCallback, CrashManager};
+
+#[cfg(feature = "debugger")]
+mod debugger;
+#[cfg(feature = "debugger")]
+pub use debugger::{
+    BreakpointId, Debugger, Pause, PauseOnExceptions, PauseReason, SourceLocation, StackFrame,
+    StepMode, Variable,
+};
+
+#[cfg(feature = "debugger")]
+pub mod inspector;
+
+mod instrumentation;
+pub use instrumentation::{GcStats, Instrumentation};
+
+mod pool;
+pub use pool::{PooledRuntime, RuntimePool, RuntimePoolBuilder};
+
+mod profiler;
+pub use profiler::{CallFrame, Profile, ProfileNode, Profiler, Sample, TraceFrame, TraceSample};
+
+mod runtime_handle;
+pub use runtime_handle::{JobHandle, RuntimeHandle};
+
+mod stream;
+
+mod thread_safe;
+pub use thread_safe::{RuntimeLock, ThreadSafeRuntime};
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -222,4 +258,879 @@ mod tests {
         assert_eq!(bytes.len(), bytecode.len());
         Ok(())
     }
+
+    #[test]
+    fn test_thread_safe_runtime_across_threads() -> Result<()> {
+        let runtime = std::sync::Arc::new(ThreadSafeRuntime::new(RuntimeConfig::default())?);
+        runtime.lock().eval("globalThis.counter = 0", None)?;
+
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let runtime = runtime.clone();
+                std::thread::spawn(move || -> Result<()> {
+                    for _ in 0..100 {
+                        runtime.lock().eval("counter++", None)?;
+                    }
+                    Ok(())
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap()?;
+        }
+
+        let counter = runtime.lock().eval_with_result("counter", None)?;
+        assert_eq!(counter.as_number(), Some(400.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_thread_safe_runtime_lock_is_recursive() -> Result<()> {
+        let runtime = ThreadSafeRuntime::new(RuntimeConfig::default())?;
+
+        let mut outer = runtime.lock();
+        let object = outer.jsi().create_object();
+        {
+            let mut inner = runtime.lock();
+            object.set(&mut inner.jsi(), "value", &jsi::JSValue::number(1.0));
+        }
+        assert_eq!(object.get(&mut outer.jsi(), "value").as_number(), Some(1.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_runtime_handle_spawn() -> Result<()> {
+        let handle = RuntimeHandle::new(RuntimeConfig::default())?;
+
+        handle
+            .spawn(|rt| rt.eval("globalThis.greeting = 'hello'", None))
+            .join()?;
+
+        let other = handle.clone();
+        let greeting = std::thread::spawn(move || {
+            other
+                .spawn(|rt| {
+                    let value = rt.eval_with_result("greeting + ' world'", None)?;
+                    let mut jsi_runtime = rt.jsi();
+                    Ok(value
+                        .as_string(&mut jsi_runtime)
+                        .map(|s| s.value(&mut jsi_runtime)))
+                })
+                .join()
+        })
+        .join()
+        .unwrap()?;
+
+        assert_eq!(greeting.as_deref(), Some("hello world"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_runtime_handle_errors_and_panics() -> Result<()> {
+        let handle = RuntimeHandle::new(RuntimeConfig::default())?;
+
+        let error = handle
+            .spawn(|rt| rt.eval("throw new Error('boom')", None))
+            .join();
+        assert!(error.is_err_and(|err| err.contains("boom")));
+
+        let panicked = handle
+            .spawn(|_rt| -> Result<()> { panic!("job failed") })
+            .join();
+        assert!(panicked.is_err());
+
+        // The runtime keeps serving jobs after a panic
+        let value = handle
+            .spawn(|rt| Ok(rt.eval_with_result("1 + 1", None)?.as_number()))
+            .join()?;
+        assert_eq!(value, Some(2.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_runtime_handle_job_as_future() -> Result<()> {
+        use std::future::Future;
+        use std::sync::Arc;
+        use std::task::{Context, Poll, Wake, Waker};
+
+        struct ThreadWaker(std::thread::Thread);
+
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let handle = RuntimeHandle::new(RuntimeConfig::default())?;
+        let mut job = std::pin::pin!(
+            handle.spawn(|rt| Ok(rt.eval_with_result("2 ** 10", None)?.as_number()))
+        );
+
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let value = loop {
+            match job.as_mut().poll(&mut cx) {
+                Poll::Ready(value) => break value?,
+                Poll::Pending => std::thread::park(),
+            }
+        };
+
+        assert_eq!(value, Some(1024.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_runtime_pool_warmup() -> Result<()> {
+        let bundle = Runtime::compile_to_bytecode("function double(x) { return x * 2; }", None)?;
+        let pool = RuntimePoolBuilder::new(RuntimeConfig::default())
+            .size(2)
+            .warmup(bundle)
+            .build()?;
+        assert_eq!(pool.size(), 2);
+        assert_eq!(pool.idle(), 2);
+
+        let mut first = pool.acquire()?;
+        let mut second = pool.acquire()?;
+        assert_eq!(pool.idle(), 0);
+        assert!(pool.try_acquire()?.is_none());
+
+        let result = first.eval_with_result("double(21)", None)?;
+        assert_eq!(result.as_number(), Some(42.0));
+        let result = second.eval_with_result("double(2)", None)?;
+        assert_eq!(result.as_number(), Some(4.0));
+
+        drop(first);
+        assert_eq!(pool.idle(), 1);
+        drop(second);
+        assert_eq!(pool.idle(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_runtime_pool_recycles_after_max_uses() -> Result<()> {
+        let pool = RuntimePoolBuilder::new(RuntimeConfig::default())
+            .max_uses(2)
+            .build()?;
+
+        pool.acquire()?.eval("globalThis.leftover = 1", None)?;
+
+        let mut runtime = pool.acquire()?;
+        let still_there = runtime.eval_with_result("globalThis.leftover === 1", None)?;
+        assert_eq!(still_there.as_bool(), Some(true));
+        drop(runtime);
+
+        // Used twice, the pool replaced it with a fresh runtime
+        assert_eq!(pool.idle(), 0);
+        let mut runtime = pool.acquire()?;
+        let leftover = runtime.eval_with_result("globalThis.leftover === undefined", None)?;
+        assert_eq!(leftover.as_bool(), Some(true));
+        Ok(())
+    }
+
+    #[test]
+    fn test_runtime_pool_refill() -> Result<()> {
+        let pool = RuntimePoolBuilder::new(RuntimeConfig::default())
+            .size(2)
+            .max_uses(1)
+            .build()?;
+
+        drop(pool.acquire()?);
+        assert_eq!(pool.idle(), 1);
+
+        assert_eq!(pool.refill()?, 1);
+        assert_eq!(pool.idle(), 2);
+        assert_eq!(pool.refill()?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_runtime_pool_discard_and_heap_limit() -> Result<()> {
+        let pool = RuntimePoolBuilder::new(RuntimeConfig::default())
+            .max_heap_bytes(32 << 20)
+            .build()?;
+
+        let mut runtime = pool.acquire()?;
+        runtime.eval("globalThis.marker = true", None)?;
+        runtime.discard();
+        let fresh = pool
+            .acquire()?
+            .eval_with_result("globalThis.marker", None)?;
+        assert!(fresh.is_undefined());
+
+        let mut runtime = pool.acquire()?;
+        runtime.eval(
+            "globalThis.big = Array.from({ length: 1 << 20 }, (_, i) => ({ i })); globalThis.marker = true",
+            None,
+        )?;
+        drop(runtime);
+        let fresh = pool
+            .acquire()?
+            .eval_with_result("globalThis.marker", None)?;
+        assert!(fresh.is_undefined());
+        Ok(())
+    }
+
+    #[test]
+    fn test_runtime_pool_across_threads() -> Result<()> {
+        let pool = RuntimePoolBuilder::new(RuntimeConfig::default())
+            .size(2)
+            .build()?;
+
+        let workers: Vec<_> = (0..8)
+            .map(|i| {
+                let pool = pool.clone();
+                std::thread::spawn(move || -> Result<Option<f64>> {
+                    let mut runtime = pool.acquire()?;
+                    Ok(runtime
+                        .eval_with_result(&format!("{i} + 1"), None)?
+                        .as_number())
+                })
+            })
+            .collect();
+
+        for (i, worker) in workers.into_iter().enumerate() {
+            assert_eq!(worker.join().unwrap()?, Some(i as f64 + 1.0));
+        }
+        assert_eq!(pool.idle(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_prepared_javascript_across_threads() -> Result<()> {
+        let bytecode = std::sync::Arc::new(Runtime::compile_to_bytecode(
+            "[1, 2, 3].map(x => x * 2).length",
+            None,
+        )?);
+
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let bytecode = bytecode.clone();
+                std::thread::spawn(move || -> Result<Option<f64>> {
+                    let mut runtime = Runtime::new(RuntimeConfig::default())?;
+                    let prepared = runtime.prepare_bytecode(&bytecode)?;
+                    Ok(runtime.evaluate_prepared_javascript(&prepared)?.as_number())
+                })
+            })
+            .collect();
+
+        for worker in workers {
+            assert_eq!(worker.join().unwrap()?, Some(3.0));
+        }
+
+        // Prepared code can be handed over to a runtime on another thread
+        let prepared = Runtime::new(RuntimeConfig::default())?.prepare_javascript("6 * 7", None)?;
+        let result = std::thread::spawn(move || -> Result<Option<f64>> {
+            let mut runtime = Runtime::new(RuntimeConfig::default())?;
+            Ok(runtime.evaluate_prepared_javascript(&prepared)?.as_number())
+        });
+        assert_eq!(result.join().unwrap()?, Some(42.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_prepared_javascript_incompatible_config() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let prepared = runtime.prepare_javascript("1 + 1", None)?;
+
+        let config = RuntimeConfigBuilder::new().enable_generator(false).build();
+        let mut other = Runtime::new(config)?;
+        let result = other.evaluate_prepared_javascript(&prepared);
+        assert!(result.is_err_and(|err| err.contains("incompatible configuration")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_prepare_bytecode() -> Result<()> {
+        let bytecode = Runtime::compile_to_bytecode("'prepared ' + 'bytecode'", None)?;
+        let prepared = Runtime::new(RuntimeConfig::default())?.prepare_bytecode(&bytecode)?;
+        drop(bytecode);
+
+        let config = RuntimeConfigBuilder::new().enable_generator(false).build();
+        let mut other = Runtime::new(config)?;
+        let result = other.evaluate_prepared_javascript(&prepared)?;
+        assert!(result.is_string());
+
+        let invalid = CompiledBytecode::from_bytes(b"not bytecode");
+        assert!(other.prepare_bytecode(&invalid).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_instrumentation_heap_info() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        runtime.eval(
+            "globalThis.data = Array.from({ length: 10000 }, (_, i) => ({ i }))",
+            None,
+        )?;
+
+        let heap_info = runtime.instrumentation().heap_info(false);
+        assert!(heap_info
+            .get("hermes_allocatedBytes")
+            .is_some_and(|&b| b > 0));
+        assert!(heap_info.contains_key("hermes_heapSize"));
+
+        let expensive = runtime.instrumentation().heap_info(true);
+        assert!(expensive.len() >= heap_info.len());
+
+        let before = runtime.allocated_bytes();
+        runtime.eval("globalThis.data = null", None)?;
+        runtime.instrumentation().collect_garbage("test");
+        assert!(runtime.allocated_bytes() < before);
+        Ok(())
+    }
+
+    #[test]
+    fn test_instrumentation_recorded_gc_stats() -> Result<()> {
+        let config = RuntimeConfigBuilder::new().record_gc_stats(true).build();
+        let mut runtime = Runtime::new(config)?;
+        runtime.instrumentation().collect_garbage("test");
+        // Parsed without the runtime's JSON global
+        runtime.eval("JSON.parse = () => ({ type: 'spoofed' });", None)?;
+
+        let stats = runtime.instrumentation().recorded_gc_stats()?;
+        assert_eq!(stats.kind, "hermes");
+        assert!(!stats.json.is_empty());
+        assert!(!stats.heap_info.is_empty());
+
+        let mut untracked = Runtime::new(RuntimeConfig::default())?;
+        assert!(untracked.instrumentation().recorded_gc_stats().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_heap_snapshot() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        runtime.eval(
+            "globalThis.leak = Array.from({ length: 100 }, () => ({}))",
+            None,
+        )?;
+
+        let mut snapshot = Vec::new();
+        runtime.write_heap_snapshot(&mut snapshot)?;
+
+        let snapshot = String::from_utf8(snapshot).map_err(|e| Error::internal(e.to_string()))?;
+        assert!(snapshot.starts_with('{'));
+        assert!(snapshot.contains("\"snapshot\""));
+        assert!(snapshot.contains("\"nodes\""));
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_heap_snapshot_writer_error() -> Result<()> {
+        struct FailingWriter;
+
+        impl std::io::Write for FailingWriter {
+            fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("disk full"))
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let result = runtime.write_heap_snapshot(FailingWriter);
+        assert!(result.is_err_and(|err| err.contains("disk full")));
+
+        // The runtime is still usable afterwards
+        runtime.eval("1 + 1", None)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_heap_sampling() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        runtime.start_heap_sampling(1024)?;
+        runtime.eval(
+            "function allocate() { return Array.from({ length: 1000 }, (_, i) => ({ i })); }\n\
+             globalThis.kept = [];\n\
+             for (let i = 0; i < 20; i++) kept.push(allocate());",
+            Some("sampling.js"),
+        )?;
+
+        let mut profile = Vec::new();
+        runtime.stop_heap_sampling(&mut profile)?;
+
+        let profile = String::from_utf8(profile).map_err(|e| Error::internal(e.to_string()))?;
+        assert!(profile.contains("\"head\""));
+        assert!(profile.contains("\"samples\""));
+        Ok(())
+    }
+
+    #[test]
+    fn test_profiler_cpuprofile() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let mut profiler = runtime.start_profiler(1000.0)?;
+        profiler.eval(
+            "function spin() { let x = 0; for (let i = 0; i < 2e6; i++) x += Math.sqrt(i); return x; }\n\
+             for (let i = 0; i < 20; i++) spin();",
+            Some("spin.js"),
+        )?;
+        let cpuprofile = profiler.stop()?;
+
+        assert!(cpuprofile.contains("\"nodes\""));
+        assert!(cpuprofile.contains("\"timeDeltas\""));
+
+        // The runtime is usable again once the profiler is gone
+        runtime.eval("1 + 1", None)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_profiler_typed_profile() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let mut profiler = runtime.start_profiler(1000.0)?;
+        profiler.eval(
+            "function hotLoop() { let x = 0; for (let i = 0; i < 2e6; i++) x += Math.sqrt(i); return x; }\n\
+             for (let i = 0; i < 20; i++) hotLoop();",
+            Some("hot.js"),
+        )?;
+        let profile = profiler.stop_profile()?;
+
+        assert!(!profile.nodes.is_empty());
+        assert!(profile.end_time >= profile.start_time);
+        assert_eq!(profile.samples.len(), profile.time_deltas.len());
+
+        let stacks = profile.stacks();
+        assert_eq!(stacks.len(), profile.samples.len());
+        assert!(stacks.iter().any(|sample| sample
+            .stack
+            .iter()
+            .any(|frame| frame.function_name == "hotLoop")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_profiler_profile_ignores_spoofed_json() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let mut profiler = runtime.start_profiler(1000.0)?;
+        profiler.eval(
+            "JSON.parse = () => ({ nodes: [] });\n\
+             function spin() { let x = 0; for (let i = 0; i < 2e6; i++) x += i; return x; }\n\
+             for (let i = 0; i < 10; i++) spin();",
+            None,
+        )?;
+        let profile = profiler.stop_profile()?;
+
+        assert!(!profile.nodes.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_profiler_trace() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let mut profiler = runtime.start_profiler(1000.0)?;
+        profiler.eval(
+            "function hotLoop() { let x = 0; for (let i = 0; i < 2e6; i++) x += Math.sqrt(i); return x; }\n\
+             for (let i = 0; i < 20; i++) hotLoop();",
+            Some("hot.js"),
+        )?;
+        let trace = profiler.stop_trace()?;
+
+        assert!(!trace.is_empty());
+        assert!(trace
+            .windows(2)
+            .all(|pair| pair[0].timestamp <= pair[1].timestamp));
+        assert!(trace
+            .iter()
+            .any(|sample| sample.stack.iter().any(|frame| matches!(
+                frame,
+                TraceFrame::Js { function_name, url, .. }
+                    if function_name == "hotLoop" && url.as_deref() == Some("hot.js")
+            ))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_profiler_rejects_invalid_frequency() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        assert!(runtime.start_profiler(0.0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_profiler_rejects_mismatched_frequency() -> Result<()> {
+        let mut first = Runtime::new(RuntimeConfig::default())?;
+        let mut second = Runtime::new(RuntimeConfig::default())?;
+        let _profiler = first.start_profiler(1000.0)?;
+        assert!(second.start_profiler(500.0).is_err());
+        drop(second.start_profiler(1000.0)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_profile_stacks_stop_at_cycles() {
+        let node = |id, children| ProfileNode {
+            id,
+            call_frame: CallFrame {
+                function_name: format!("f{id}"),
+                script_id: "0".to_string(),
+                url: String::new(),
+                line_number: 0,
+                column_number: 0,
+            },
+            hit_count: 0,
+            children,
+        };
+        let profile = Profile {
+            nodes: vec![node(1, vec![2]), node(2, vec![1])],
+            start_time: 0.0,
+            end_time: 1.0,
+            samples: vec![2],
+            time_deltas: vec![1.0],
+        };
+
+        let stacks = profile.stacks();
+        assert_eq!(stacks.len(), 1);
+        assert_eq!(stacks[0].stack.len(), 2);
+    }
+
+    #[test]
+    fn test_code_coverage_lcov() -> Result<()> {
+        let source =
+            "function applied(x) { return x * 2; }\nfunction skipped() { return 0; }\napplied(21);";
+        let coverage = CodeCoverage::start()?;
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        runtime.eval(source, Some("rules.js"))?;
+
+        let functions = coverage.executed_functions()?;
+        assert!(functions
+            .iter()
+            .any(|f| f.source_url.as_deref() == Some("rules.js") && f.line == 1));
+        assert!(!functions
+            .iter()
+            .any(|f| f.source_url.as_deref() == Some("rules.js") && f.line == 2));
+
+        let report = coverage.report()?.with_source("rules.js", source);
+        let rules = &report.files["rules.js"];
+        assert!(rules.iter().any(|f| f.line == 1 && f.hits > 0));
+        assert!(rules.iter().any(|f| f.line == 2 && f.hits == 0));
+
+        let lcov = report.to_lcov();
+        assert!(lcov.contains("SF:rules.js\n"));
+        assert!(lcov.contains("FN:1,"));
+        assert!(lcov.contains("FN:2,(anonymous_2_1)\n"));
+        assert!(lcov.contains("FNDA:0,(anonymous_2_1)\n"));
+        assert!(!lcov.contains("DA:"));
+        assert!(lcov.contains("end_of_record"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_code_coverage_with_source() {
+        let source = r#"
+function ran(x) { return x / 2; }
+const idle = async (y) => y * 2, text = `a ${ {k: 1}.k } function fake() {}`;
+class Rule {
+  static *each() { return /}[(]/g; }
+  get [Symbol.toStringTag]() { return "Rule"; }
+}
+// function commented() {}
+"#;
+        let executed = ExecutedFunction {
+            runtime: "1".into(),
+            module_id: 0,
+            function_offset: 0,
+            source_url: Some("rules.js".into()),
+            line: 2,
+            column: 10,
+        };
+        let report = CoverageReport::from_functions(&[executed]).with_source("rules.js", source);
+
+        let functions: Vec<_> = report.files["rules.js"]
+            .iter()
+            .map(|f| (f.line, f.column, f.hits))
+            .collect();
+        assert_eq!(functions, [(2, 10, 1), (3, 14, 0), (5, 3, 0), (6, 3, 0)]);
+    }
+
+    #[test]
+    fn test_code_coverage_source_map() -> Result<()> {
+        // bundle.js line 1 -> src/a.ts:10:1, line 2 column 5 -> src/b.ts:3:3 named `check`
+        let map = SourceMap::parse(
+            r#"{"version":3,"sources":["a.ts","b.ts"],"sourceRoot":"src/","names":["check"],"mappings":"AASA;IChOEA"}"#,
+        )?;
+        assert_eq!(
+            map.lookup(2, 9).map(|p| (p.source, p.line, p.column)),
+            Some(("src/b.ts".into(), 3, 3))
+        );
+        assert_eq!(map.lookup(3, 1), None);
+
+        // Roots without a trailing slash are joined with one, null sources have no position
+        let map = SourceMap::parse(
+            r#"{"version":3,"sources":[null,"a.ts"],"sourceRoot":"src","mappings":"AAAA;ACAA"}"#,
+        )?;
+        assert_eq!(map.lookup(1, 1), None);
+        assert_eq!(map.lookup(2, 1).map(|p| p.source), Some("src/a.ts".into()));
+
+        let function = |url: &str, line, column| ExecutedFunction {
+            runtime: "1".into(),
+            module_id: 0,
+            function_offset: line,
+            source_url: Some(url.into()),
+            line,
+            column,
+        };
+        let report = CoverageReport::from_functions(&[
+            function("bundle.js", 1, 1),
+            function("bundle.js", 2, 5),
+            function("other.js", 4, 1),
+        ])
+        .apply_source_map("bundle.js", &map);
+
+        let files: Vec<_> = report.files.keys().map(String::as_str).collect();
+        assert_eq!(files, ["other.js", "src/a.ts", "src/b.ts"]);
+
+        let lcov = report.to_lcov();
+        assert!(lcov.contains("SF:src/b.ts\nFN:3,check\nFNDA:1,check\nFNF:1\nFNH:1\n"));
+        assert!(lcov.contains("SF:src/a.ts\nFN:10,"));
+
+        let istanbul: serde_json::Value = serde_json::from_str(&report.to_istanbul_json())
+            .map_err(|err| Error::internal(err.to_string()))?;
+        let hits = istanbul.get("src/b.ts").and_then(|file| file.get("f"));
+        assert_eq!(hits.and_then(|hits| hits.get("0")?.as_f64()), Some(1.0));
+        Ok(())
+    }
+
+    #[cfg(feature = "debugger")]
+    #[test]
+    fn test_inspector_cdp_session() -> Result<()> {
+        use std::io::{Read, Write};
+        use std::net::TcpStream;
+
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut inspector = runtime.start_inspector("127.0.0.1:0")?;
+        let addr = inspector.local_addr();
+
+        let mut http = TcpStream::connect(addr).unwrap();
+        http.write_all(b"GET /json/list HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+        let mut list = String::new();
+        http.read_to_string(&mut list).unwrap();
+        assert!(list.contains(
+            &inspector
+                .websocket_url()
+                .replace(&addr.to_string(), "localhost")
+        ));
+
+        let url = inspector.websocket_url();
+        let (ready_tx, ready) = std::sync::mpsc::channel();
+        let (go, go_rx) = std::sync::mpsc::channel();
+        let client = std::thread::spawn(move || {
+            let stream = TcpStream::connect(addr).unwrap();
+            let (mut socket, _) = tungstenite::client(url.as_str(), stream).unwrap();
+            let mut call = |id: u32, method: &str, params: &str| {
+                let command =
+                    format!("{{\"id\":{id},\"method\":\"{method}\",\"params\":{params}}}");
+                socket.send(tungstenite::Message::text(command)).unwrap();
+                loop {
+                    let message = socket.read().unwrap();
+                    let text = message.to_text().unwrap();
+                    if text.contains(&format!("\"id\":{id}")) {
+                        return text.to_string();
+                    }
+                }
+            };
+
+            let enabled = call(1, "Debugger.enable", "{}");
+            // Mentioning the method elsewhere doesn't release the runtime
+            call(
+                2,
+                "Runtime.evaluate",
+                r#"{"expression":"1","objectGroup":"Runtime.runIfWaitingForDebugger"}"#,
+            );
+            ready_tx.send(()).unwrap();
+            go_rx.recv().unwrap();
+            call(3, "Runtime.runIfWaitingForDebugger", "{}");
+            enabled
+        });
+
+        while ready.try_recv().is_err() {
+            inspector.run_pending_tasks();
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert!(inspector
+            .wait_for_debugger_timeout(std::time::Duration::from_millis(200))
+            .is_err());
+        go.send(()).unwrap();
+
+        inspector.wait_for_debugger_timeout(std::time::Duration::from_secs(10))?;
+        assert!(inspector.is_attached());
+        let enabled = client.join().unwrap();
+        assert!(enabled.contains("\"result\""));
+
+        let result = inspector.eval_with_result("6 * 7", Some("inspected.js"))?;
+        assert_eq!(result.as_number(), Some(42.0));
+        Ok(())
+    }
+
+    #[cfg(feature = "debugger")]
+    #[test]
+    fn test_inspector_rejects_foreign_requests() -> Result<()> {
+        use std::io::{Read, Write};
+        use std::net::TcpStream;
+
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let inspector = runtime.start_inspector("127.0.0.1:0")?;
+        let addr = inspector.local_addr();
+        let target = inspector
+            .websocket_url()
+            .rsplit('/')
+            .next()
+            .unwrap()
+            .to_string();
+        assert_eq!(target.len(), 36);
+
+        let get = |head: &str| {
+            let mut http = TcpStream::connect(addr).unwrap();
+            http.write_all(format!("GET {head}\r\n\r\n").as_bytes())
+                .unwrap();
+            let mut response = String::new();
+            let _ = http.read_to_string(&mut response);
+            response
+        };
+
+        assert!(get("/json/list HTTP/1.1\r\nHost: 127.0.0.1").starts_with("HTTP/1.1 200"));
+        assert!(get("/json/list HTTP/1.1\r\nHost: attacker.example").starts_with("HTTP/1.1 403"));
+        assert!(get("/json/list HTTP/1.1\r\nHost: 203.0.113.7").starts_with("HTTP/1.1 403"));
+        assert!(
+            get("/json/list HTTP/1.1\r\nHost: [::1]:9229\r\nOrigin: http://203.0.113.7")
+                .starts_with("HTTP/1.1 403")
+        );
+        assert!(
+            get("/json/list HTTP/1.1\r\nHost: localhost\r\nOrigin: https://attacker.example")
+                .starts_with("HTTP/1.1 403")
+        );
+
+        let upgrade = format!(
+            "/{target} HTTP/1.1\r\nHost: localhost\r\nOrigin: https://attacker.example\r\n\
+             Connection: Upgrade\r\nUpgrade: websocket\r\nSec-WebSocket-Version: 13\r\n\
+             Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ=="
+        );
+        assert!(get(&upgrade).starts_with("HTTP/1.1 403"));
+        assert!(!inspector.is_attached());
+        Ok(())
+    }
+
+    #[cfg(feature = "debugger")]
+    #[test]
+    fn test_debugger_breakpoint_scopes_and_stepping() -> Result<()> {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut debugger = runtime.debugger();
+
+        let breakpoint = debugger.set_breakpoint("rules.js", 2, 1)?;
+        let pauses = Rc::new(RefCell::new(Vec::new()));
+        debugger.on_pause({
+            let pauses = pauses.clone();
+            move |pause| {
+                let frame = pause.stack()[0].clone();
+                let x = pause.variable(0, "x").and_then(|value| value.as_number());
+                pauses
+                    .borrow_mut()
+                    .push((pause.reason(), pause.breakpoint(), frame, x));
+                if pause.reason() == PauseReason::Breakpoint {
+                    pause.resume(StepMode::Over);
+                }
+            }
+        });
+
+        debugger.debug_javascript(
+            "function rule(x) {\n  const y = x * 2;\n  return y > 1;\n}\nrule(21);",
+            Some("rules.js"),
+        )?;
+        assert!(debugger.breakpoint_location(breakpoint).is_some());
+
+        let pauses = pauses.borrow();
+        assert_eq!(pauses.len(), 2);
+        let (reason, hit, frame, x) = &pauses[0];
+        assert_eq!(*reason, PauseReason::Breakpoint);
+        assert_eq!(*hit, Some(breakpoint));
+        assert_eq!(frame.function_name, "rule");
+        assert_eq!(
+            (frame.location.url.as_str(), frame.location.line),
+            ("rules.js", 2)
+        );
+        assert_eq!(*x, Some(21.0));
+
+        let (reason, _, frame, _) = &pauses[1];
+        assert_eq!(*reason, PauseReason::StepFinish);
+        assert_eq!(frame.location.line, 3);
+        Ok(())
+    }
+
+    #[cfg(feature = "debugger")]
+    #[test]
+    fn test_debugger_pause_on_exceptions() -> Result<()> {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut debugger = runtime.debugger();
+        debugger.set_pause_on_exceptions(PauseOnExceptions::All);
+
+        let exceptions = Rc::new(Cell::new(0));
+        debugger.on_pause({
+            let exceptions = exceptions.clone();
+            move |pause| {
+                if pause.reason() == PauseReason::Exception {
+                    exceptions.set(exceptions.get() + 1);
+                }
+            }
+        });
+
+        debugger.debug_javascript("try { throw new Error('rule failed'); } catch (e) {}", None)?;
+        assert_eq!(exceptions.get(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_crash_manager_receives_context() -> Result<()> {
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Default)]
+        struct Recorder {
+            custom_data: Mutex<Vec<(String, String)>>,
+            heap_infos: Mutex<usize>,
+        }
+
+        impl CrashManager for Recorder {
+            fn set_custom_data(&self, key: &str, value: &str) {
+                let mut data = self.custom_data.lock().unwrap();
+                data.push((key.to_string(), value.to_string()));
+            }
+
+            fn set_contextual_custom_data(&self, key: &str, value: &str) {
+                self.set_custom_data(key, value);
+            }
+
+            fn set_heap_info(&self, _used: usize, _size: usize) {
+                *self.heap_infos.lock().unwrap() += 1;
+            }
+        }
+
+        let recorder = Arc::new(Recorder::default());
+        let config = RuntimeConfigBuilder::new()
+            .crash_manager(recorder.clone())
+            .build();
+        let mut runtime = Runtime::new(config)?;
+        runtime.eval(
+            "let a = []; for (let i = 0; i < 1e5; i++) a.push({ i });",
+            None,
+        )?;
+        runtime.instrumentation().collect_garbage("crash context");
+
+        let reported = !recorder.custom_data.lock().unwrap().is_empty()
+            || *recorder.heap_infos.lock().unwrap() > 0;
+        assert!(reported);
+        Ok(())
+    }
 }
@@ -0,0 +1,336 @@
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::config::RuntimeConfig;
+use crate::error::Result;
+use crate::runtime::{CompiledBytecode, Runtime};
+
+/// A pool of pre-created, pre-warmed runtimes leased out one at a time.
+///
+/// Runtimes are created from a [`RuntimeConfig`] template and warmed by evaluating shared
+/// bytecode, e.g. a framework bundle. A leased [`PooledRuntime`] returns to the pool when
+/// dropped, unless the recycle policy says it has served enough: it is then destroyed so
+/// global state and heap growth don't leak between tenants forever. Its replacement is
+/// created by the next lease finding no idle runtime, which reports creation errors, or
+/// ahead of time with [`RuntimePool::refill`].
+///
+/// The pool is cheap to clone and can be shared between threads.
+///
+/// # Example
+/// ```no_run
+/// # use hermes_engine::{Runtime, RuntimeConfig, RuntimePoolBuilder};
+/// let bundle = Runtime::compile_to_bytecode("function handle(x) { return x * 2; }", None)?;
+/// let pool = RuntimePoolBuilder::new(RuntimeConfig::default())
+///     .size(4)
+///     .warmup(bundle)
+///     .max_uses(100)
+///     .build()?;
+///
+/// let mut runtime = pool.acquire()?;
+/// let result = runtime.eval_with_result("handle(21)", None)?;
+/// assert_eq!(result.as_number(), Some(42.0));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone)]
+pub struct RuntimePool {
+    inner: Arc<PoolInner>,
+}
+
+struct PoolInner {
+    config: RuntimeConfig,
+    warmup: Vec<CompiledBytecode>,
+    size: usize,
+    max_uses: Option<u32>,
+    max_heap_bytes: Option<u64>,
+    slots: Mutex<Slots>,
+    available: Condvar,
+}
+
+struct Slots {
+    idle: Vec<Entry>,
+    /// Runtimes in existence, idle or leased
+    live: usize,
+}
+
+struct Entry {
+    runtime: Runtime,
+    uses: u32,
+}
+
+enum Taken {
+    Idle(Entry),
+    /// Slot of a recycled runtime, the replacement is created by the lease
+    Vacant,
+}
+
+/// Builder for RuntimePool with fluent API
+pub struct RuntimePoolBuilder {
+    config: RuntimeConfig,
+    warmup: Vec<CompiledBytecode>,
+    size: usize,
+    max_uses: Option<u32>,
+    max_heap_bytes: Option<u64>,
+}
+
+impl RuntimePoolBuilder {
+    /// Create a new builder creating runtimes from `config`
+    pub fn new(config: RuntimeConfig) -> Self {
+        Self {
+            config,
+            warmup: Vec::new(),
+            size: 1,
+            max_uses: None,
+            max_heap_bytes: None,
+        }
+    }
+
+    /// Set the number of runtimes, all created upfront
+    pub fn size(mut self, size: usize) -> Self {
+        self.size = size.max(1);
+        self
+    }
+
+    /// Evaluate `bytecode` in every new runtime, in the order added
+    pub fn warmup(mut self, bytecode: CompiledBytecode) -> Self {
+        self.warmup.push(bytecode);
+        self
+    }
+
+    /// Replace a runtime after it has been leased `uses` times
+    pub fn max_uses(mut self, uses: u32) -> Self {
+        self.max_uses = Some(uses.max(1));
+        self
+    }
+
+    /// Replace a runtime returned with more than `bytes` allocated on its heap
+    pub fn max_heap_bytes(mut self, bytes: u64) -> Self {
+        self.max_heap_bytes = Some(bytes);
+        self
+    }
+
+    /// Create and warm all runtimes of the pool
+    pub fn build(self) -> Result<RuntimePool> {
+        let inner = Arc::new(PoolInner {
+            config: self.config,
+            warmup: self.warmup,
+            size: self.size,
+            max_uses: self.max_uses,
+            max_heap_bytes: self.max_heap_bytes,
+            slots: Mutex::new(Slots {
+                idle: Vec::with_capacity(self.size),
+                live: 0,
+            }),
+            available: Condvar::new(),
+        });
+
+        for _ in 0..inner.size {
+            let runtime = inner.create_runtime()?;
+            let mut slots = inner.slots.lock().unwrap();
+            slots.idle.push(Entry { runtime, uses: 0 });
+            slots.live += 1;
+        }
+
+        Ok(RuntimePool { inner })
+    }
+}
+
+impl PoolInner {
+    fn create_runtime(&self) -> Result<Runtime> {
+        let mut runtime = Runtime::new(self.config.clone())?;
+        for bytecode in &self.warmup {
+            runtime.eval_bytecode(bytecode)?;
+        }
+        Ok(runtime)
+    }
+
+    /// Take an idle runtime or reserve a vacant slot, `None` when all are leased
+    fn take(&self, slots: &mut Slots) -> Option<Taken> {
+        if let Some(entry) = slots.idle.pop() {
+            Some(Taken::Idle(entry))
+        } else if slots.live < self.size {
+            slots.live += 1;
+            Some(Taken::Vacant)
+        } else {
+            None
+        }
+    }
+
+    fn lease(self: &Arc<Self>, taken: Taken) -> Result<PooledRuntime> {
+        let entry = match taken {
+            Taken::Idle(entry) => entry,
+            Taken::Vacant => match self.create_runtime() {
+                Ok(runtime) => Entry { runtime, uses: 0 },
+                Err(err) => {
+                    self.release_slot();
+                    return Err(err);
+                }
+            },
+        };
+
+        Ok(PooledRuntime {
+            entry: Some(entry),
+            pool: self.clone(),
+            discard: false,
+        })
+    }
+
+    /// Reserve a slot left by a recycled runtime
+    fn reserve_vacant(&self) -> bool {
+        let mut slots = self.slots.lock().unwrap();
+        if slots.live >= self.size {
+            return false;
+        }
+        slots.live += 1;
+        true
+    }
+
+    fn release_slot(&self) {
+        self.slots.lock().unwrap().live -= 1;
+        self.available.notify_one();
+    }
+
+    fn give_back(&self, entry: Entry) {
+        self.slots.lock().unwrap().idle.push(entry);
+        self.available.notify_one();
+    }
+
+    fn should_recycle(&self, entry: &mut Entry) -> bool {
+        if self.max_uses.is_some_and(|max_uses| entry.uses >= max_uses) {
+            return true;
+        }
+        self.max_heap_bytes
+            .is_some_and(|max_heap_bytes| entry.runtime.allocated_bytes() > max_heap_bytes)
+    }
+}
+
+impl RuntimePool {
+    /// Lease a runtime, blocking until one is returned if all are in use.
+    pub fn acquire(&self) -> Result<PooledRuntime> {
+        let mut slots = self.inner.slots.lock().unwrap();
+        loop {
+            if let Some(taken) = self.inner.take(&mut slots) {
+                drop(slots);
+                return self.inner.lease(taken);
+            }
+            slots = self.inner.available.wait(slots).unwrap();
+        }
+    }
+
+    /// Lease a runtime, giving up after `timeout` if all are in use.
+    pub fn acquire_timeout(&self, timeout: Duration) -> Result<Option<PooledRuntime>> {
+        let deadline = Instant::now() + timeout;
+        let mut slots = self.inner.slots.lock().unwrap();
+        loop {
+            if let Some(taken) = self.inner.take(&mut slots) {
+                drop(slots);
+                return self.inner.lease(taken).map(Some);
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+            slots = self
+                .inner
+                .available
+                .wait_timeout(slots, remaining)
+                .unwrap()
+                .0;
+        }
+    }
+
+    /// Lease a runtime if one is available right away.
+    pub fn try_acquire(&self) -> Result<Option<PooledRuntime>> {
+        self.acquire_timeout(Duration::ZERO)
+    }
+
+    /// Create the replacements of recycled runtimes now, instead of in the lease that needs
+    /// one. Returns how many were created.
+    ///
+    /// Meant to be called off the request path, e.g. from a maintenance thread.
+    pub fn refill(&self) -> Result<usize> {
+        let mut created = 0;
+        while self.inner.reserve_vacant() {
+            match self.inner.create_runtime() {
+                Ok(runtime) => self.inner.give_back(Entry { runtime, uses: 0 }),
+                Err(err) => {
+                    self.inner.release_slot();
+                    return Err(err);
+                }
+            }
+            created += 1;
+        }
+        Ok(created)
+    }
+
+    /// Number of runtimes the pool keeps.
+    pub fn size(&self) -> usize {
+        self.inner.size
+    }
+
+    /// Number of runtimes ready to be leased without waiting.
+    pub fn idle(&self) -> usize {
+        self.inner.slots.lock().unwrap().idle.len()
+    }
+}
+
+/// A runtime leased from a [`RuntimePool`], returned to it when dropped.
+pub struct PooledRuntime {
+    entry: Option<Entry>,
+    pool: Arc<PoolInner>,
+    discard: bool,
+}
+
+impl PooledRuntime {
+    /// Don't return the runtime to the pool, e.g. after a script left it in a bad state.
+    ///
+    /// A fresh runtime takes its place, see [`RuntimePool`].
+    pub fn discard(mut self) {
+        self.discard = true;
+    }
+
+    fn entry(&self) -> &Entry {
+        self.entry
+            .as_ref()
+            .expect("pooled runtime already returned")
+    }
+
+    fn entry_mut(&mut self) -> &mut Entry {
+        self.entry
+            .as_mut()
+            .expect("pooled runtime already returned")
+    }
+}
+
+impl Deref for PooledRuntime {
+    type Target = Runtime;
+
+    fn deref(&self) -> &Runtime {
+        &self.entry().runtime
+    }
+}
+
+impl DerefMut for PooledRuntime {
+    fn deref_mut(&mut self) -> &mut Runtime {
+        &mut self.entry_mut().runtime
+    }
+}
+
+impl Drop for PooledRuntime {
+    fn drop(&mut self) {
+        let Some(mut entry) = self.entry.take() else {
+            return;
+        };
+        entry.uses += 1;
+
+        if !self.discard && !self.pool.should_recycle(&mut entry) {
+            self.pool.give_back(entry);
+            return;
+        }
+
+        // The replacement is created by a later lease or refill, where errors can be reported
+        drop(entry);
+        self.pool.release_slot();
+    }
+}
@@ -0,0 +1,402 @@
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::ops::{Deref, DerefMut};
+use std::sync::Mutex;
+
+use serde_json::Value;
+
+use crate::bridge::ffi;
+use crate::error::{Error, Result};
+use crate::runtime::Runtime;
+use crate::stream::WriteSink;
+
+/// Number of active profilers and their sampling frequency, the sampling profiler is process-wide
+static ACTIVE_PROFILERS: Mutex<(usize, f64)> = Mutex::new((0, 0.0));
+
+/// Sampling CPU profiler attached to a runtime, detached when dropped.
+///
+/// Created via `Runtime::start_profiler()`. The profiler gives access to the runtime, run
+/// the workload through it and stop it to get the samples collected meanwhile, either as
+/// a Chrome DevTools `.cpuprofile` or parsed into a [`Profile`].
+///
+/// Sampling is process-wide: the frequency is set by the first profiler started, later
+/// ones must use the same frequency, and sampling stops when the last one is dropped.
+///
+/// # Example
+/// ```no_run
+/// # use hermes_engine::{Runtime, RuntimeConfig};
+/// let mut runtime = Runtime::new(RuntimeConfig::default())?;
+///
+/// let mut profiler = runtime.start_profiler(1000.0)?;
+/// profiler.eval("for (let i = 0; i < 1e6; i++) Math.sqrt(i)", Some("work.js"))?;
+/// let cpuprofile = profiler.stop()?;
+///
+/// std::fs::write("work.cpuprofile", cpuprofile)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct Profiler<'a> {
+    runtime: &'a mut Runtime,
+    /// Whether the runtime was registered by this profiler rather than by its config
+    registered: bool,
+}
+
+impl<'a> Profiler<'a> {
+    pub(crate) fn start(runtime: &'a mut Runtime, frequency_hz: f64) -> Result<Self> {
+        if frequency_hz.is_nan() || frequency_hz <= 0.0 {
+            return Err(Error::runtime("Sampling frequency must be positive"));
+        }
+
+        let mut active = ACTIVE_PROFILERS.lock().unwrap();
+        let (count, active_hz) = &mut *active;
+        if *count == 0 {
+            ffi::enable_sampling_profiler(frequency_hz)?;
+            *active_hz = frequency_hz;
+        } else if *active_hz != frequency_hz {
+            return Err(Error::runtime(format!(
+                "Sampling profiler is already running at {active_hz} Hz"
+            )));
+        }
+        *count += 1;
+        drop(active);
+
+        // Runtimes created with sample profiling enabled register themselves
+        let registered = !runtime.sample_profiling();
+        if registered {
+            if let Err(err) = ffi::register_for_profiling(runtime.hermes()) {
+                release_sampling();
+                return Err(err.into());
+            }
+        }
+
+        Ok(Self {
+            runtime,
+            registered,
+        })
+    }
+
+    /// Stop profiling and return the samples as a Chrome DevTools `.cpuprofile` JSON string.
+    pub fn stop(mut self) -> Result<String> {
+        let mut profile = Vec::new();
+        self.write_profile(&mut profile)?;
+        String::from_utf8(profile).map_err(|err| Error::internal(err.to_string()))
+    }
+
+    /// Stop profiling and write the `.cpuprofile` JSON to `writer`.
+    pub fn stop_to_writer<W: Write>(mut self, writer: W) -> Result<()> {
+        self.write_profile(writer)
+    }
+
+    /// Stop profiling and parse the samples into a [`Profile`].
+    pub fn stop_profile(mut self) -> Result<Profile> {
+        let mut profile = Vec::new();
+        self.write_profile(&mut profile)?;
+        let profile = String::from_utf8(profile).map_err(|err| Error::internal(err.to_string()))?;
+        let profile: Value = serde_json::from_str(&profile)
+            .map_err(|err| Error::runtime(format!("Invalid CPU profile: {err}")))?;
+        Profile::from_json(&profile)
+    }
+
+    /// Stop profiling and return the raw samples, with the thread they were taken on and
+    /// their full call stack including native functions and GC pauses.
+    pub fn stop_trace(self) -> Result<Vec<TraceSample>> {
+        let mut timestamps = Vec::new();
+        let mut threads = Vec::new();
+        let mut depths = Vec::new();
+        let mut kinds = Vec::new();
+        let mut names = Vec::new();
+        let mut script_ids = Vec::new();
+        let mut urls = Vec::new();
+        let mut lines = Vec::new();
+        let mut columns = Vec::new();
+        ffi::sampled_trace_to_profile(
+            self.runtime.hermes(),
+            &mut timestamps,
+            &mut threads,
+            &mut depths,
+            &mut kinds,
+            &mut names,
+            &mut script_ids,
+            &mut urls,
+            &mut lines,
+            &mut columns,
+        )?;
+
+        let position = |value: i64| u32::try_from(value).ok();
+        let mut frames = kinds
+            .into_iter()
+            .zip(names)
+            .zip(script_ids.into_iter().zip(urls))
+            .zip(lines.into_iter().zip(columns))
+            .map(
+                |(((kind, function_name), (script_id, url)), (line, column))| match kind {
+                    0 => TraceFrame::Js {
+                        function_name,
+                        script_id,
+                        url: Some(url).filter(|url| !url.is_empty()),
+                        line_number: position(line),
+                        column_number: position(column),
+                    },
+                    1 => TraceFrame::Native { function_name },
+                    2 => TraceFrame::Host { function_name },
+                    3 => TraceFrame::GarbageCollector,
+                    4 => TraceFrame::Debugger,
+                    _ => TraceFrame::Suspended,
+                },
+            );
+
+        Ok(timestamps
+            .into_iter()
+            .zip(threads)
+            .zip(depths)
+            .map(|((timestamp, thread_id), depth)| TraceSample {
+                timestamp,
+                thread_id,
+                stack: frames.by_ref().take(depth).collect(),
+            })
+            .collect())
+    }
+
+    fn write_profile<W: Write>(&mut self, mut writer: W) -> Result<()> {
+        let runtime = &mut *self.runtime;
+        let mut result = Ok(());
+        WriteSink::run(&mut writer, |sink| {
+            result = ffi::sampled_trace_to_devtools(runtime.hermes(), sink);
+        })
+        .map_err(|err| Error::runtime(format!("Failed to write CPU profile: {err}")))?;
+        Ok(result?)
+    }
+}
+
+impl Deref for Profiler<'_> {
+    type Target = Runtime;
+
+    fn deref(&self) -> &Runtime {
+        self.runtime
+    }
+}
+
+impl DerefMut for Profiler<'_> {
+    fn deref_mut(&mut self) -> &mut Runtime {
+        self.runtime
+    }
+}
+
+impl Drop for Profiler<'_> {
+    fn drop(&mut self) {
+        if self.registered {
+            let _ = ffi::unregister_for_profiling(self.runtime.hermes());
+        }
+        release_sampling();
+    }
+}
+
+fn release_sampling() {
+    let mut active = ACTIVE_PROFILERS.lock().unwrap();
+    active.0 -= 1;
+    if active.0 == 0 {
+        let _ = ffi::disable_sampling_profiler();
+    }
+}
+
+/// CPU profile in the Chrome DevTools format, see `Profiler::stop_profile()`.
+///
+/// Times are in microseconds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile {
+    /// Call tree, the first node is the root
+    pub nodes: Vec<ProfileNode>,
+    pub start_time: f64,
+    pub end_time: f64,
+    /// Id of the node on top of the stack for each sample
+    pub samples: Vec<u64>,
+    /// Time since the previous sample, or since `start_time` for the first one
+    pub time_deltas: Vec<f64>,
+}
+
+/// Function in the call tree of a [`Profile`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileNode {
+    pub id: u64,
+    pub call_frame: CallFrame,
+    /// Number of samples taken with this node on top of the stack
+    pub hit_count: u64,
+    pub children: Vec<u64>,
+}
+
+/// Location of a function in a [`Profile`], line and column numbers are 0-based.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallFrame {
+    pub function_name: String,
+    pub script_id: String,
+    pub url: String,
+    pub line_number: i64,
+    pub column_number: i64,
+}
+
+/// A sample of a [`Profile`], see `Profile::stacks()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample<'a> {
+    pub timestamp: f64,
+    /// Call frames from the innermost function outwards
+    pub stack: Vec<&'a CallFrame>,
+}
+
+/// A raw sample of the sampling profiler, see `Profiler::stop_trace()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceSample {
+    /// Time the sample was taken, in microseconds since the profiler's epoch
+    pub timestamp: u64,
+    pub thread_id: u64,
+    /// Frames from the innermost outwards
+    pub stack: Vec<TraceFrame>,
+}
+
+/// Frame of a [`TraceSample`] call stack.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TraceFrame {
+    /// JavaScript function, line and column numbers are 1-based
+    Js {
+        function_name: String,
+        script_id: u32,
+        url: Option<String>,
+        line_number: Option<u32>,
+        column_number: Option<u32>,
+    },
+    /// Function implemented by the VM, e.g. `Array.prototype.map`
+    Native { function_name: String },
+    /// Function implemented by the embedder
+    Host { function_name: String },
+    /// Execution was suspended for garbage collection
+    GarbageCollector,
+    /// Execution was suspended by the debugger
+    Debugger,
+    /// Execution was suspended for several reasons
+    Suspended,
+}
+
+impl Profile {
+    /// Resolve every sample into its timestamp and call stack.
+    pub fn stacks(&self) -> Vec<Sample<'_>> {
+        let nodes: HashMap<u64, &ProfileNode> =
+            self.nodes.iter().map(|node| (node.id, node)).collect();
+        let parents: HashMap<u64, u64> = self
+            .nodes
+            .iter()
+            .flat_map(|node| node.children.iter().map(move |&child| (child, node.id)))
+            .collect();
+
+        let mut timestamp = self.start_time;
+        self.samples
+            .iter()
+            .zip(&self.time_deltas)
+            .map(|(&id, delta)| {
+                timestamp += delta;
+
+                let mut stack = Vec::new();
+                // A malformed profile may contain parent cycles, stop at the first repeated node
+                let mut visited = HashSet::new();
+                let mut current = Some(id);
+                while let Some(node) = current
+                    .filter(|&id| visited.insert(id))
+                    .and_then(|id| nodes.get(&id))
+                {
+                    stack.push(&node.call_frame);
+                    current = parents.get(&node.id).copied();
+                }
+
+                Sample { timestamp, stack }
+            })
+            .collect()
+    }
+}
+
+fn invalid(name: &str) -> Error {
+    Error::runtime(format!("Invalid CPU profile: bad or missing \"{name}\""))
+}
+
+fn number(object: &Value, name: &str) -> Result<f64> {
+    object
+        .get(name)
+        .and_then(Value::as_f64)
+        .ok_or_else(|| invalid(name))
+}
+
+fn string(object: &Value, name: &str) -> Result<String> {
+    object
+        .get(name)
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| invalid(name))
+}
+
+/// Optional array member, each item converted with `item`
+fn list<T>(object: &Value, name: &str, item: impl Fn(&Value) -> Option<T>) -> Result<Vec<T>> {
+    match object.get(name) {
+        None | Some(Value::Null) => Ok(Vec::new()),
+        Some(value) => value
+            .as_array()
+            .and_then(|items| items.iter().map(item).collect())
+            .ok_or_else(|| invalid(name)),
+    }
+}
+
+impl Profile {
+    fn from_json(json: &Value) -> Result<Self> {
+        if !json.is_object() {
+            return Err(Error::runtime("Invalid CPU profile: expected an object"));
+        }
+
+        let nodes = json
+            .get("nodes")
+            .and_then(Value::as_array)
+            .ok_or_else(|| invalid("nodes"))?
+            .iter()
+            .map(ProfileNode::from_json)
+            .collect::<Result<_>>()?;
+
+        Ok(Profile {
+            nodes,
+            start_time: number(json, "startTime")?,
+            end_time: number(json, "endTime")?,
+            samples: list(json, "samples", Value::as_u64)?,
+            time_deltas: list(json, "timeDeltas", Value::as_f64)?,
+        })
+    }
+}
+
+impl ProfileNode {
+    fn from_json(json: &Value) -> Result<Self> {
+        Ok(ProfileNode {
+            id: json
+                .get("id")
+                .and_then(Value::as_u64)
+                .ok_or_else(|| invalid("id"))?,
+            call_frame: CallFrame::from_json(
+                json.get("callFrame").ok_or_else(|| invalid("callFrame"))?,
+            )?,
+            hit_count: match json.get("hitCount") {
+                None => 0,
+                Some(count) => count.as_u64().ok_or_else(|| invalid("hitCount"))?,
+            },
+            children: list(json, "children", Value::as_u64)?,
+        })
+    }
+}
+
+impl CallFrame {
+    fn from_json(json: &Value) -> Result<Self> {
+        // Script ids are strings in the protocol, some producers emit numbers
+        let script_id = match json.get("scriptId") {
+            Some(Value::Number(id)) => id.to_string(),
+            _ => string(json, "scriptId")?,
+        };
+
+        Ok(CallFrame {
+            function_name: string(json, "functionName")?,
+            script_id,
+            url: string(json, "url")?,
+            line_number: number(json, "lineNumber")? as i64,
+            column_number: number(json, "columnNumber")? as i64,
+        })
+    }
+}
@@ -1,9 +1,19 @@
+use std::io::Write;
+use std::pin::Pin;
+use std::sync::Arc;
+
 use cxx::{SharedPtr, UniquePtr};
 
 use crate::bridge::ffi;
 use crate::config::RuntimeConfig;
-use crate::error::Result;
-use crate::jsi::{self, JSValue};
+#[cfg(feature = "debugger")]
+use crate::debugger::Debugger;
+use crate::error::{Error, Result};
+#[cfg(feature = "debugger")]
+use crate::inspector::Inspector;
+use crate::instrumentation::Instrumentation;
+use crate::jsi::{self, JSValue, RuntimeState};
+use crate::profiler::Profiler;
 
 /// Prepared JavaScript code optimized for repeated execution.
 ///
@@ -11,13 +21,23 @@ use crate::jsi::{self, JSValue};
 /// that has been parsed and optimized by the runtime. The prepared code can be
 /// executed multiple times efficiently using `Runtime::evaluate_prepared_javascript()`.
 ///
-/// The prepared form is runtime-specific and optimized for the runtime configuration
-/// used when it was created. It can be shared across multiple runtime instances of
-/// the same type for memory efficiency.
+/// The prepared form is optimized for the runtime configuration used when it was
+/// created, and can be evaluated by other runtimes with a compatible configuration. Code
+/// prepared from bytecode with `Runtime::prepare_bytecode()` works with any configuration.
+///
+/// It can be moved to another thread but not shared between threads: Hermes compiles
+/// functions lazily while running them. To parse code once for runtimes on several
+/// threads, share the `CompiledBytecode` and prepare it in each runtime.
 pub struct PreparedJavaScript {
     handle: SharedPtr<ffi::PreparedJavaScript>,
+    /// Compile settings of the preparing runtime, `None` for bytecode
+    compile_flags: Option<u32>,
 }
 
+// SAFETY: JSI allows passing prepared code to other runtimes. The handle is the only
+// reference to it and isn't Clone, so moving it hands the code over to one thread at a time.
+unsafe impl Send for PreparedJavaScript {}
+
 /// Pre-compiled Hermes bytecode ready for execution.
 ///
 /// Created via `Runtime::compile_to_bytecode()`. Can be executed using
@@ -49,16 +69,32 @@ impl CompiledBytecode {
     }
 }
 
+// SAFETY: the bytecode buffer is never modified after creation
+unsafe impl Send for CompiledBytecode {}
+unsafe impl Sync for CompiledBytecode {}
+
 /// A Hermes JavaScript runtime instance.
+///
+/// Values created from the runtime are tied to it: using them with another runtime
+/// panics, and values still alive when the runtime is dropped are leaked instead of
+/// being destroyed after the runtime's memory is gone.
 pub struct Runtime {
     handle: UniquePtr<ffi::HermesRuntime>,
+    state: Arc<RuntimeState>,
+    compile_flags: u32,
+    sample_profiling: bool,
 }
 
 impl Runtime {
     /// Create a new Hermes runtime with the specified configuration.
     pub fn new(config: RuntimeConfig) -> Result<Self> {
         let handle = ffi::create_hermes_runtime(config.as_ref());
-        Ok(Self { handle })
+        Ok(Self {
+            handle,
+            state: RuntimeState::new(),
+            compile_flags: ffi::runtime_config_compile_flags(config.as_ref()),
+            sample_profiling: ffi::runtime_config_sample_profiling(config.as_ref()),
+        })
     }
 
     /// Evaluate JavaScript code.
@@ -70,16 +106,81 @@ impl Runtime {
     pub fn eval_with_result(&mut self, source: &str, source_url: Option<&str>) -> Result<JSValue> {
         let url = source_url.unwrap_or("eval");
 
-        let value_ptr = ffi::eval_js(self.handle.pin_mut(), source, url)?;
+        let value = ffi::eval_js(self.handle.pin_mut(), source, url)?;
+        Ok(JSValue::from_unique_ptr(&self.jsi(), value))
+    }
+
+    /// Bytes currently allocated on the JavaScript heap.
+    pub fn allocated_bytes(&mut self) -> u64 {
+        let heap_info = self.instrumentation().heap_info(false);
+        heap_info
+            .get("hermes_allocatedBytes")
+            .map_or(0, |&bytes| bytes.max(0) as u64)
+    }
+
+    /// Heap statistics and GC control, see `jsi::Instrumentation`.
+    pub fn instrumentation(&mut self) -> Instrumentation<'_> {
+        Instrumentation::new(self)
+    }
+
+    /// Write a heap snapshot in Chrome's `.heapsnapshot` format to `writer`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use hermes_engine::{Runtime, RuntimeConfig};
+    /// let mut runtime = Runtime::new(RuntimeConfig::default())?;
+    /// let file = std::fs::File::create("app.heapsnapshot")?;
+    /// runtime.write_heap_snapshot(std::io::BufWriter::new(file))?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_heap_snapshot<W: Write>(&mut self, writer: W) -> Result<()> {
+        self.instrumentation().write_heap_snapshot(writer)
+    }
+
+    /// Start sampling heap allocations every `interval` bytes on average.
+    pub fn start_heap_sampling(&mut self, interval: usize) -> Result<()> {
+        self.instrumentation().start_heap_sampling(interval)
+    }
+
+    /// Stop sampling heap allocations and write the `.heapprofile` JSON to `writer`.
+    pub fn stop_heap_sampling<W: Write>(&mut self, writer: W) -> Result<()> {
+        self.instrumentation().stop_heap_sampling(writer)
+    }
+
+    /// Start the sampling CPU profiler, taking `frequency_hz` samples per second on average.
+    ///
+    /// Profiling stops when the returned guard is stopped or dropped, see [`Profiler`].
+    /// Fails if another profiler is already sampling at a different frequency.
+    pub fn start_profiler(&mut self, frequency_hz: f64) -> Result<Profiler<'_>> {
+        Profiler::start(self, frequency_hz)
+    }
+
+    /// Attach a programmatic debugger, detached when the returned guard is dropped.
+    ///
+    /// See [`Debugger`] for breakpoints, stepping and pause callbacks.
+    #[cfg(feature = "debugger")]
+    pub fn debugger(&mut self) -> Debugger<'_> {
+        Debugger::attach(self)
+    }
 
-        // SAFETY: Since JSIValue and jsi_rs::sys::ffi::JSIValue are the same type (both facebook::jsi::Value),
-        // we can safely transmute the UniquePtr
-        let js_value = unsafe {
-            let raw_ptr = cxx::UniquePtr::into_raw(value_ptr);
-            JSValue::from_raw(raw_ptr as *mut crate::jsi::sys::ffi::JSIValue)
-        };
+    /// Attach a Chrome DevTools Protocol debugger, listening for clients on `addr`.
+    ///
+    /// The debugger is detached when the returned guard is dropped, see [`Inspector`].
+    #[cfg(feature = "debugger")]
+    pub fn start_inspector(&mut self, addr: impl std::net::ToSocketAddrs) -> Result<Inspector<'_>> {
+        Inspector::start(self, addr)
+    }
+
+    pub(crate) fn state(&self) -> &Arc<RuntimeState> {
+        &self.state
+    }
 
-        Ok(js_value)
+    pub(crate) fn sample_profiling(&self) -> bool {
+        self.sample_profiling
+    }
+
+    pub(crate) fn hermes(&mut self) -> Pin<&mut ffi::HermesRuntime> {
+        self.handle.pin_mut()
     }
 
     /// Get access to the underlying JSI Runtime
@@ -92,10 +193,11 @@ impl Runtime {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn jsi(&mut self) -> jsi_rs::JSRuntime<'_> {
+        let jsi_ref = ffi::get_jsi_runtime(self.handle.pin_mut());
+        // SAFETY: the JSI runtime lives as long as `self`, which the wrapper borrows
         unsafe {
-            let mut jsi_ref = ffi::get_jsi_runtime(self.handle.pin_mut());
-            let ptr = jsi_ref.as_mut().get_unchecked_mut();
-            jsi_rs::JSRuntime::from_raw(ptr as *mut _ as *mut jsi_rs::sys::ffi::JSIRuntime)
+            let ptr = jsi_ref.get_unchecked_mut();
+            jsi_rs::JSRuntime::from_raw_with_state(ptr, self.state.clone())
         }
     }
 
@@ -135,8 +237,20 @@ impl Runtime {
         self.jsi().create_prop_name_id(name)
     }
 
-    pub fn create_bigint<T: jsi::IntoJSIBigInt>(&mut self, value: T) -> jsi::JSBigInt {
-        self.jsi().create_bigint(value)
+    pub fn create_bigint<T: jsi::IntoJSIBigInt>(&mut self, value: T) -> Result<jsi::JSBigInt> {
+        Ok(self.jsi().create_bigint(value)?)
+    }
+
+    pub fn create_host_function<F>(
+        &mut self,
+        name: &str,
+        param_count: u32,
+        func: F,
+    ) -> jsi::JSFunction
+    where
+        F: Fn(&mut jsi::JSRuntime<'_>, &JSValue, &[JSValue]) -> jsi::Result<JSValue> + 'static,
+    {
+        self.jsi().create_host_function(name, param_count, func)
     }
 
     /// Compile JavaScript source to Hermes bytecode.
@@ -177,14 +291,44 @@ impl Runtime {
         let url = source_url.unwrap_or("prepared");
         let handle = ffi::prepare_javascript(self.handle.pin_mut(), source, url)?;
 
-        Ok(PreparedJavaScript { handle })
+        Ok(PreparedJavaScript {
+            handle,
+            compile_flags: Some(self.compile_flags),
+        })
+    }
+
+    /// Prepare pre-compiled bytecode for repeated execution.
+    ///
+    /// Unlike code prepared from source, the result can be evaluated by runtimes of any
+    /// configuration. The bytecode is copied, `bytecode` can be dropped afterwards.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use hermes_engine::{Runtime, RuntimeConfig};
+    /// let bytecode = Runtime::compile_to_bytecode("6 * 7", None)?;
+    /// let prepared = Runtime::new(RuntimeConfig::default())?.prepare_bytecode(&bytecode)?;
+    ///
+    /// let mut other = Runtime::new(RuntimeConfig::default())?;
+    /// let result = other.evaluate_prepared_javascript(&prepared)?;
+    /// assert_eq!(result.as_number(), Some(42.0));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn prepare_bytecode(&mut self, bytecode: &CompiledBytecode) -> Result<PreparedJavaScript> {
+        let handle = ffi::prepare_bytecode(self.handle.pin_mut(), &bytecode.handle)?;
+
+        Ok(PreparedJavaScript {
+            handle,
+            compile_flags: None,
+        })
     }
 
     /// Evaluate prepared JavaScript code and return the result.
     ///
-    /// Executes JavaScript code that was previously prepared using `prepare_javascript()`.
-    /// This is more efficient than calling `eval_with_result()` repeatedly with the same
-    /// source code, as the parsing and optimization has already been done.
+    /// Executes JavaScript code that was previously prepared using `prepare_javascript()`,
+    /// by this or another runtime. This is more efficient than calling `eval_with_result()`
+    /// repeatedly with the same source code, as the parsing and optimization has already
+    /// been done. Code prepared from source by a runtime whose configuration compiles it
+    /// differently, e.g. with generators disabled, is rejected.
     ///
     /// # Example
     /// ```no_run
@@ -200,16 +344,24 @@ impl Runtime {
         &mut self,
         prepared: &PreparedJavaScript,
     ) -> Result<JSValue> {
-        let value_ptr = ffi::evaluate_prepared_javascript(self.handle.pin_mut(), &prepared.handle)?;
+        if prepared
+            .compile_flags
+            .is_some_and(|flags| flags != self.compile_flags)
+        {
+            return Err(Error::preparation(
+                "PreparedJavaScript was prepared by a runtime with an incompatible configuration",
+            ));
+        }
 
-        // SAFETY: Since JSIValue and jsi_rs::sys::ffi::JSIValue are the same type (both facebook::jsi::Value),
-        // we can safely transmute the UniquePtr
-        let js_value = unsafe {
-            let raw_ptr = cxx::UniquePtr::into_raw(value_ptr);
-            JSValue::from_raw(raw_ptr as *mut crate::jsi::sys::ffi::JSIValue)
-        };
+        let value = ffi::evaluate_prepared_javascript(self.handle.pin_mut(), &prepared.handle)?;
+        Ok(JSValue::from_unique_ptr(&self.jsi(), value))
+    }
+}
 
-        Ok(js_value)
+impl Drop for Runtime {
+    fn drop(&mut self) {
+        // Runs before `handle` is destroyed, values dropped from now on are leaked
+        self.state.invalidate();
     }
 }
 
@@ -0,0 +1,211 @@
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::{mpsc, Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+
+use crate::config::RuntimeConfig;
+use crate::error::{Error, Result};
+use crate::runtime::Runtime;
+
+type Job = Box<dyn FnOnce(&mut Runtime) + Send>;
+
+/// A Hermes runtime running on its own thread, driven by submitting closures.
+///
+/// Jobs run one at a time in submission order. Handles are cheap to clone and can be
+/// used from any thread; the runtime thread stops once every handle is dropped and the
+/// queued jobs have run.
+///
+/// # Example
+/// ```no_run
+/// # use hermes_engine::{RuntimeConfig, RuntimeHandle};
+/// let handle = RuntimeHandle::new(RuntimeConfig::default())?;
+///
+/// let job = handle.spawn(|rt| {
+///     let value = rt.eval_with_result("6 * 7", None)?;
+///     Ok(value.as_number())
+/// });
+/// assert_eq!(job.join()?, Some(42.0));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone)]
+pub struct RuntimeHandle {
+    inner: Arc<RuntimeThread>,
+}
+
+struct RuntimeThread {
+    sender: Option<mpsc::Sender<Job>>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl RuntimeHandle {
+    /// Start a runtime with the specified configuration on a dedicated thread.
+    pub fn new(config: RuntimeConfig) -> Result<Self> {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let (ready_sender, ready_receiver) = mpsc::channel();
+
+        let thread = thread::Builder::new()
+            .name("hermes-runtime".to_string())
+            .spawn(move || {
+                let mut runtime = match Runtime::new(config) {
+                    Ok(runtime) => {
+                        let _ = ready_sender.send(Ok(()));
+                        runtime
+                    }
+                    Err(err) => {
+                        let _ = ready_sender.send(Err(err));
+                        return;
+                    }
+                };
+
+                for job in receiver {
+                    job(&mut runtime);
+                }
+            })
+            .map_err(|err| Error::runtime(format!("Failed to spawn runtime thread: {err}")))?;
+
+        ready_receiver
+            .recv()
+            .map_err(|_| Error::runtime("Runtime thread exited during startup"))??;
+
+        Ok(Self {
+            inner: Arc::new(RuntimeThread {
+                sender: Some(sender),
+                thread: Some(thread),
+            }),
+        })
+    }
+
+    /// Run `f` on the runtime thread, returning a handle to its result.
+    ///
+    /// The result can be waited for with [`JobHandle::join`] or awaited as a future. A
+    /// panicking job is reported as an error and leaves the runtime running. Values the job
+    /// returns must be `Send`, so JavaScript values can't leave the runtime thread.
+    pub fn spawn<F, R>(&self, f: F) -> JobHandle<R>
+    where
+        F: FnOnce(&mut Runtime) -> Result<R> + Send + 'static,
+        R: Send + 'static,
+    {
+        let shared = Arc::new(JobState {
+            result: Mutex::new(JobResult {
+                value: None,
+                waker: None,
+            }),
+            done: Condvar::new(),
+        });
+        let completer = JobCompleter {
+            shared: Some(shared.clone()),
+        };
+
+        let job: Job = Box::new(move |runtime| {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| f(runtime)))
+                .unwrap_or_else(|_| Err(Error::runtime("Job panicked on the runtime thread")));
+            completer.complete(result);
+        });
+
+        if let Some(sender) = &self.inner.sender {
+            // A failed send drops the job, its completer then reports the shutdown
+            let _ = sender.send(job);
+        }
+
+        JobHandle { shared }
+    }
+}
+
+impl Drop for RuntimeThread {
+    fn drop(&mut self) {
+        // Closing the channel lets the thread finish the queue and exit
+        self.sender.take();
+
+        if let Some(thread) = self.thread.take() {
+            // The last handle may be dropped by a job, the thread can't wait for itself
+            if thread.thread().id() != thread::current().id() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+struct JobResult<R> {
+    value: Option<Result<R>>,
+    waker: Option<Waker>,
+}
+
+struct JobState<R> {
+    result: Mutex<JobResult<R>>,
+    done: Condvar,
+}
+
+impl<R> JobState<R> {
+    fn finish(&self, value: Result<R>) {
+        let mut result = self.result.lock().unwrap();
+        result.value = Some(value);
+        if let Some(waker) = result.waker.take() {
+            waker.wake();
+        }
+        self.done.notify_all();
+    }
+}
+
+/// Sending half of a job result, reports an error if dropped before completing
+struct JobCompleter<R> {
+    shared: Option<Arc<JobState<R>>>,
+}
+
+impl<R> JobCompleter<R> {
+    fn complete(mut self, value: Result<R>) {
+        if let Some(shared) = self.shared.take() {
+            shared.finish(value);
+        }
+    }
+}
+
+impl<R> Drop for JobCompleter<R> {
+    fn drop(&mut self) {
+        if let Some(shared) = self.shared.take() {
+            shared.finish(Err(Error::runtime("Runtime thread has shut down")));
+        }
+    }
+}
+
+/// Result of a job submitted with [`RuntimeHandle::spawn`].
+///
+/// Either block on it with [`JobHandle::join`] or `.await` it. Dropping the handle doesn't
+/// cancel the job.
+pub struct JobHandle<R> {
+    shared: Arc<JobState<R>>,
+}
+
+impl<R> JobHandle<R> {
+    /// Block the current thread until the job has finished.
+    pub fn join(self) -> Result<R> {
+        let mut result = self.shared.result.lock().unwrap();
+        loop {
+            if let Some(value) = result.value.take() {
+                return value;
+            }
+            result = self.shared.done.wait(result).unwrap();
+        }
+    }
+
+    /// Check whether the job has finished without blocking.
+    pub fn is_finished(&self) -> bool {
+        self.shared.result.lock().unwrap().value.is_some()
+    }
+}
+
+impl<R> Future for JobHandle<R> {
+    type Output = Result<R>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut result = self.shared.result.lock().unwrap();
+        match result.value.take() {
+            Some(value) => Poll::Ready(value),
+            None => {
+                result.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use std::any::Any;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+
+/// Rust writer receiving the output of a C++ `std::ostream`
+pub struct WriteSink<'a> {
+    writer: &'a mut dyn Write,
+    error: Option<io::Error>,
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+impl<'a> WriteSink<'a> {
+    /// Run `stream` with a sink forwarding to `writer`, then flush it
+    ///
+    /// The first write error stops forwarding and is returned once the engine is done. A
+    /// panicking writer is re-raised afterwards, it can't unwind through the C++ frames.
+    pub(crate) fn run(
+        writer: &'a mut dyn Write,
+        stream: impl FnOnce(&mut WriteSink<'a>),
+    ) -> io::Result<()> {
+        let mut sink = WriteSink {
+            writer,
+            error: None,
+            panic: None,
+        };
+        stream(&mut sink);
+
+        if let Some(payload) = sink.panic {
+            panic::resume_unwind(payload);
+        }
+        match sink.error {
+            Some(err) => Err(err),
+            None => sink.writer.flush(),
+        }
+    }
+
+    fn write(&mut self, data: &[u8]) -> bool {
+        if self.error.is_some() || self.panic.is_some() {
+            return false;
+        }
+
+        let writer = &mut self.writer;
+        match panic::catch_unwind(AssertUnwindSafe(|| writer.write_all(data))) {
+            Ok(Ok(())) => true,
+            Ok(Err(err)) => {
+                self.error = Some(err);
+                false
+            }
+            Err(payload) => {
+                self.panic = Some(payload);
+                false
+            }
+        }
+    }
+}
+
+/// Called by the C++ stream buffer with each filled chunk, `false` stops the stream
+pub(crate) fn write_sink_write(sink: &mut WriteSink<'_>, data: &[u8]) -> bool {
+    sink.write(data)
+}
@@ -0,0 +1,117 @@
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use cxx::UniquePtr;
+
+use crate::bridge::ffi;
+use crate::config::RuntimeConfig;
+use crate::error::Result;
+use crate::jsi::{JSRuntime, JSValue, RuntimeState};
+
+/// A Hermes runtime that can be shared between threads.
+///
+/// Wraps `jsi::ThreadSafeRuntime`: every JSI call takes a recursive lock, and
+/// [`ThreadSafeRuntime::lock`] holds it across several calls. Values created under a lock
+/// stay on the thread that created them, drop them before the runtime goes away.
+///
+/// # Example
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use hermes_engine::{RuntimeConfig, ThreadSafeRuntime};
+/// let runtime = Arc::new(ThreadSafeRuntime::new(RuntimeConfig::default())?);
+///
+/// let worker = {
+///     let runtime = runtime.clone();
+///     std::thread::spawn(move || -> hermes_engine::Result<()> {
+///         runtime.lock().eval("globalThis.answer = 42", None)?;
+///         Ok(())
+///     })
+/// };
+/// worker.join().unwrap()?;
+///
+/// let answer = runtime.lock().eval_with_result("answer", None)?;
+/// assert_eq!(answer.as_number(), Some(42.0));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct ThreadSafeRuntime {
+    handle: UniquePtr<ffi::ThreadSafeRuntime>,
+    runtime: *mut ffi::JSIRuntime,
+    state: Arc<RuntimeState>,
+}
+
+impl ThreadSafeRuntime {
+    /// Create a new thread-safe Hermes runtime with the specified configuration.
+    pub fn new(config: RuntimeConfig) -> Result<Self> {
+        let mut handle = ffi::create_thread_safe_hermes_runtime(config.as_ref());
+        // SAFETY: the JSI runtime is the decorator itself and lives as long as `handle`
+        let runtime = unsafe {
+            ffi::get_thread_safe_jsi_runtime(handle.pin_mut()).get_unchecked_mut()
+                as *mut ffi::JSIRuntime
+        };
+
+        Ok(Self {
+            handle,
+            runtime,
+            state: RuntimeState::new_shared(),
+        })
+    }
+
+    /// Acquire the runtime lock, blocking until other threads release it.
+    ///
+    /// The lock is recursive, locking again on the same thread doesn't block.
+    pub fn lock(&self) -> RuntimeLock<'_> {
+        ffi::thread_safe_runtime_lock(&self.handle);
+        RuntimeLock {
+            runtime: self,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+impl Drop for ThreadSafeRuntime {
+    fn drop(&mut self) {
+        // Waits for values being dropped on other threads, later ones are leaked
+        self.state.invalidate();
+    }
+}
+
+// SAFETY: all access to the runtime goes through the lock of `jsi::ThreadSafeRuntime`
+unsafe impl Send for ThreadSafeRuntime {}
+unsafe impl Sync for ThreadSafeRuntime {}
+
+/// Exclusive access to a [`ThreadSafeRuntime`], released when dropped.
+pub struct RuntimeLock<'a> {
+    runtime: &'a ThreadSafeRuntime,
+    // The lock has to be released on the thread that acquired it
+    _not_send: PhantomData<*const ()>,
+}
+
+impl RuntimeLock<'_> {
+    /// Evaluate JavaScript code.
+    pub fn eval(&mut self, source: &str, source_url: Option<&str>) -> Result<()> {
+        self.eval_with_result(source, source_url).map(|_| ())
+    }
+
+    /// Evaluate JavaScript code and return the result.
+    pub fn eval_with_result(&mut self, source: &str, source_url: Option<&str>) -> Result<JSValue> {
+        let url = source_url.unwrap_or("eval");
+
+        // SAFETY: the lock gives this thread exclusive use of the runtime
+        let runtime = unsafe { Pin::new_unchecked(&mut *self.runtime.runtime) };
+        let value = ffi::eval_js_jsi(runtime, source, url)?;
+        Ok(JSValue::from_unique_ptr(&self.jsi(), value))
+    }
+
+    /// Get access to the underlying JSI Runtime while the lock is held
+    pub fn jsi(&mut self) -> JSRuntime<'_> {
+        // SAFETY: the runtime outlives the lock, which the wrapper borrows
+        unsafe { JSRuntime::from_raw_with_state(self.runtime.runtime, self.runtime.state.clone()) }
+    }
+}
+
+impl Drop for RuntimeLock<'_> {
+    fn drop(&mut self) {
+        ffi::thread_safe_runtime_unlock(&self.runtime.handle);
+    }
+}
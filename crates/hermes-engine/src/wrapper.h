@@ -4,12 +4,30 @@
 #include "rust/cxx.h"
 #include <hermes/hermes.h>
 #include <hermes/CompileJS.h>
+#include <hermes/Public/CrashManager.h>
 #include <hermes/Public/RuntimeConfig.h>
+#include <hermes/Public/SamplingProfiler.h>
 #include <jsi/jsi.h>
+#include <jsi/threadsafe.h>
 #include <memory>
 #include <string>
 #include <stdexcept>
 
+// Defined on the Rust side (see WriteSink in stream.rs)
+struct WriteSink;
+
+// Defined on the Rust side (see CrashManagerBox in crash.rs)
+struct CrashManagerBox;
+
+// Callback registered with a CrashManager, writes crash information to a file descriptor
+struct CrashCallback {
+    ::hermes::vm::CrashManager::CallbackFunc run;
+};
+
+inline void crash_callback_call(const CrashCallback& callback, int32_t fd) {
+    callback.run(fd);
+}
+
 // Non-owning buffer for zero-copy bytecode evaluation
 class BorrowedBuffer : public facebook::jsi::Buffer {
 public:
@@ -42,18 +60,20 @@ inline std::unique_ptr<::hermes::vm::RuntimeConfig> create_runtime_config(
     bool enable_hermes_internal,
     bool enable_sample_profiling,
     uint32_t native_stack_gap,
-    uint32_t max_num_registers) {
+    uint32_t max_num_registers,
+    bool record_gc_stats) {
 
     ::hermes::vm::RuntimeConfig::Builder builder;
     ::hermes::vm::GCConfig::Builder gcBuilder;
 
-    if (init_heap_size > 0 || max_heap_size > 0) {
+    if (init_heap_size > 0 || max_heap_size > 0 || record_gc_stats) {
         if (init_heap_size > 0) {
             gcBuilder.withInitHeapSize(init_heap_size);
         }
         if (max_heap_size > 0) {
             gcBuilder.withMaxHeapSize(max_heap_size);
         }
+        gcBuilder.withShouldRecordStats(record_gc_stats);
         builder.withGCConfig(gcBuilder.build());
     }
 
@@ -78,6 +98,35 @@ inline std::unique_ptr<::hermes::vm::RuntimeConfig> create_runtime_config(
     return std::make_unique<::hermes::vm::RuntimeConfig>(builder.build());
 }
 
+// Whether runtimes created from the config register for sampling profiling themselves
+inline bool runtime_config_sample_profiling(const ::hermes::vm::RuntimeConfig& config) {
+    return config.getEnableSampleProfiling();
+}
+
+// Forward the runtime's crash context to a Rust CrashManager (implemented in bridge.cc)
+void runtime_config_set_crash_manager(
+    ::hermes::vm::RuntimeConfig& config,
+    rust::Box<CrashManagerBox> manager);
+
+// Forward fatal errors to the Rust fatal handler (implemented in bridge.cc)
+void install_fatal_handler();
+
+// Copy a RuntimeConfig, e.g. to create several runtimes from one template
+inline std::unique_ptr<::hermes::vm::RuntimeConfig> clone_runtime_config(
+    const ::hermes::vm::RuntimeConfig& config) {
+    return std::make_unique<::hermes::vm::RuntimeConfig>(config);
+}
+
+// Settings that change how a runtime compiles source, prepared code must match them
+inline uint32_t runtime_config_compile_flags(const ::hermes::vm::RuntimeConfig& config) {
+    uint32_t flags = 0;
+    flags |= config.getEnableGenerator() ? 1u << 0 : 0;
+    flags |= config.getES6BlockScoping() ? 1u << 1 : 0;
+    flags |= config.getEnableAsyncGenerators() ? 1u << 2 : 0;
+    flags |= config.getAsyncBreakCheckInEval() ? 1u << 3 : 0;
+    return flags;
+}
+
 // Create a new Hermes runtime with provided configuration
 inline std::unique_ptr<facebook::hermes::HermesRuntime> create_hermes_runtime(
     const ::hermes::vm::RuntimeConfig& config
@@ -85,9 +134,30 @@ inline std::unique_ptr<facebook::hermes::HermesRuntime> create_hermes_runtime(
     return facebook::hermes::makeHermesRuntime(config);
 }
 
-// Evaluate JavaScript source code and return the result
-inline std::unique_ptr<facebook::jsi::Value> eval_js(
-    facebook::hermes::HermesRuntime& runtime,
+// Create a new Hermes runtime guarded by a recursive mutex
+inline std::unique_ptr<facebook::jsi::ThreadSafeRuntime> create_thread_safe_hermes_runtime(
+    const ::hermes::vm::RuntimeConfig& config
+) {
+    return facebook::hermes::makeThreadSafeHermesRuntime(config);
+}
+
+inline void thread_safe_runtime_lock(const facebook::jsi::ThreadSafeRuntime& runtime) {
+    runtime.lock();
+}
+
+inline void thread_safe_runtime_unlock(const facebook::jsi::ThreadSafeRuntime& runtime) {
+    runtime.unlock();
+}
+
+// Get the locking JSI runtime (upcast ThreadSafeRuntime to jsi::Runtime base class)
+inline facebook::jsi::Runtime& get_thread_safe_jsi_runtime(
+    facebook::jsi::ThreadSafeRuntime& runtime) {
+    return static_cast<facebook::jsi::Runtime&>(runtime);
+}
+
+// Evaluate JavaScript source code on any JSI runtime and return the result
+inline std::unique_ptr<facebook::jsi::Value> eval_js_jsi(
+    facebook::jsi::Runtime& runtime,
     rust::Str source,
     rust::Str source_url) {
 
@@ -110,6 +180,15 @@ inline std::unique_ptr<facebook::jsi::Value> eval_js(
     }
 }
 
+// Evaluate JavaScript source code and return the result
+inline std::unique_ptr<facebook::jsi::Value> eval_js(
+    facebook::hermes::HermesRuntime& runtime,
+    rust::Str source,
+    rust::Str source_url) {
+
+    return eval_js_jsi(runtime, source, source_url);
+}
+
 // Compile JavaScript to bytecode - returns CompiledBytecode wrapper
 inline std::unique_ptr<CompiledBytecode> compile_js_to_bytecode(
     rust::Str source,
@@ -170,6 +249,180 @@ inline facebook::jsi::Runtime& get_jsi_runtime(facebook::hermes::HermesRuntime&
     return static_cast<facebook::jsi::Runtime&>(runtime);
 }
 
+// Current heap statistics, flattened into parallel key/value vectors since cxx can't return maps
+inline void instrumentation_heap_info(
+    facebook::hermes::HermesRuntime& runtime,
+    bool include_expensive,
+    rust::Vec<rust::String>& keys,
+    rust::Vec<int64_t>& values) {
+
+    for (const auto& [key, value] : runtime.instrumentation().getHeapInfo(include_expensive)) {
+        keys.push_back(rust::String(key));
+        values.push_back(value);
+    }
+}
+
+// Perform a full garbage collection
+inline void instrumentation_collect_garbage(
+    facebook::hermes::HermesRuntime& runtime,
+    rust::Str cause) {
+    runtime.instrumentation().collectGarbage(std::string(cause.data(), cause.size()));
+}
+
+// GC statistics recorded so far, JSON-encoded
+inline rust::String instrumentation_recorded_gc_stats(facebook::hermes::HermesRuntime& runtime) {
+    return rust::String(runtime.instrumentation().getRecordedGCStats());
+}
+
+// Start sampling heap allocations every `interval` bytes on average
+inline void instrumentation_start_heap_sampling(
+    facebook::hermes::HermesRuntime& runtime,
+    size_t interval) {
+    runtime.instrumentation().startHeapSampling(interval);
+}
+
+// Heap export through a Rust writer (implemented in bridge.cc, it needs the generated
+// WriteSink callback)
+void instrumentation_create_heap_snapshot(
+    facebook::hermes::HermesRuntime& runtime,
+    WriteSink& sink);
+
+void instrumentation_stop_heap_sampling(
+    facebook::hermes::HermesRuntime& runtime,
+    WriteSink& sink);
+
+// Start the process-wide sampling profiler
+inline void enable_sampling_profiler(double mean_hz_freq) {
+    facebook::hermes::HermesRuntime::enableSamplingProfiler(mean_hz_freq);
+}
+
+// Stop the process-wide sampling profiler
+inline void disable_sampling_profiler() {
+    facebook::hermes::HermesRuntime::disableSamplingProfiler();
+}
+
+// Sample this runtime's thread while the sampling profiler is enabled
+inline void register_for_profiling(facebook::hermes::HermesRuntime& runtime) {
+    runtime.registerForProfiling();
+}
+
+inline void unregister_for_profiling(facebook::hermes::HermesRuntime& runtime) {
+    runtime.unregisterForProfiling();
+}
+
+// Write the runtime's samples as a Chrome DevTools .cpuprofile (implemented in bridge.cc)
+void sampled_trace_to_devtools(
+    facebook::hermes::HermesRuntime& runtime,
+    WriteSink& sink);
+
+// Flatten the runtime's samples: one (timestamp, thread, stack depth) entry per sample, and
+// the frames of all stacks in order, innermost first. Frame kinds: 0 JS function, 1 native
+// function, 2 host function, 3 GC, 4 debugger, 5 multiple suspensions. Missing lines and
+// columns are -1.
+inline void sampled_trace_to_profile(
+    facebook::hermes::HermesRuntime& runtime,
+    rust::Vec<uint64_t>& timestamps,
+    rust::Vec<uint64_t>& threads,
+    rust::Vec<size_t>& depths,
+    rust::Vec<uint8_t>& kinds,
+    rust::Vec<rust::String>& names,
+    rust::Vec<uint32_t>& script_ids,
+    rust::Vec<rust::String>& urls,
+    rust::Vec<int64_t>& lines,
+    rust::Vec<int64_t>& columns) {
+
+    using namespace facebook::hermes::sampling_profiler;
+    using Kind = ProfileSampleCallStackFrame::Kind;
+    using SuspendKind = ProfileSampleCallStackSuspendFrame::SuspendFrameKind;
+
+    auto profile = runtime.dumpSampledTraceToProfile();
+    for (const auto& sample : profile.getSamples()) {
+        const auto& stack = sample.getCallStack();
+        timestamps.push_back(sample.getTimestamp());
+        threads.push_back(sample.getThreadId());
+        depths.push_back(stack.size());
+
+        for (const auto* frame : stack) {
+            uint8_t kind = 0;
+            std::string name;
+            uint32_t script_id = 0;
+            std::string url;
+            int64_t line = -1;
+            int64_t column = -1;
+
+            switch (frame->getKind()) {
+                case Kind::JSFunction: {
+                    auto js = static_cast<const ProfileSampleCallStackJSFunctionFrame*>(frame);
+                    name = js->getFunctionName();
+                    script_id = js->getScriptId();
+                    url = js->getScriptUrl().value_or("");
+                    if (js->getFunctionLineNumber()) {
+                        line = *js->getFunctionLineNumber();
+                    }
+                    if (js->getFunctionColumnNumber()) {
+                        column = *js->getFunctionColumnNumber();
+                    }
+                    break;
+                }
+                case Kind::NativeFunction:
+                    kind = 1;
+                    name = static_cast<const ProfileSampleCallStackNativeFunctionFrame*>(frame)
+                               ->getFunctionName();
+                    break;
+                case Kind::HostFunction:
+                    kind = 2;
+                    name = static_cast<const ProfileSampleCallStackHostFunctionFrame*>(frame)
+                               ->getFunctionName();
+                    break;
+                case Kind::Suspend:
+                    switch (static_cast<const ProfileSampleCallStackSuspendFrame*>(frame)
+                                ->getSuspendFrameKind()) {
+                        case SuspendKind::GC:
+                            kind = 3;
+                            break;
+                        case SuspendKind::Debugger:
+                            kind = 4;
+                            break;
+                        default:
+                            kind = 5;
+                            break;
+                    }
+                    break;
+            }
+
+            kinds.push_back(kind);
+            names.push_back(rust::String(name));
+            script_ids.push_back(script_id);
+            urls.push_back(rust::String(url));
+            lines.push_back(line);
+            columns.push_back(column);
+        }
+    }
+}
+
+// Start the process-wide code coverage profiler
+inline void enable_code_coverage_profiler() {
+    facebook::hermes::HermesRuntime::enableCodeCoverageProfiler();
+}
+
+// Stop the process-wide code coverage profiler
+inline void disable_code_coverage_profiler() {
+    facebook::hermes::HermesRuntime::disableCodeCoverageProfiler();
+}
+
+// Flatten the executed functions of every runtime, one (runtime, function) pair per entry
+inline void get_executed_functions(
+    rust::Vec<rust::String>& runtimes,
+    rust::Vec<rust::String>& functions) {
+
+    for (const auto& [runtime, executed] : facebook::hermes::HermesRuntime::getExecutedFunctions()) {
+        for (const auto& function : executed) {
+            runtimes.push_back(rust::String(runtime));
+            functions.push_back(rust::String(function));
+        }
+    }
+}
+
 // Prepare JavaScript for optimized execution
 inline std::shared_ptr<facebook::jsi::PreparedJavaScript> prepare_javascript(
     facebook::hermes::HermesRuntime& runtime,
@@ -194,6 +447,31 @@ inline std::shared_ptr<facebook::jsi::PreparedJavaScript> prepare_javascript(
     }
 }
 
+// Prepare bytecode for evaluation in any runtime
+inline std::shared_ptr<facebook::jsi::PreparedJavaScript> prepare_bytecode(
+    facebook::hermes::HermesRuntime& runtime,
+    const CompiledBytecode& bytecode) {
+
+    if (bytecode.data.empty()) {
+        throw std::runtime_error("Invalid bytecode buffer");
+    }
+
+    try {
+        // The prepared code keeps the buffer alive, copy it so it doesn't borrow `bytecode`
+        auto buffer = std::make_shared<facebook::jsi::StringBuffer>(bytecode.data);
+        auto prepared = runtime.prepareJavaScript(buffer, "bundle");
+
+        // CXX doesn't support const in SharedPtr, so we need to cast it away
+        return std::const_pointer_cast<facebook::jsi::PreparedJavaScript>(prepared);
+    } catch (const facebook::jsi::JSError& e) {
+        std::string error_msg = "JSError: " + e.getMessage();
+        throw std::runtime_error(error_msg);
+    } catch (const std::exception& e) {
+        std::string error_msg = "Error: " + std::string(e.what());
+        throw std::runtime_error(error_msg);
+    }
+}
+
 // Evaluate prepared JavaScript and return the result
 inline std::unique_ptr<facebook::jsi::Value> evaluate_prepared_javascript(
     facebook::hermes::HermesRuntime& runtime,
@@ -1,6 +1,6 @@
 mod tests {
     use hermes_engine::jsi::{JSArray, JSValue};
-    use hermes_engine::{Result, Runtime, RuntimeConfig};
+    use hermes_engine::{Error, Result, Runtime, RuntimeConfig};
 
     #[test]
     fn test_jsarray_new() -> Result<()> {
@@ -13,6 +13,21 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_jsarray_into_value() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let array = JSArray::new(&mut jsi_runtime, 2);
+        let value = JSValue::from(array);
+
+        let array = value
+            .as_array(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not an array"))?;
+        assert_eq!(array.len(&mut jsi_runtime), 2);
+        Ok(())
+    }
+
     #[test]
     fn test_jsarray_get_set() -> Result<()> {
         let mut runtime = Runtime::new(RuntimeConfig::default())?;
@@ -33,9 +48,9 @@ mod tests {
         let retrieved3 = array.get(&mut jsi_runtime, 2);
 
         assert!(retrieved1.is_number());
-        assert_eq!(retrieved1.as_number(), 42.0);
+        assert_eq!(retrieved1.as_number(), Some(42.0));
         assert!(retrieved2.is_bool());
-        assert_eq!(retrieved2.as_bool(), true);
+        assert_eq!(retrieved2.as_bool(), Some(true));
         assert!(retrieved3.is_null());
 
         Ok(())
@@ -67,10 +82,73 @@ mod tests {
         let value = array.get(&mut jsi_runtime, 10);
         assert!(value.is_undefined());
 
+        // Setting past the end grows the array
         let val = JSValue::number(42.0);
-        let result = array.set(&mut jsi_runtime, 10, &val);
-        assert!(result.is_err());
+        array.set(&mut jsi_runtime, 10, &val)?;
+        assert_eq!(array.len(&mut jsi_runtime), 11);
+        assert_eq!(array.get(&mut jsi_runtime, 10).as_number(), Some(42.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsarray_from_values() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let values = [JSValue::number(1.0), JSValue::bool(true), JSValue::null()];
+        let array = JSArray::from_values(&mut jsi_runtime, &values);
+
+        assert_eq!(array.len(&mut jsi_runtime), 3);
+        assert_eq!(array.get(&mut jsi_runtime, 0).as_number(), Some(1.0));
+        assert_eq!(array.get(&mut jsi_runtime, 1).as_bool(), Some(true));
+        assert!(array.get(&mut jsi_runtime, 2).is_null());
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsarray_push() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let array = JSArray::new(&mut jsi_runtime, 0);
+        array.push(&mut jsi_runtime, &JSValue::number(1.0))?;
+        array.push(&mut jsi_runtime, &JSValue::number(2.0))?;
+
+        assert_eq!(array.len(&mut jsi_runtime), 2);
+        assert_eq!(array.get(&mut jsi_runtime, 1).as_number(), Some(2.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsarray_extend_from_iter() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let array = JSArray::new(&mut jsi_runtime, 1);
+        array.extend_from_iter(&mut jsi_runtime, (0..3).map(|i| JSValue::number(i as f64)))?;
+
+        assert_eq!(array.len(&mut jsi_runtime), 4);
+        assert!(array.get(&mut jsi_runtime, 0).is_undefined());
+        assert_eq!(array.get(&mut jsi_runtime, 3).as_number(), Some(2.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsarray_iter() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result("[1, 2, 3]", None)?;
+        let mut jsi_runtime = runtime.jsi();
+        let array = value
+            .as_array(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not an array"))?;
+
+        let iter = array.iter(&mut jsi_runtime);
+        assert_eq!(iter.len(), 3);
 
+        let sum: f64 = iter.filter_map(|value| value.as_number()).sum();
+        assert_eq!(sum, 6.0);
         Ok(())
     }
 }
@@ -1,12 +1,13 @@
 mod tests {
-    use hermes_engine::{Result, Runtime, RuntimeConfig};
+    use hermes_engine::jsi::{FromJs, IntoJs, JSBigInt, JSValue};
+    use hermes_engine::{Error, Result, Runtime, RuntimeConfig};
 
     #[test]
     fn test_jsbigint_from_i64() -> Result<()> {
         let mut runtime = Runtime::new(RuntimeConfig::default())?;
         let mut jsi_runtime = runtime.jsi();
 
-        let bigint = jsi_runtime.create_bigint::<i64>(-9223372036854775808);
+        let bigint = jsi_runtime.create_bigint::<i64>(-9223372036854775808)?;
 
         assert!(!bigint.inner().is_null());
         Ok(())
@@ -17,18 +18,30 @@ mod tests {
         let mut runtime = Runtime::new(RuntimeConfig::default())?;
         let mut jsi_runtime = runtime.jsi();
 
-        let bigint = jsi_runtime.create_bigint::<u64>(18446744073709551615);
+        let bigint = jsi_runtime.create_bigint::<u64>(18446744073709551615)?;
 
         assert!(!bigint.inner().is_null());
         Ok(())
     }
 
+    #[test]
+    fn test_jsbigint_into_value() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let bigint = jsi_runtime.create_bigint::<i64>(42)?;
+        let value = JSValue::from(bigint);
+
+        assert!(value.is_bigint());
+        Ok(())
+    }
+
     #[test]
     fn test_jsbigint_as_string_default_radix() -> Result<()> {
         let mut runtime = Runtime::new(RuntimeConfig::default())?;
         let mut jsi_runtime = runtime.jsi();
 
-        let bigint = jsi_runtime.create_bigint::<i64>(42);
+        let bigint = jsi_runtime.create_bigint::<i64>(42)?;
         let result = bigint.as_string(&mut jsi_runtime)?;
         let value = result.value(&mut jsi_runtime);
 
@@ -41,7 +54,7 @@ mod tests {
         let mut runtime = Runtime::new(RuntimeConfig::default())?;
         let mut jsi_runtime = runtime.jsi();
 
-        let bigint = jsi_runtime.create_bigint::<i64>(-123456789123456789);
+        let bigint = jsi_runtime.create_bigint::<i64>(-123456789123456789)?;
         let result = bigint.as_string(&mut jsi_runtime)?;
         let value = result.value(&mut jsi_runtime);
 
@@ -54,7 +67,7 @@ mod tests {
         let mut runtime = Runtime::new(RuntimeConfig::default())?;
         let mut jsi_runtime = runtime.jsi();
 
-        let bigint = jsi_runtime.create_bigint::<i64>(42);
+        let bigint = jsi_runtime.create_bigint::<i64>(42)?;
         let result = bigint.as_string_opt(&mut jsi_runtime, 2)?;
         let value = result.value(&mut jsi_runtime);
 
@@ -67,7 +80,7 @@ mod tests {
         let mut runtime = Runtime::new(RuntimeConfig::default())?;
         let mut jsi_runtime = runtime.jsi();
 
-        let bigint = jsi_runtime.create_bigint::<i64>(255);
+        let bigint = jsi_runtime.create_bigint::<i64>(255)?;
         let result = bigint.as_string_opt(&mut jsi_runtime, 16)?;
         let value = result.value(&mut jsi_runtime);
 
@@ -80,7 +93,7 @@ mod tests {
         let mut runtime = Runtime::new(RuntimeConfig::default())?;
         let mut jsi_runtime = runtime.jsi();
 
-        let bigint = jsi_runtime.create_bigint::<i64>(-123456789123456789);
+        let bigint = jsi_runtime.create_bigint::<i64>(-123456789123456789)?;
         let result = bigint.as_string_opt(&mut jsi_runtime, 32)?;
         let value = result.value(&mut jsi_runtime);
 
@@ -93,7 +106,7 @@ mod tests {
         let mut runtime = Runtime::new(RuntimeConfig::default())?;
         let mut jsi_runtime = runtime.jsi();
 
-        let bigint = jsi_runtime.create_bigint::<i64>(1234567890);
+        let bigint = jsi_runtime.create_bigint::<i64>(1234567890)?;
         let result = bigint.as_string_opt(&mut jsi_runtime, 36)?;
         let value = result.value(&mut jsi_runtime);
 
@@ -106,7 +119,7 @@ mod tests {
         let mut runtime = Runtime::new(RuntimeConfig::default())?;
         let mut jsi_runtime = runtime.jsi();
 
-        let bigint = jsi_runtime.create_bigint::<i64>(42);
+        let bigint = jsi_runtime.create_bigint::<i64>(42)?;
         let result = bigint.as_string_opt(&mut jsi_runtime, 1);
 
         assert!(result.is_err());
@@ -118,7 +131,7 @@ mod tests {
         let mut runtime = Runtime::new(RuntimeConfig::default())?;
         let mut jsi_runtime = runtime.jsi();
 
-        let bigint = jsi_runtime.create_bigint::<i64>(42);
+        let bigint = jsi_runtime.create_bigint::<i64>(42)?;
         let result = bigint.as_string_opt(&mut jsi_runtime, 37);
 
         assert!(result.is_err());
@@ -130,7 +143,7 @@ mod tests {
         let mut runtime = Runtime::new(RuntimeConfig::default())?;
         let mut jsi_runtime = runtime.jsi();
 
-        let bigint = jsi_runtime.create_bigint::<u64>(18446744073709551615);
+        let bigint = jsi_runtime.create_bigint::<u64>(18446744073709551615)?;
         let result = bigint.as_string(&mut jsi_runtime)?;
         let value = result.value(&mut jsi_runtime);
 
@@ -143,10 +156,130 @@ mod tests {
         let mut runtime = Runtime::new(RuntimeConfig::default())?;
         let mut jsi_runtime = runtime.jsi();
 
-        let bigint = jsi_runtime.create_bigint::<i64>(-123456789);
+        let bigint = jsi_runtime.create_bigint::<i64>(-123456789)?;
         let value = bigint.to_string(&mut jsi_runtime)?;
 
         assert_eq!(value, "-123456789");
         Ok(())
     }
+
+    #[test]
+    fn test_jsbigint_as_i64_u64() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let negative = jsi_runtime.create_bigint::<i64>(-42)?;
+        assert_eq!(negative.as_i64(&mut jsi_runtime), Some(-42));
+        assert_eq!(negative.as_u64(&mut jsi_runtime), None);
+
+        let large = jsi_runtime.create_bigint::<u64>(u64::MAX)?;
+        assert_eq!(large.as_i64(&mut jsi_runtime), None);
+        assert_eq!(large.as_u64(&mut jsi_runtime), Some(u64::MAX));
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsbigint_truncate() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let negative = jsi_runtime.create_bigint::<i64>(-1)?;
+        assert_eq!(negative.truncate(&mut jsi_runtime), u64::MAX);
+
+        let wide = JSBigInt::from_u128(&mut jsi_runtime, (1u128 << 64) + 5)?;
+        assert_eq!(wide.truncate(&mut jsi_runtime), 5);
+        assert_eq!(wide.as_u64(&mut jsi_runtime), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsbigint_i128_roundtrip() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        for value in [i128::MIN, -1, 0, i64::MAX as i128 + 1, i128::MAX] {
+            let bigint = JSBigInt::from_i128(&mut jsi_runtime, value)?;
+            assert_eq!(bigint.as_i128(&mut jsi_runtime), Some(value));
+            assert_eq!(bigint.to_string(&mut jsi_runtime)?, value.to_string());
+        }
+
+        let bigint = JSBigInt::from_u128(&mut jsi_runtime, u128::MAX)?;
+        assert_eq!(bigint.as_u128(&mut jsi_runtime), Some(u128::MAX));
+        assert_eq!(bigint.as_i128(&mut jsi_runtime), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsbigint_parse() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let decimal =
+            JSBigInt::parse(&mut jsi_runtime, "-340282366920938463463374607431768211455")?;
+        assert_eq!(
+            decimal.to_string(&mut jsi_runtime)?,
+            "-340282366920938463463374607431768211455"
+        );
+
+        let hex = JSBigInt::parse(&mut jsi_runtime, "0xff")?;
+        assert_eq!(hex.as_i64(&mut jsi_runtime), Some(255));
+
+        let negative_hex = JSBigInt::parse(&mut jsi_runtime, "-0xFF")?;
+        assert_eq!(negative_hex.as_i64(&mut jsi_runtime), Some(-255));
+
+        for invalid in ["", "-", "0x", "1.5", "12a", "0xg"] {
+            assert!(JSBigInt::parse(&mut jsi_runtime, invalid).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsbigint_i128_conversion() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let from_script = runtime.eval_with_result("2n ** 100n", None)?;
+        let number = runtime.eval_with_result("42", None)?;
+        let mut jsi_runtime = runtime.jsi();
+
+        assert_eq!(i128::from_js(&mut jsi_runtime, &from_script)?, 1i128 << 100);
+        assert_eq!(u128::from_js(&mut jsi_runtime, &number)?, 42);
+
+        let value = (-1i128 << 100).into_js(&mut jsi_runtime)?;
+        assert!(value.is_bigint());
+        assert!(u128::from_js(&mut jsi_runtime, &value).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsbigint_num_bigint() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let from_script = runtime.eval_with_result("-(3n ** 200n)", None)?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let expected = -num_bigint::BigInt::from(3).pow(200);
+        let decoded = num_bigint::BigInt::from_js(&mut jsi_runtime, &from_script)?;
+        assert_eq!(decoded, expected);
+        assert!(num_bigint::BigUint::from_js(&mut jsi_runtime, &from_script).is_err());
+
+        let value = expected.clone().into_js(&mut jsi_runtime)?;
+        let bigint = value
+            .as_bigint(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not a bigint"))?;
+        assert_eq!(bigint.to_string(&mut jsi_runtime)?, expected.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsbigint_i128_without_global_bigint() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        runtime.eval("delete globalThis.BigInt;", None)?;
+        let mut jsi_runtime = runtime.jsi();
+
+        assert!(JSBigInt::from_i128(&mut jsi_runtime, i128::MAX).is_err());
+        // Values in the 64-bit range don't need the global
+        let bigint = JSBigInt::from_i128(&mut jsi_runtime, -1)?;
+        assert_eq!(bigint.as_i128(&mut jsi_runtime), Some(-1));
+        Ok(())
+    }
 }
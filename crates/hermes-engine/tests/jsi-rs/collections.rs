@@ -0,0 +1,131 @@
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use hermes_engine::jsi::{FromJs, IntoJs, JSMap, JSSet, JSValue};
+    use hermes_engine::{Error, Result, Runtime, RuntimeConfig};
+
+    #[test]
+    fn test_jsmap_from_entries() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let map = JSMap::from_entries(&mut jsi_runtime, vec![("a", 1.0), ("b", 2.0)])?;
+        assert_eq!(map.size(&mut jsi_runtime)?, 2);
+
+        let key = "b".into_js(&mut jsi_runtime)?;
+        assert!(map.has(&mut jsi_runtime, &key)?);
+        assert_eq!(map.get(&mut jsi_runtime, &key)?.as_number(), Some(2.0));
+
+        assert!(map.delete(&mut jsi_runtime, &key)?);
+        assert!(!map.delete(&mut jsi_runtime, &key)?);
+        assert_eq!(map.size(&mut jsi_runtime)?, 1);
+
+        map.clear(&mut jsi_runtime)?;
+        assert_eq!(map.size(&mut jsi_runtime)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsmap_object_keys() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        // Unlike plain objects, maps keep non-string keys as they are
+        let map = JSMap::new(&mut jsi_runtime)?;
+        map.set(
+            &mut jsi_runtime,
+            &JSValue::number(1.0),
+            &JSValue::bool(true),
+        )?;
+
+        let entries = map.entries(&mut jsi_runtime)?;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].0.as_number(), Some(1.0));
+        assert_eq!(entries[0].1.as_bool(), Some(true));
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsmap_to_hash_map() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result("new Map([['x', 1], ['y', 2]])", None)?;
+        let plain = runtime.eval_with_result("({ x: 1 })", None)?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let map = value
+            .as_map(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not a Map"))?;
+        let map: HashMap<String, u32> = map.to_hash_map(&mut jsi_runtime)?;
+        assert_eq!(map.get("x"), Some(&1));
+        assert_eq!(map.get("y"), Some(&2));
+
+        assert!(plain.as_map(&mut jsi_runtime).is_none());
+        assert!(value.as_set(&mut jsi_runtime).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsset_values() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let set = JSSet::from_values(&mut jsi_runtime, vec![1.0, 2.0, 1.0])?;
+        assert_eq!(set.size(&mut jsi_runtime)?, 2);
+        assert!(set.has(&mut jsi_runtime, &JSValue::number(2.0))?);
+        assert!(!set.has(&mut jsi_runtime, &JSValue::number(3.0))?);
+
+        set.add(&mut jsi_runtime, &JSValue::number(3.0))?;
+        let values = set.values(&mut jsi_runtime)?;
+        let numbers: Vec<f64> = values
+            .iter()
+            .filter_map(|value| value.as_number())
+            .collect();
+        assert_eq!(numbers, vec![1.0, 2.0, 3.0]);
+
+        assert!(set.delete(&mut jsi_runtime, &JSValue::number(1.0))?);
+        assert_eq!(set.size(&mut jsi_runtime)?, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_set_roundtrip() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let set: HashSet<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+        let value = set.clone().into_js(&mut jsi_runtime)?;
+
+        assert!(value.as_set(&mut jsi_runtime).is_some());
+        assert_eq!(HashSet::<String>::from_js(&mut jsi_runtime, &value)?, set);
+
+        let array = vec![1.0].into_js(&mut jsi_runtime)?;
+        assert!(HashSet::<String>::from_js(&mut jsi_runtime, &array).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsmap_ignores_spoofed_prototype() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let fake = runtime.eval_with_result("Object.create(Map.prototype)", None)?;
+        let mut jsi_runtime = runtime.jsi();
+        assert!(fake.as_map(&mut jsi_runtime).is_none());
+
+        let map = JSMap::from_entries(&mut jsi_runtime, vec![("a", 1.0)])?;
+        let key = "a".into_js(&mut jsi_runtime)?;
+        assert_eq!(map.get(&mut jsi_runtime, &key)?.as_number(), Some(1.0));
+        assert_eq!(map.size(&mut jsi_runtime)?, 1);
+
+        // Builtins are captured on first use, later changes by scripts don't apply
+        runtime.eval(
+            "Map.prototype.get = () => 'replaced'; \
+             Object.defineProperty(Map.prototype, 'size', { get: () => 42 });",
+            None,
+        )?;
+        let mut jsi_runtime = runtime.jsi();
+        assert_eq!(map.get(&mut jsi_runtime, &key)?.as_number(), Some(1.0));
+        assert_eq!(map.size(&mut jsi_runtime)?, 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+mod tests {
+    use std::collections::HashMap;
+
+    use hermes_engine::jsi::{FromJs, IntoJs, JSValue};
+    use hermes_engine::{Error, Result, Runtime, RuntimeConfig};
+
+    #[test]
+    fn test_convert_integers() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let value = 42i32.into_js(&mut jsi_runtime)?;
+        assert_eq!(i32::from_js(&mut jsi_runtime, &value)?, 42);
+        assert_eq!(u8::from_js(&mut jsi_runtime, &value)?, 42);
+
+        let value = JSValue::number(-1.0);
+        assert_eq!(i64::from_js(&mut jsi_runtime, &value)?, -1);
+        assert!(u32::from_js(&mut jsi_runtime, &value).is_err());
+
+        let value = JSValue::number(256.0);
+        assert!(u8::from_js(&mut jsi_runtime, &value).is_err());
+
+        let value = JSValue::number(1.5);
+        assert!(i32::from_js(&mut jsi_runtime, &value).is_err());
+
+        let value = JSValue::number(f64::NAN);
+        assert!(i32::from_js(&mut jsi_runtime, &value).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_large_integers() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let max_safe = 9007199254740991i64;
+        let value = max_safe.into_js(&mut jsi_runtime)?;
+        assert_eq!(i64::from_js(&mut jsi_runtime, &value)?, max_safe);
+
+        assert!((max_safe + 1).into_js(&mut jsi_runtime).is_err());
+        assert!(u64::MAX.into_js(&mut jsi_runtime).is_err());
+
+        let value = JSValue::number(9007199254740992.0);
+        assert!(i64::from_js(&mut jsi_runtime, &value).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_type_mismatch() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let value = "42".into_js(&mut jsi_runtime)?;
+        let err = i32::from_js(&mut jsi_runtime, &value)
+            .err()
+            .ok_or_else(|| Error::internal("Expected an error"))?;
+        assert_eq!(err.message(), "expected number, got string");
+
+        assert!(bool::from_js(&mut jsi_runtime, &JSValue::number(1.0)).is_err());
+        assert!(String::from_js(&mut jsi_runtime, &JSValue::null()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_option_and_unit() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let value = None::<f64>.into_js(&mut jsi_runtime)?;
+        assert!(value.is_null());
+        assert_eq!(Option::<f64>::from_js(&mut jsi_runtime, &value)?, None);
+        assert_eq!(
+            Option::<f64>::from_js(&mut jsi_runtime, &JSValue::undefined())?,
+            None
+        );
+
+        let value = Some(2.5).into_js(&mut jsi_runtime)?;
+        assert_eq!(Option::<f64>::from_js(&mut jsi_runtime, &value)?, Some(2.5));
+
+        let value = ().into_js(&mut jsi_runtime)?;
+        assert!(value.is_undefined());
+        <()>::from_js(&mut jsi_runtime, &value)?;
+        assert!(<()>::from_js(&mut jsi_runtime, &JSValue::bool(true)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_vec() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result("[1, 2, 3]", None)?;
+        let mut jsi_runtime = runtime.jsi();
+        assert_eq!(
+            Vec::<u32>::from_js(&mut jsi_runtime, &value)?,
+            vec![1, 2, 3]
+        );
+
+        let value = vec!["a".to_string(), "b".to_string()].into_js(&mut jsi_runtime)?;
+        assert_eq!(
+            Vec::<String>::from_js(&mut jsi_runtime, &value)?,
+            vec!["a".to_string(), "b".to_string()]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_vec_errors() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let mixed = runtime.eval_with_result("[1, 'two']", None)?;
+        let object = runtime.eval_with_result("({ length: 1, 0: 1 })", None)?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let err = Vec::<f64>::from_js(&mut jsi_runtime, &mixed)
+            .err()
+            .ok_or_else(|| Error::internal("Expected an error"))?;
+        assert_eq!(err.message(), "[1]: expected number, got string");
+
+        assert!(Vec::<f64>::from_js(&mut jsi_runtime, &object).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_hashmap() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result("({ a: 1, b: 2 })", None)?;
+        let mut jsi_runtime = runtime.jsi();
+        let map = HashMap::<String, f64>::from_js(&mut jsi_runtime, &value)?;
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get("a"), Some(&1.0));
+        assert_eq!(map.get("b"), Some(&2.0));
+
+        let value = map.into_js(&mut jsi_runtime)?;
+        let roundtrip = HashMap::<String, f64>::from_js(&mut jsi_runtime, &value)?;
+        assert_eq!(roundtrip.get("a"), Some(&1.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_hashmap_skips_inherited() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result(
+            "const limits = Object.create({ inherited: 9 });\nlimits.own = 1;\nlimits",
+            None,
+        )?;
+        let map = HashMap::<String, f64>::from_js(&mut runtime.jsi(), &value)?;
+
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get("own"), Some(&1.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_tuple() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result("[1, 'x', true]", None)?;
+        let mut jsi_runtime = runtime.jsi();
+        let tuple = <(i32, String, bool)>::from_js(&mut jsi_runtime, &value)?;
+        assert_eq!(tuple, (1, "x".to_string(), true));
+
+        assert!(<(i32, String)>::from_js(&mut jsi_runtime, &value).is_err());
+
+        let value = (2.5, false).into_js(&mut jsi_runtime)?;
+        assert_eq!(
+            <(f64, bool)>::from_js(&mut jsi_runtime, &value)?,
+            (2.5, false)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+mod tests {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use chrono::{TimeZone, Utc};
+    use hermes_engine::jsi::{FromJs, IntoJs, JSDate};
+    use hermes_engine::{Error, Result, Runtime, RuntimeConfig};
+
+    #[test]
+    fn test_jsdate_from_millis() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let date = JSDate::from_millis(&mut jsi_runtime, 86_400_000.0)?;
+        assert_eq!(date.millis(&mut jsi_runtime)?, 86_400_000.0);
+
+        let time = date.to_system_time(&mut jsi_runtime)?;
+        assert_eq!(time, UNIX_EPOCH + Duration::from_secs(86_400));
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsdate_from_js() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result("new Date(Date.UTC(2024, 0, 2))", None)?;
+        let invalid = runtime.eval_with_result("new Date(NaN)", None)?;
+        let plain = runtime.eval_with_result("({ getTime() { return 0; } })", None)?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let date = value
+            .as_date(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not a Date"))?;
+        assert_eq!(date.millis(&mut jsi_runtime)?, 1_704_153_600_000.0);
+
+        let invalid = invalid
+            .as_date(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not a Date"))?;
+        assert!(invalid.to_system_time(&mut jsi_runtime).is_err());
+
+        assert!(plain.as_date(&mut jsi_runtime).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_system_time_roundtrip() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let before_epoch = UNIX_EPOCH - Duration::from_millis(1500);
+        let after_epoch = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+
+        for time in [before_epoch, after_epoch] {
+            let value = time.into_js(&mut jsi_runtime)?;
+            assert_eq!(SystemTime::from_js(&mut jsi_runtime, &value)?, time);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_chrono_roundtrip() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let time = Utc
+            .timestamp_millis_opt(1_700_000_000_123)
+            .single()
+            .ok_or_else(|| Error::internal("Invalid timestamp"))?;
+        let value = time.into_js(&mut runtime.jsi())?;
+
+        let iso = runtime.eval_with_result("(function (d) { return d.toISOString(); })", None)?;
+        let mut jsi_runtime = runtime.jsi();
+        let arg = value.clone_in(&mut jsi_runtime);
+        let iso = iso
+            .as_function(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not a function"))?
+            .call(&mut jsi_runtime, &[arg])?;
+        assert_eq!(
+            String::from_js(&mut jsi_runtime, &iso)?,
+            "2023-11-14T22:13:20.123Z"
+        );
+
+        let decoded = chrono::DateTime::<Utc>::from_js(&mut jsi_runtime, &value)?;
+        assert_eq!(decoded, time);
+        Ok(())
+    }
+}
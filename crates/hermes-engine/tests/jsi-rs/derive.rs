@@ -0,0 +1,296 @@
+mod tests {
+    use hermes_engine::jsi::{self, js_class, FromJs, IntoJs, JSRuntime, JSValue};
+    use hermes_engine::{Error, Result, Runtime, RuntimeConfig};
+
+    #[derive(Debug, PartialEq, IntoJs, FromJs)]
+    #[js(crate = "hermes_engine::jsi", rename_all = "camelCase")]
+    struct User {
+        user_name: String,
+        active: bool,
+        #[js(rename = "SCORE")]
+        score: f64,
+        #[js(skip)]
+        cached: bool,
+    }
+
+    #[derive(Debug, PartialEq, IntoJs, FromJs)]
+    struct Point(f64, f64);
+
+    #[derive(Debug, PartialEq, IntoJs, FromJs)]
+    struct Meters(f64);
+
+    #[derive(Debug, PartialEq, IntoJs, FromJs)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Line(Point, Point),
+        Rect { width: f64, height: f64 },
+    }
+
+    struct Counter {
+        count: f64,
+    }
+
+    #[js_class]
+    impl Counter {
+        #[js_method(constructor)]
+        fn new(start: f64) -> Self {
+            Counter { count: start }
+        }
+
+        #[js_method]
+        fn increment(&mut self, by: f64) -> f64 {
+            self.count += by;
+            self.count
+        }
+
+        #[js_method(name = "value")]
+        fn current(&self) -> f64 {
+            self.count
+        }
+
+        #[js_method]
+        fn checked_reset(&mut self, to: f64) -> std::result::Result<(), String> {
+            if to < 0.0 {
+                return Err("negative count".to_string());
+            }
+            self.count = to;
+            Ok(())
+        }
+
+        #[js_method]
+        fn label(&self, runtime: &mut JSRuntime<'_>) -> jsi::Result<JSValue> {
+            format!("count={}", self.count).into_js(runtime)
+        }
+    }
+
+    fn call_js(runtime: &mut Runtime, source: &str, args: &[JSValue]) -> Result<JSValue> {
+        let function = runtime.eval_with_result(source, Some("test.js"))?;
+        let mut jsi_runtime = runtime.jsi();
+        let function = function
+            .as_function(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not a function"))?;
+        Ok(function.call(&mut jsi_runtime, args)?)
+    }
+
+    #[test]
+    fn test_derive_struct_into_js() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let user = User {
+            user_name: "ann".to_string(),
+            active: true,
+            score: 7.5,
+            cached: true,
+        };
+        let value = user.into_js(&mut runtime.jsi())?;
+
+        let result = call_js(
+            &mut runtime,
+            "(function (u) { return [u.userName, u.active, u.SCORE, 'cached' in u].join(','); })",
+            &[value],
+        )?;
+        let result = String::from_js(&mut runtime.jsi(), &result)?;
+
+        assert_eq!(result, "ann,true,7.5,false");
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_struct_from_js() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value =
+            runtime.eval_with_result("({ userName: 'bob', active: false, SCORE: 3 })", None)?;
+        let user = User::from_js(&mut runtime.jsi(), &value)?;
+
+        assert_eq!(
+            user,
+            User {
+                user_name: "bob".to_string(),
+                active: false,
+                score: 3.0,
+                cached: false,
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_struct_from_js_type_mismatch() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value =
+            runtime.eval_with_result("({ userName: 42, active: false, SCORE: 3 })", None)?;
+        let err = User::from_js(&mut runtime.jsi(), &value)
+            .err()
+            .ok_or_else(|| Error::internal("Expected an error"))?;
+
+        assert!(err.message().contains("User.userName"));
+        assert!(err.message().contains("expected string, got number"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_struct_from_js_throwing_getter() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result(
+            "({ get userName() { throw new Error('boom'); }, active: false, SCORE: 3 })",
+            None,
+        )?;
+        let err = User::from_js(&mut runtime.jsi(), &value)
+            .err()
+            .ok_or_else(|| Error::internal("Expected an error"))?;
+
+        assert!(err.message().contains("User.userName"));
+        assert!(err.message().contains("boom"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_tuple_and_newtype_roundtrip() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let value = Point(1.0, 2.0).into_js(&mut jsi_runtime)?;
+        assert!(value.is_object());
+        assert_eq!(Point::from_js(&mut jsi_runtime, &value)?, Point(1.0, 2.0));
+
+        let value = Meters(3.5).into_js(&mut jsi_runtime)?;
+        assert!(value.is_number());
+        assert_eq!(Meters::from_js(&mut jsi_runtime, &value)?, Meters(3.5));
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_enum_roundtrip() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let shapes = vec![
+            Shape::Empty,
+            Shape::Circle(2.0),
+            Shape::Line(Point(0.0, 0.0), Point(1.0, 1.0)),
+            Shape::Rect {
+                width: 3.0,
+                height: 4.0,
+            },
+        ];
+
+        for shape in shapes {
+            let expected = format!("{:?}", shape);
+            let value = shape.into_js(&mut jsi_runtime)?;
+            let decoded = Shape::from_js(&mut jsi_runtime, &value)?;
+            assert_eq!(format!("{:?}", decoded), expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_enum_from_js_representation() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let empty = runtime.eval_with_result("'Empty'", None)?;
+        let rect = runtime.eval_with_result("({ Rect: { width: 1, height: 2 } })", None)?;
+        let unknown = runtime.eval_with_result("'Triangle'", None)?;
+
+        let mut jsi_runtime = runtime.jsi();
+        assert_eq!(Shape::from_js(&mut jsi_runtime, &empty)?, Shape::Empty);
+        assert_eq!(
+            Shape::from_js(&mut jsi_runtime, &rect)?,
+            Shape::Rect {
+                width: 1.0,
+                height: 2.0
+            }
+        );
+        assert!(Shape::from_js(&mut jsi_runtime, &unknown).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_enum_from_js_ignores_inherited_keys() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let circle = runtime.eval_with_result(
+            "const proto = { Empty: null };\n\
+             const circle = Object.create(proto);\n\
+             circle.Circle = 2;\n\
+             circle",
+            None,
+        )?;
+
+        let mut jsi_runtime = runtime.jsi();
+        assert_eq!(
+            Shape::from_js(&mut jsi_runtime, &circle)?,
+            Shape::Circle(2.0)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_js_class_methods() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let counter = Counter { count: 1.0 }.into_js(&mut runtime.jsi())?;
+        let result = call_js(
+            &mut runtime,
+            "(function (c) { c.increment(2); c.increment(3); return c.value() + ' ' + c.label(); })",
+            &[counter],
+        )?;
+
+        let result = String::from_js(&mut runtime.jsi(), &result)?;
+        assert_eq!(result, "6 count=6");
+        Ok(())
+    }
+
+    #[test]
+    fn test_js_class_method_error() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let counter = Counter { count: 1.0 }.into_js(&mut runtime.jsi())?;
+        let result = call_js(
+            &mut runtime,
+            "(function (c) { try { c.checkedReset(-1); } catch (e) { return e.message; } })",
+            &[counter],
+        )?;
+
+        let message = String::from_js(&mut runtime.jsi(), &result)?;
+        assert!(message.contains("negative count"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_js_class_constructor() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let constructor = Counter::js_constructor(&mut jsi_runtime);
+        let counter = constructor.call(&mut jsi_runtime, &[JSValue::number(10.0)])?;
+
+        assert!(counter.is_object());
+        let result = call_js(
+            &mut runtime,
+            "(function (c) { return c.increment(5); })",
+            &[counter],
+        )?;
+        assert_eq!(f64::from_js(&mut runtime.jsi(), &result)?, 15.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_js_class_argument_type_error() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let counter = Counter { count: 1.0 }.into_js(&mut runtime.jsi())?;
+        let result = call_js(
+            &mut runtime,
+            "(function (c) { try { c.increment('x'); } catch (e) { return e.message; } })",
+            &[counter],
+        )?;
+
+        let message = String::from_js(&mut runtime.jsi(), &result)?;
+        assert!(message.contains("increment: argument 0"));
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 mod tests {
-    use hermes_engine::jsi::JSValue;
+    use hermes_engine::jsi::{self, JSFunction, JSValue};
     use hermes_engine::{Error, Result, Runtime, RuntimeConfig};
 
     #[test]
@@ -25,7 +25,7 @@ mod tests {
         let result = add_func.call(&mut jsi_runtime, &args)?;
 
         assert!(result.is_number());
-        assert_eq!(result.as_number(), 8.0);
+        assert_eq!(result.as_number(), Some(8.0));
         Ok(())
     }
 
@@ -77,4 +77,82 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_jsfunction_no_conversion_from_object() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let object = runtime.eval_with_result("({ call: 1 })", None)?;
+        let array = runtime.eval_with_result("[]", None)?;
+
+        let mut jsi_runtime = runtime.jsi();
+        assert!(object.as_function(&mut jsi_runtime).is_none());
+        assert!(array.as_function(&mut jsi_runtime).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsfunction_into_value() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let func = JSFunction::from_host_fn(&mut jsi_runtime, "noop", 0, |_rt, _this, _args| {
+            Ok(JSValue::undefined())
+        });
+        let value = JSValue::from(func);
+
+        assert!(value.is_object());
+        assert!(value.as_function(&mut jsi_runtime).is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_host_function_call() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let multiply =
+            JSFunction::from_host_fn(&mut jsi_runtime, "multiply", 2, |_rt, _this, args| {
+                Ok(JSValue::number(
+                    args[0].try_as_number()? * args[1].try_as_number()?,
+                ))
+            });
+
+        let args = vec![JSValue::number(6.0), JSValue::number(7.0)];
+        let result = multiply.call(&mut jsi_runtime, &args)?;
+
+        assert!(result.is_number());
+        assert_eq!(result.as_number(), Some(42.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_host_function_error() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let fail = runtime.create_host_function("fail", 0, |_rt, _this, _args| {
+            Err(jsi::Error::new("host failure"))
+        });
+
+        let result = fail.call(&mut runtime.jsi(), &[]);
+        let err = result
+            .err()
+            .ok_or_else(|| Error::internal("Expected an error"))?;
+        assert!(err.message().contains("host failure"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_host_function_panic() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let boom = JSFunction::from_host_fn(&mut jsi_runtime, "boom", 0, |_rt, _this, _args| {
+            panic!("boom")
+        });
+
+        assert!(boom.call(&mut jsi_runtime, &[]).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,102 @@
+mod tests {
+    use std::panic::{self, AssertUnwindSafe};
+
+    use hermes_engine::jsi::JSValue;
+    use hermes_engine::{Error, Result, Runtime, RuntimeConfig};
+
+    #[test]
+    fn test_runtime_state_identity() -> Result<()> {
+        let mut runtime_a = Runtime::new(RuntimeConfig::default())?;
+        let mut runtime_b = Runtime::new(RuntimeConfig::default())?;
+
+        let id_a = runtime_a.jsi().state().map(|state| state.id());
+        let id_b = runtime_b.jsi().state().map(|state| state.id());
+
+        assert!(id_a.is_some());
+        assert_ne!(id_a, id_b);
+        assert_eq!(id_a, runtime_a.jsi().state().map(|state| state.id()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_handle_used_with_other_runtime_panics() -> Result<()> {
+        let mut runtime_a = Runtime::new(RuntimeConfig::default())?;
+        let mut runtime_b = Runtime::new(RuntimeConfig::default())?;
+
+        let object = runtime_a.create_object();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let mut jsi_runtime = runtime_b.jsi();
+            object.get(&mut jsi_runtime, "value")
+        }));
+        assert!(result.is_err());
+
+        let value = runtime_a.eval_with_result("({})", None)?;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let mut jsi_runtime = runtime_b.jsi();
+            let target = jsi_runtime.create_object();
+            target.set(&mut jsi_runtime, "value", &value);
+        }));
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_detached_values_work_with_any_runtime() -> Result<()> {
+        let mut runtime_a = Runtime::new(RuntimeConfig::default())?;
+        let mut runtime_b = Runtime::new(RuntimeConfig::default())?;
+
+        let number = JSValue::number(42.0);
+        let object_a = runtime_a.create_object();
+        object_a.set(&mut runtime_a.jsi(), "n", &number);
+        let object_b = runtime_b.create_object();
+        object_b.set(&mut runtime_b.jsi(), "n", &number);
+
+        assert_eq!(
+            object_b.get(&mut runtime_b.jsi(), "n").as_number(),
+            Some(42.0)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_values_outliving_runtime() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result("({ nested: { list: [1, 2, 3] } })", None)?;
+        let string = runtime.create_string("still here");
+        let state = runtime
+            .jsi()
+            .state()
+            .cloned()
+            .ok_or_else(|| Error::internal("Runtime has no state"))?;
+        assert!(state.is_alive());
+
+        drop(runtime);
+        assert!(!state.is_alive());
+
+        // Dropping after the runtime must not touch its freed memory
+        drop(value);
+        drop(string);
+        Ok(())
+    }
+
+    #[test]
+    fn test_host_function_values_belong_to_runtime() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let func = runtime.create_host_function("wrap", 1, |rt, _this, args| {
+            let object = rt.create_object();
+            object.set(rt, "value", &args[0]);
+            Ok(JSValue::from(object))
+        });
+
+        let mut jsi_runtime = runtime.jsi();
+        let result = func.call(&mut jsi_runtime, &[JSValue::number(7.0)])?;
+        let object = result
+            .as_object(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not an object"))?;
+
+        assert_eq!(object.get(&mut jsi_runtime, "value").as_number(), Some(7.0));
+        Ok(())
+    }
+}
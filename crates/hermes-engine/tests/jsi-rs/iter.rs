@@ -0,0 +1,147 @@
+mod tests {
+    use hermes_engine::jsi::{self, FromJs, JSValue};
+    use hermes_engine::{Result, Runtime, RuntimeConfig};
+
+    fn collect_numbers(runtime: &mut Runtime, source: &str) -> Result<Vec<f64>> {
+        let iterable = runtime.eval_with_result(source, None)?;
+        let mut numbers = Vec::new();
+        jsi::for_of(&mut runtime.jsi(), &iterable, |_rt, value| {
+            numbers.push(value.try_as_number()?);
+            Ok(())
+        })?;
+        Ok(numbers)
+    }
+
+    #[test]
+    fn test_for_of_array() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        assert_eq!(
+            collect_numbers(&mut runtime, "[1, 2, 3]")?,
+            vec![1.0, 2.0, 3.0]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_for_of_set() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        assert_eq!(
+            collect_numbers(&mut runtime, "new Set([3, 1, 3, 2])")?,
+            vec![3.0, 1.0, 2.0]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_for_of_map() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let map = runtime.eval_with_result("new Map([['a', 1], ['b', 2]])", None)?;
+        let mut entries = Vec::new();
+        jsi::for_of(&mut runtime.jsi(), &map, |rt, entry| {
+            entries.push(<(String, f64)>::from_js(rt, &entry)?);
+            Ok(())
+        })?;
+
+        assert_eq!(
+            entries,
+            vec![("a".to_string(), 1.0), ("b".to_string(), 2.0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_for_of_generator() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        assert_eq!(
+            collect_numbers(&mut runtime, "(function* () { yield 1; yield 2; })()")?,
+            vec![1.0, 2.0]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_for_of_bigint_done() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        // `0n` is falsy and `1n` truthy, like in a `for...of` loop
+        let source = "({ [Symbol.iterator]() {\n\
+                        let i = 0;\n\
+                        return { next: () => (++i < 3 ? { done: 0n, value: i } : { done: 1n }) };\n\
+                      } })";
+        assert_eq!(collect_numbers(&mut runtime, source)?, vec![1.0, 2.0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_for_of_ignores_replaced_symbol() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        assert_eq!(collect_numbers(&mut runtime, "[1]")?, vec![1.0]);
+        runtime.eval("globalThis.Symbol = { iterator: 'fake' };", None)?;
+        assert_eq!(collect_numbers(&mut runtime, "[1, 2]")?, vec![1.0, 2.0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_for_of_string() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let string = runtime.eval_with_result("'héllo'", None)?;
+        let mut chars = String::new();
+        jsi::for_of(&mut runtime.jsi(), &string, |rt, value| {
+            chars.push_str(&String::from_js(rt, &value)?);
+            chars.push('|');
+            Ok(())
+        })?;
+
+        assert_eq!(chars, "h|é|l|l|o|");
+        Ok(())
+    }
+
+    #[test]
+    fn test_for_of_not_iterable() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let object = runtime.eval_with_result("({ a: 1 })", None)?;
+        let mut jsi_runtime = runtime.jsi();
+
+        assert!(jsi::for_of(&mut jsi_runtime, &object, |_rt, _value| Ok(())).is_err());
+        assert!(jsi::for_of(
+            &mut jsi_runtime,
+            &JSValue::number(1.0),
+            |_rt, _value| Ok(())
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_for_of_error_closes_iterator() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        runtime.eval(
+            "var closed = false; var gen = (function* () { try { yield 1; yield 2; } finally { closed = true; } })();",
+            None,
+        )?;
+        let generator = runtime.eval_with_result("gen", None)?;
+
+        let mut seen = 0;
+        let result = jsi::for_of(&mut runtime.jsi(), &generator, |_rt, _value| {
+            seen += 1;
+            Err(jsi::Error::new("stop"))
+        });
+
+        assert_eq!(seen, 1);
+        assert_eq!(
+            result.err().map(|e| e.message().to_string()),
+            Some("stop".to_string())
+        );
+        let closed = runtime.eval_with_result("closed", None)?;
+        assert_eq!(closed.as_bool(), Some(true));
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+mod tests {
+    use hermes_engine::jsi::{FromJs, IntoJs};
+    use hermes_engine::{Error, Result, Runtime, RuntimeConfig};
+    use serde_json::json;
+
+    #[test]
+    fn test_json_parse() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let value = jsi_runtime.json_parse(r#"{"name": "kermio", "tags": [1, 2]}"#)?;
+        let obj = value
+            .as_object(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not an object"))?;
+
+        let name = obj.get(&mut jsi_runtime, "name");
+        assert_eq!(String::from_js(&mut jsi_runtime, &name)?, "kermio");
+        let tags = obj.get(&mut jsi_runtime, "tags");
+        assert_eq!(Vec::<u32>::from_js(&mut jsi_runtime, &tags)?, vec![1, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_parse_is_not_eval() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        assert!(jsi_runtime.json_parse("{invalid").is_err());
+        assert!(jsi_runtime
+            .json_parse("(globalThis.injected = true)")
+            .is_err());
+
+        let value = jsi_runtime.json_parse(r#""'); globalThis.injected = true; //""#)?;
+        assert!(value.is_string());
+
+        let global = jsi_runtime.global();
+        assert!(global.get(&mut jsi_runtime, "injected").is_undefined());
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_stringify() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result("({ a: 1, b: [true, null], c: undefined })", None)?;
+        let func = runtime.eval_with_result("(function () {})", None)?;
+        let mut jsi_runtime = runtime.jsi();
+
+        assert_eq!(
+            jsi_runtime.json_stringify(&value, None)?,
+            Some(r#"{"a":1,"b":[true,null]}"#.to_string())
+        );
+        assert_eq!(
+            jsi_runtime.json_stringify(&value, Some(2))?,
+            Some("{\n  \"a\": 1,\n  \"b\": [\n    true,\n    null\n  ]\n}".to_string())
+        );
+        assert_eq!(jsi_runtime.json_stringify(&func, None)?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_stringify_cycle() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result(
+            "(function () { var o = {}; o.self = o; return o; })()",
+            None,
+        )?;
+
+        assert!(runtime.jsi().json_stringify(&value, None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_json_roundtrip() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let original = json!({
+            "name": "kermio",
+            "version": 1.5,
+            "features": ["jsi", "json"],
+            "nested": { "enabled": true, "none": null }
+        });
+
+        let value = original.clone().into_js(&mut jsi_runtime)?;
+        assert!(value.is_object());
+
+        let decoded = serde_json::Value::from_js(&mut jsi_runtime, &value)?;
+        assert_eq!(decoded, original);
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_json_from_js() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let date = runtime.eval_with_result("({ at: new Date(0), skip() {} })", None)?;
+        let undefined = runtime.eval_with_result("undefined", None)?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let decoded = serde_json::Value::from_js(&mut jsi_runtime, &date)?;
+        assert_eq!(decoded, json!({ "at": "1970-01-01T00:00:00.000Z" }));
+
+        assert!(serde_json::Value::from_js(&mut jsi_runtime, &undefined).is_err());
+        Ok(())
+    }
+}
@@ -1,8 +1,17 @@
 mod array;
 mod bigint;
+mod collections;
+mod convert;
+mod date;
+mod derive;
 mod function;
+mod handle;
+mod iter;
+mod json;
 mod object;
+mod persistent;
 mod propnameid;
 mod runtime_shortcuts;
+mod scope;
 mod string;
 mod value;
@@ -1,6 +1,6 @@
 mod tests {
-    use hermes_engine::jsi::{JSObject, JSValue};
-    use hermes_engine::{Result, Runtime, RuntimeConfig};
+    use hermes_engine::jsi::{JSFunction, JSObject, JSPropNameID, JSValue, PropertyDescriptor};
+    use hermes_engine::{Error, Result, Runtime, RuntimeConfig};
 
     #[test]
     fn test_jsobject_new() -> Result<()> {
@@ -27,7 +27,7 @@ mod tests {
         // Get the property back
         let result = obj.get(&mut jsi_runtime, "answer");
         assert!(result.is_number());
-        assert_eq!(result.as_number(), 42.0);
+        assert_eq!(result.as_number(), Some(42.0));
 
         Ok(())
     }
@@ -79,11 +79,74 @@ mod tests {
         assert!(obj.has(&mut jsi_runtime, "temp"));
 
         // Delete it
-        obj.delete(&mut jsi_runtime, "temp");
+        obj.delete(&mut jsi_runtime, "temp")?;
 
-        // The property value should now be undefined
+        // The property is gone, not just set to undefined
         let result = obj.get(&mut jsi_runtime, "temp");
         assert!(result.is_undefined());
+        assert!(!obj.has(&mut jsi_runtime, "temp"));
+        let names = obj.get_property_names(&mut jsi_runtime);
+        assert_eq!(names.len(&mut jsi_runtime), 0);
+
+        // Deleting a missing property succeeds
+        obj.delete(&mut jsi_runtime, "missing")?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsobject_delete_with_id() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let obj = JSObject::new(&mut jsi_runtime);
+        let name = JSPropNameID::new(&mut jsi_runtime, "temp");
+        obj.set_with_id(&mut jsi_runtime, &name, &JSValue::bool(true))?;
+
+        obj.delete_with_id(&mut jsi_runtime, &name)?;
+        assert!(!obj.has_with_id(&mut jsi_runtime, &name)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsobject_delete_non_configurable() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let obj = JSObject::new(&mut jsi_runtime);
+        obj.define_property(
+            &mut jsi_runtime,
+            "fixed",
+            PropertyDescriptor::new()
+                .value(JSValue::number(1.0))
+                .configurable(false),
+        )?;
+
+        assert!(obj.delete(&mut jsi_runtime, "fixed").is_err());
+        assert!(obj.has(&mut jsi_runtime, "fixed"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsobject_delete_proxy_trap_error() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result(
+            "new Proxy({}, { deleteProperty() { throw new Error('denied'); } })",
+            None,
+        )?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let obj = value
+            .as_object(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not an object"))?;
+        let err = obj
+            .delete(&mut jsi_runtime, "key")
+            .err()
+            .ok_or_else(|| Error::internal("Expected an error"))?;
+        assert!(err.message().contains("denied"));
 
         Ok(())
     }
@@ -128,4 +191,338 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_jsobject_type_checks() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let plain = runtime.eval_with_result("({})", None)?;
+        let array = runtime.eval_with_result("[1, 2]", None)?;
+        let func = runtime.eval_with_result("(function () {})", None)?;
+        let buffer = runtime.eval_with_result("new ArrayBuffer(8)", None)?;
+
+        let mut jsi_runtime = runtime.jsi();
+        let mut object = |value: &JSValue| {
+            value
+                .as_object(&mut jsi_runtime)
+                .ok_or_else(|| Error::internal("Not an object"))
+        };
+        let plain = object(&plain)?;
+        let array = object(&array)?;
+        let func = object(&func)?;
+        let buffer = object(&buffer)?;
+
+        assert!(!plain.is_array(&mut jsi_runtime));
+        assert!(!plain.is_function(&mut jsi_runtime));
+        assert!(!plain.is_array_buffer(&mut jsi_runtime));
+
+        assert!(array.is_array(&mut jsi_runtime));
+        assert!(!array.is_function(&mut jsi_runtime));
+
+        assert!(func.is_function(&mut jsi_runtime));
+        assert!(!func.is_array(&mut jsi_runtime));
+
+        assert!(buffer.is_array_buffer(&mut jsi_runtime));
+        assert!(!buffer.is_array(&mut jsi_runtime));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsobject_as_array() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result("[1, 2, 3]", None)?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let obj = value
+            .as_object(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not an object"))?;
+        let array = obj
+            .as_array(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not an array"))?;
+        assert_eq!(array.len(&mut jsi_runtime), 3);
+
+        let plain = JSObject::new(&mut jsi_runtime);
+        assert!(plain.as_array(&mut jsi_runtime).is_none());
+        assert!(plain.as_function(&mut jsi_runtime).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsobject_into_value() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let obj = JSObject::new(&mut jsi_runtime);
+        obj.set(&mut jsi_runtime, "answer", &JSValue::number(42.0));
+
+        let value = JSValue::from(obj);
+        assert!(value.is_object());
+
+        let obj = value
+            .as_object(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not an object"))?;
+        assert_eq!(obj.get(&mut jsi_runtime, "answer").as_number(), Some(42.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsobject_strict_equals() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result("({})", None)?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let a = value
+            .as_object(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not an object"))?;
+        let b = value
+            .as_object(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not an object"))?;
+        let other = JSObject::new(&mut jsi_runtime);
+
+        assert!(a.strict_equals(&mut jsi_runtime, &b));
+        assert!(!a.strict_equals(&mut jsi_runtime, &other));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsobject_property_with_id() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let obj = JSObject::new(&mut jsi_runtime);
+        let name = JSPropNameID::new(&mut jsi_runtime, "count");
+
+        assert!(!obj.has_with_id(&mut jsi_runtime, &name)?);
+        obj.set_with_id(&mut jsi_runtime, &name, &JSValue::number(3.0))?;
+        assert!(obj.has_with_id(&mut jsi_runtime, &name)?);
+
+        let value = obj.get_with_id(&mut jsi_runtime, &name)?;
+        assert_eq!(value.as_number(), Some(3.0));
+        assert_eq!(obj.get(&mut jsi_runtime, "count").as_number(), Some(3.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsobject_get_with_id_throwing_getter() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value =
+            runtime.eval_with_result("({ get broken() { throw new Error('boom'); } })", None)?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let obj = value
+            .as_object(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not an object"))?;
+        let name = JSPropNameID::new(&mut jsi_runtime, "broken");
+
+        let err = obj
+            .get_with_id(&mut jsi_runtime, &name)
+            .err()
+            .ok_or_else(|| Error::internal("Expected an error"))?;
+        assert!(err.message().contains("boom"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsobject_define_property_value() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let obj = JSObject::new(&mut jsi_runtime);
+        obj.define_property(
+            &mut jsi_runtime,
+            "hidden",
+            PropertyDescriptor::new()
+                .value(JSValue::number(1.0))
+                .writable(false)
+                .enumerable(false),
+        )?;
+
+        assert_eq!(obj.get(&mut jsi_runtime, "hidden").as_number(), Some(1.0));
+
+        // Non-enumerable properties are not listed, read-only ones ignore writes
+        let names = obj.get_property_names(&mut jsi_runtime);
+        assert_eq!(names.len(&mut jsi_runtime), 0);
+        obj.set(&mut jsi_runtime, "hidden", &JSValue::number(2.0));
+        assert_eq!(obj.get(&mut jsi_runtime, "hidden").as_number(), Some(1.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsobject_define_property_ignores_replaced_builtin() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let first = JSObject::new(&mut runtime.jsi());
+        first.define_property(
+            &mut runtime.jsi(),
+            "a",
+            PropertyDescriptor::new().value(JSValue::number(1.0)),
+        )?;
+
+        runtime.eval(
+            "Object.defineProperty = () => { throw new Error('hijacked'); };",
+            None,
+        )?;
+        let mut jsi_runtime = runtime.jsi();
+        let obj = JSObject::new(&mut jsi_runtime);
+        obj.define_property(
+            &mut jsi_runtime,
+            "b",
+            PropertyDescriptor::new().value(JSValue::number(2.0)),
+        )?;
+
+        assert_eq!(obj.get(&mut jsi_runtime, "b").as_number(), Some(2.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsobject_define_property_accessor() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let getter = JSFunction::from_host_fn(&mut jsi_runtime, "get", 0, |_rt, _this, _args| {
+            Ok(JSValue::number(42.0))
+        });
+
+        let obj = JSObject::new(&mut jsi_runtime);
+        obj.define_property(
+            &mut jsi_runtime,
+            "answer",
+            PropertyDescriptor::new().getter(getter).enumerable(true),
+        )?;
+
+        assert_eq!(obj.get(&mut jsi_runtime, "answer").as_number(), Some(42.0));
+        let names = obj.get_property_names(&mut jsi_runtime);
+        assert_eq!(names.len(&mut jsi_runtime), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsobject_define_property_invalid() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let getter = JSFunction::from_host_fn(&mut jsi_runtime, "get", 0, |_rt, _this, _args| {
+            Ok(JSValue::undefined())
+        });
+
+        // A descriptor can't be both a data and an accessor property
+        let obj = JSObject::new(&mut jsi_runtime);
+        let result = obj.define_property(
+            &mut jsi_runtime,
+            "both",
+            PropertyDescriptor::new()
+                .value(JSValue::number(1.0))
+                .getter(getter),
+        );
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsobject_prototype() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let proto = runtime.eval_with_result("({ greet() { return 'hi'; } })", None)?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let obj = JSObject::new(&mut jsi_runtime);
+        let default_proto = obj.get_prototype(&mut jsi_runtime)?;
+        assert!(default_proto.is_object());
+
+        obj.set_prototype(&mut jsi_runtime, &proto)?;
+        let current = obj.get_prototype(&mut jsi_runtime)?;
+        assert!(current.strict_equals(&mut jsi_runtime, &proto));
+        assert!(obj
+            .get(&mut jsi_runtime, "greet")
+            .as_function(&mut jsi_runtime)
+            .is_some());
+
+        obj.set_prototype(&mut jsi_runtime, &JSValue::null())?;
+        assert!(obj.get_prototype(&mut jsi_runtime)?.is_null());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsobject_instance_of() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let date = runtime.eval_with_result("new Date(0)", None)?;
+        let date_ctor = runtime.eval_with_result("Date", None)?;
+        let array_ctor = runtime.eval_with_result("Array", None)?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let date = date
+            .as_object(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not an object"))?;
+        let date_ctor = date_ctor
+            .as_function(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not a function"))?;
+        let array_ctor = array_ctor
+            .as_function(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not a function"))?;
+
+        assert!(date.instance_of(&mut jsi_runtime, &date_ctor)?);
+        assert!(!date.instance_of(&mut jsi_runtime, &array_ctor)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsobject_keys_and_entries() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result("({ a: 1, b: 'two' })", None)?;
+        let mut jsi_runtime = runtime.jsi();
+        let obj = value
+            .as_object(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not an object"))?;
+
+        assert_eq!(obj.keys(&mut jsi_runtime)?, vec!["a", "b"]);
+
+        let entries = obj.entries(&mut jsi_runtime)?;
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].0, "a");
+        assert_eq!(entries[0].1.as_number(), Some(1.0));
+        assert_eq!(entries[1].0, "b");
+        assert!(entries[1].1.is_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsobject_keys_skip_inherited() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result(
+            "Object.assign(Object.create({ inherited: 1 }), { own: 2 })",
+            None,
+        )?;
+        let mut jsi_runtime = runtime.jsi();
+        let obj = value
+            .as_object(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not an object"))?;
+
+        assert_eq!(obj.keys(&mut jsi_runtime)?, vec!["own"]);
+        assert_eq!(obj.entries(&mut jsi_runtime)?.len(), 1);
+        assert_eq!(
+            obj.get_property_names(&mut jsi_runtime)
+                .len(&mut jsi_runtime),
+            2
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,114 @@
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use hermes_engine::jsi::{Error as JsiError, JSFunction, JSObject, JSValue, Persistent};
+    use hermes_engine::{Error, Result, Runtime, RuntimeConfig};
+
+    #[test]
+    fn test_persistent_event_listeners() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let listeners: Rc<RefCell<Vec<Persistent<JSFunction>>>> = Rc::default();
+        let registry = listeners.clone();
+        let on_message = runtime.create_host_function("onMessage", 1, move |rt, _this, args| {
+            let callback = args
+                .first()
+                .and_then(|value| value.as_function(rt))
+                .ok_or_else(|| JsiError::new("expected a function"))?;
+            registry.borrow_mut().push(Persistent::new(rt, callback));
+            Ok(JSValue::undefined())
+        });
+
+        let mut jsi_runtime = runtime.jsi();
+        let global = jsi_runtime.global();
+        global.set(&mut jsi_runtime, "onMessage", &JSValue::from(on_message));
+        drop(jsi_runtime);
+
+        runtime.eval(
+            "var received = []; onMessage(function (m) { received.push(m); }); onMessage(function (m) { received.push(m * 2); });",
+            None,
+        )?;
+
+        let mut jsi_runtime = runtime.jsi();
+        for listener in listeners.borrow().iter() {
+            let callback = listener.get(&mut jsi_runtime)?;
+            callback.call(&mut jsi_runtime, &[JSValue::number(21.0)])?;
+        }
+        drop(jsi_runtime);
+
+        let received = runtime.eval_with_result("received.join(',')", None)?;
+        let mut jsi_runtime = runtime.jsi();
+        let received = received
+            .as_string(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not a string"))?;
+        assert_eq!(received.value(&mut jsi_runtime), "21,42");
+        Ok(())
+    }
+
+    #[test]
+    fn test_persistent_clone_and_release() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let object = JSObject::new(&mut jsi_runtime);
+        object.set(&mut jsi_runtime, "id", &JSValue::number(1.0));
+        let persistent = Persistent::new(&jsi_runtime, object);
+        let copy = persistent.clone();
+
+        let object = copy.get(&mut jsi_runtime)?;
+        assert_eq!(object.get(&mut jsi_runtime, "id").as_number(), Some(1.0));
+
+        persistent.release();
+        assert!(copy.is_released());
+        assert!(copy.get(&mut jsi_runtime).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_persistent_released_on_runtime_shutdown() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result("({ data: [1, 2, 3] })", None)?;
+        let persistent = Persistent::new(&runtime.jsi(), value);
+        assert!(!persistent.is_released());
+
+        drop(runtime);
+        assert!(persistent.is_released());
+        Ok(())
+    }
+
+    #[test]
+    fn test_persistent_runtime_dropped_on_other_thread() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result("({ data: [1, 2, 3] })", None)?;
+        let persistent = Persistent::new(&runtime.jsi(), value);
+
+        std::thread::spawn(move || drop(runtime))
+            .join()
+            .map_err(|_| Error::internal("Dropping the runtime panicked"))?;
+
+        // Not released from the other thread, but no longer usable either
+        assert!(persistent.is_released());
+        let mut other = Runtime::new(RuntimeConfig::default())?;
+        assert!(persistent.get(&mut other.jsi()).is_err());
+        drop(persistent);
+        Ok(())
+    }
+
+    #[test]
+    fn test_persistent_other_runtime_panics() -> Result<()> {
+        let mut runtime_a = Runtime::new(RuntimeConfig::default())?;
+        let mut runtime_b = Runtime::new(RuntimeConfig::default())?;
+
+        let object = runtime_a.create_object();
+        let persistent = Persistent::new(&runtime_a.jsi(), object);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            persistent.get(&mut runtime_b.jsi())
+        }));
+        assert!(result.is_err());
+        Ok(())
+    }
+}
@@ -24,4 +24,47 @@ mod tests {
         assert_eq!(value, "myProperty");
         Ok(())
     }
+
+    #[test]
+    fn test_jspropnameid_utf16() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let data: Vec<u16> = "ключ😀".encode_utf16().collect();
+        let propname = JSPropNameID::from_utf16(&mut jsi_runtime, &data);
+
+        assert_eq!(propname.to_utf16(&mut jsi_runtime), data);
+        assert_eq!(propname.value(&mut jsi_runtime), "ключ😀");
+        Ok(())
+    }
+
+    #[test]
+    fn test_jspropnameid_from_ascii() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let propname = JSPropNameID::from_ascii(&mut jsi_runtime, b"length")?;
+        assert_eq!(propname.value(&mut jsi_runtime), "length");
+
+        assert!(JSPropNameID::from_ascii(&mut jsi_runtime, &[0xff]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_jspropnameid_for_each_chunk() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let propname = JSPropNameID::new(&mut jsi_runtime, "name");
+        let mut length = 0;
+        propname.for_each_chunk(&mut jsi_runtime, |chunk| {
+            length += match chunk {
+                hermes_engine::jsi::StringChunk::Ascii(data) => data.len(),
+                hermes_engine::jsi::StringChunk::Utf16(data) => data.len(),
+            };
+        });
+
+        assert_eq!(length, 4);
+        Ok(())
+    }
 }
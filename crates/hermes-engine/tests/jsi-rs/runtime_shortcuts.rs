@@ -96,7 +96,7 @@ mod tests {
         let mut runtime = Runtime::new(RuntimeConfig::default())?;
         let mut jsi_runtime = runtime.jsi();
 
-        let bigint = jsi_runtime.create_bigint::<i64>(-9223372036854775808);
+        let bigint = jsi_runtime.create_bigint::<i64>(-9223372036854775808)?;
 
         assert!(!bigint.inner().is_null());
         Ok(())
@@ -107,9 +107,36 @@ mod tests {
         let mut runtime = Runtime::new(RuntimeConfig::default())?;
         let mut jsi_runtime = runtime.jsi();
 
-        let bigint = jsi_runtime.create_bigint::<u64>(18446744073709551615);
+        let bigint = jsi_runtime.create_bigint::<u64>(18446744073709551615)?;
 
         assert!(!bigint.inner().is_null());
         Ok(())
     }
+
+    #[test]
+    fn test_jsruntime_create_bigint_i128() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let amount = -(1i128 << 100) - 7;
+        let bigint = jsi_runtime.create_bigint(amount)?;
+        assert_eq!(bigint.as_i128(&mut jsi_runtime), Some(amount));
+
+        let bigint = jsi_runtime.create_bigint(u128::MAX)?;
+        assert_eq!(bigint.as_u128(&mut jsi_runtime), Some(u128::MAX));
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsruntime_global() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        runtime.eval("var kermioGlobal = 7;", None)?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let global = jsi_runtime.global();
+        let value = global.get(&mut jsi_runtime, "kermioGlobal");
+
+        assert_eq!(value.as_number(), Some(7.0));
+        Ok(())
+    }
 }
@@ -0,0 +1,81 @@
+mod tests {
+    use hermes_engine::jsi::{FromJs, JSValue};
+    use hermes_engine::{Error, Result, Runtime, RuntimeConfig};
+
+    #[test]
+    fn test_scope_returns_rust_value() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let text = jsi_runtime.scope(|rt| {
+            let string = rt.create_string("scoped");
+            string.value(rt)
+        });
+
+        assert_eq!(text, "scoped");
+        Ok(())
+    }
+
+    #[test]
+    fn test_scope_local_handles() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let number = jsi_runtime.scope(|scope| {
+            let value = scope.local(JSValue::number(42.0));
+            f64::from_js(scope, &value)
+        })?;
+
+        assert_eq!(number, 42.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_scope_in_loop() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result(
+            "Array.from({ length: 10000 }, (_, i) => ({ value: i }))",
+            None,
+        )?;
+        let mut jsi_runtime = runtime.jsi();
+        let array = value
+            .as_array(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not an array"))?;
+
+        let mut sum = 0.0;
+        for index in 0..array.len(&mut jsi_runtime) {
+            sum += jsi_runtime.scope(|rt| -> Result<f64> {
+                let item = array.get(rt, index);
+                let object = item
+                    .as_object(rt)
+                    .ok_or_else(|| Error::internal("Not an object"))?;
+                let value = object.get(rt, "value");
+                Ok(f64::from_js(rt, &value)?)
+            })?;
+        }
+
+        assert_eq!(sum, 49995000.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_nested_scopes() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let outer = jsi_runtime.create_object();
+        let count = jsi_runtime.scope(|rt| {
+            let inner = rt.scope(|rt| {
+                outer.set(rt, "a", &JSValue::number(1.0));
+                outer.keys(rt).map(|keys| keys.len())
+            })?;
+            outer.set(rt, "b", &JSValue::number(2.0));
+            Ok::<_, Error>(inner + outer.keys(rt)?.len())
+        })?;
+
+        assert_eq!(count, 3);
+        assert_eq!(outer.get(&mut jsi_runtime, "b").as_number(), Some(2.0));
+        Ok(())
+    }
+}
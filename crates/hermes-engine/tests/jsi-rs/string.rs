@@ -1,6 +1,6 @@
 mod tests {
-    use hermes_engine::jsi::JSString;
-    use hermes_engine::{Result, Runtime, RuntimeConfig};
+    use hermes_engine::jsi::{JSString, JSValue, StringChunk};
+    use hermes_engine::{Error, Result, Runtime, RuntimeConfig};
 
     #[test]
     fn test_jsstring_new() -> Result<()> {
@@ -24,4 +24,112 @@ mod tests {
         assert_eq!(value, "Hello, World!");
         Ok(())
     }
+
+    #[test]
+    fn test_jsstring_into_value() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let string = JSString::new(&mut jsi_runtime, "hello");
+        let value = JSValue::from(string);
+
+        assert!(value.is_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsstring_strict_equals() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let a = JSString::new(&mut jsi_runtime, "hello");
+        let b = JSString::new(&mut jsi_runtime, "hello");
+        let c = JSString::new(&mut jsi_runtime, "world");
+
+        assert!(a.strict_equals(&mut jsi_runtime, &b));
+        assert!(!a.strict_equals(&mut jsi_runtime, &c));
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsstring_utf16_roundtrip() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let data: Vec<u16> = "emoji 😀 ✓".encode_utf16().collect();
+        let string = JSString::from_utf16(&mut jsi_runtime, &data);
+
+        assert_eq!(string.to_utf16(&mut jsi_runtime), data);
+        assert_eq!(string.value(&mut jsi_runtime), "emoji 😀 ✓");
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsstring_lone_surrogate() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result("'a\\uD800b'", None)?;
+        let mut jsi_runtime = runtime.jsi();
+        let string = value
+            .as_string(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not a string"))?;
+
+        let data = string.to_utf16(&mut jsi_runtime);
+        assert_eq!(data, vec![0x61, 0xD800, 0x62]);
+
+        let copy = JSString::from_utf16(&mut jsi_runtime, &data);
+        assert!(copy.strict_equals(&mut jsi_runtime, &string));
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsstring_from_ascii() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let string = JSString::from_ascii(&mut jsi_runtime, b"plain ascii")?;
+        assert_eq!(string.value(&mut jsi_runtime), "plain ascii");
+
+        assert!(JSString::from_ascii(&mut jsi_runtime, "héllo".as_bytes()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsstring_for_each_chunk() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let ascii = JSString::new(&mut jsi_runtime, "hello");
+        let mut bytes = Vec::new();
+        ascii.for_each_chunk(&mut jsi_runtime, |chunk| match chunk {
+            StringChunk::Ascii(data) => bytes.extend_from_slice(data),
+            StringChunk::Utf16(data) => bytes.extend(data.iter().map(|&unit| unit as u8)),
+        });
+        assert_eq!(bytes, b"hello");
+
+        let wide = JSString::new(&mut jsi_runtime, "😀");
+        let mut units = 0;
+        wide.for_each_chunk(&mut jsi_runtime, |chunk| {
+            if let StringChunk::Utf16(data) = chunk {
+                units += data.len();
+            }
+        });
+        assert_eq!(units, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsstring_for_each_chunk_panic() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let string = JSString::new(&mut jsi_runtime, "hello");
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            string.for_each_chunk(&mut jsi_runtime, |_| panic!("chunk callback"));
+        }));
+        assert!(result.is_err());
+
+        assert_eq!(string.value(&mut jsi_runtime), "hello");
+        Ok(())
+    }
 }
@@ -1,5 +1,5 @@
 mod tests {
-    use hermes_engine::jsi::JSValue;
+    use hermes_engine::jsi::{JSValue, ValueKind};
     use hermes_engine::{Error, Result, Runtime, RuntimeConfig};
 
     #[test]
@@ -54,8 +54,8 @@ mod tests {
         let value_true = JSValue::bool(true);
         let value_false = JSValue::bool(false);
 
-        assert_eq!(value_true.as_bool(), true);
-        assert_eq!(value_false.as_bool(), false);
+        assert_eq!(value_true.as_bool(), Some(true));
+        assert_eq!(value_false.as_bool(), Some(false));
         Ok(())
     }
 
@@ -65,7 +65,7 @@ mod tests {
 
         let value = JSValue::number(42.5);
 
-        assert_eq!(value.as_number(), 42.5);
+        assert_eq!(value.as_number(), Some(42.5));
         Ok(())
     }
 
@@ -119,4 +119,205 @@ mod tests {
         assert!(value.as_bigint(&mut runtime.jsi()).is_none());
         Ok(())
     }
+
+    #[test]
+    fn test_jsvalue_as_bool_none() -> Result<()> {
+        let _runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = JSValue::undefined();
+
+        assert_eq!(value.as_bool(), None);
+        assert!(value.try_as_bool().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsvalue_as_number_none() -> Result<()> {
+        let _runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = JSValue::undefined();
+
+        assert_eq!(value.as_number(), None);
+        let err = value
+            .try_as_number()
+            .err()
+            .ok_or_else(|| Error::internal("Expected an error"))?;
+        assert_eq!(err.message(), "expected number, got undefined");
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsvalue_try_as_number() -> Result<()> {
+        let _runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = JSValue::number(1.5);
+
+        assert_eq!(value.try_as_number()?, 1.5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsvalue_kind() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let string = runtime.eval_with_result("'text'", None)?;
+        let bigint = runtime.eval_with_result("1n", None)?;
+        let symbol = runtime.eval_with_result("Symbol('s')", None)?;
+        let object = runtime.eval_with_result("({})", None)?;
+
+        assert_eq!(JSValue::undefined().kind(), ValueKind::Undefined);
+        assert_eq!(JSValue::null().kind(), ValueKind::Null);
+        assert_eq!(JSValue::bool(true).kind(), ValueKind::Bool);
+        assert_eq!(JSValue::number(1.0).kind(), ValueKind::Number);
+        assert_eq!(string.kind(), ValueKind::String);
+        assert_eq!(bigint.kind(), ValueKind::BigInt);
+        assert_eq!(symbol.kind(), ValueKind::Symbol);
+        assert_eq!(object.kind(), ValueKind::Object);
+        assert_eq!(ValueKind::Bool.name(), "boolean");
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsvalue_as_object() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result("({ answer: 42 })", None)?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let object = value
+            .as_object(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not an object"))?;
+        let answer = object.get(&mut jsi_runtime, "answer");
+        assert_eq!(answer.as_number(), Some(42.0));
+
+        assert!(JSValue::number(1.0).as_object(&mut jsi_runtime).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsvalue_as_array() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let array = runtime.eval_with_result("[1, 2, 3]", None)?;
+        let object = runtime.eval_with_result("({ length: 3 })", None)?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let array = array
+            .as_array(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not an array"))?;
+        assert_eq!(array.len(&mut jsi_runtime), 3);
+
+        assert!(object.as_array(&mut jsi_runtime).is_none());
+        assert!(JSValue::null().as_array(&mut jsi_runtime).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsvalue_clone_in() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let object = runtime.eval_with_result("({ hits: 0 })", None)?;
+        let bump = runtime.eval_with_result("(function (o) { return ++o.hits; })", None)?;
+        let mut jsi_runtime = runtime.jsi();
+        let bump = bump
+            .as_function(&mut jsi_runtime)
+            .ok_or_else(|| Error::internal("Not a function"))?;
+
+        let copy = object.clone_in(&mut jsi_runtime);
+        bump.call(&mut jsi_runtime, &[object])?;
+        let result = bump.call(&mut jsi_runtime, &[copy])?;
+
+        assert_eq!(result.as_number(), Some(2.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsvalue_strict_equals() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let object = runtime.eval_with_result("({})", None)?;
+        let other = runtime.eval_with_result("({})", None)?;
+        let a = runtime.eval_with_result("'abc'", None)?;
+        let b = runtime.eval_with_result("'ab' + 'c'", None)?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let same = object.clone_in(&mut jsi_runtime);
+        assert!(object.strict_equals(&mut jsi_runtime, &same));
+        assert!(!object.strict_equals(&mut jsi_runtime, &other));
+        assert!(a.strict_equals(&mut jsi_runtime, &b));
+
+        let one = JSValue::number(1.0);
+        assert!(one.strict_equals(&mut jsi_runtime, &JSValue::number(1.0)));
+        assert!(!one.strict_equals(&mut jsi_runtime, &JSValue::bool(true)));
+
+        let nan = JSValue::number(f64::NAN);
+        assert!(!nan.strict_equals(&mut jsi_runtime, &JSValue::number(f64::NAN)));
+        assert!(!JSValue::null().strict_equals(&mut jsi_runtime, &JSValue::undefined()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsvalue_to_js_string() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let object = runtime.eval_with_result("({})", None)?;
+        let array = runtime.eval_with_result("[1, [2, 3]]", None)?;
+        let symbol = runtime.eval_with_result("Symbol('tag')", None)?;
+        let mut jsi_runtime = runtime.jsi();
+
+        let mut to_string = |value: &JSValue| -> Result<String> {
+            let string = value.to_js_string(&mut jsi_runtime)?;
+            Ok(string.value(&mut jsi_runtime))
+        };
+
+        assert_eq!(to_string(&JSValue::number(1.5))?, "1.5");
+        assert_eq!(to_string(&JSValue::number(3.0))?, "3");
+        assert_eq!(to_string(&JSValue::null())?, "null");
+        assert_eq!(to_string(&JSValue::undefined())?, "undefined");
+        assert_eq!(to_string(&JSValue::bool(false))?, "false");
+        assert_eq!(to_string(&object)?, "[object Object]");
+        assert_eq!(to_string(&array)?, "1,2,3");
+        assert_eq!(to_string(&symbol)?, "Symbol(tag)");
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsvalue_to_js_string_throws() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value =
+            runtime.eval_with_result("({ toString() { throw new Error('nope'); } })", None)?;
+
+        assert!(value.to_js_string(&mut runtime.jsi()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsvalue_to_debug_string() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let value = runtime.eval_with_result(
+            "({ name: 'kermio', tags: [1, 'a', null], size: 10n, nested: { deep: { x: 1 } }, f() {} })",
+            None,
+        )?;
+
+        assert_eq!(
+            value.to_debug_string(&mut runtime.jsi()),
+            "{ name: \"kermio\", tags: [1, \"a\", null], size: 10n, nested: { deep: [Object] }, f: [Function] }"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsvalue_debug() -> Result<()> {
+        let mut runtime = Runtime::new(RuntimeConfig::default())?;
+
+        let string = runtime.eval_with_result("'text'", None)?;
+
+        assert_eq!(format!("{:?}", JSValue::number(1.5)), "Number(1.5)");
+        assert_eq!(format!("{:?}", JSValue::bool(true)), "Bool(true)");
+        assert_eq!(format!("{:?}", JSValue::undefined()), "Undefined");
+        assert_eq!(format!("{:?}", string), "String");
+        Ok(())
+    }
 }
@@ -0,0 +1,183 @@
+use syn::{Attribute, LitStr, Path, Result};
+
+/// Options from `#[js(...)]` on a struct or enum
+pub struct ContainerAttrs {
+    pub krate: Path,
+    pub rename_all: Option<RenameRule>,
+}
+
+/// Options from `#[js(...)]` on a field
+#[derive(Default)]
+pub struct FieldAttrs {
+    pub rename: Option<String>,
+    pub skip: bool,
+}
+
+/// Options from `#[js(...)]` on an enum variant
+#[derive(Default)]
+pub struct VariantAttrs {
+    pub rename: Option<String>,
+}
+
+#[derive(Clone, Copy)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Camel,
+    Pascal,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+}
+
+pub fn default_crate() -> Path {
+    syn::parse_quote!(::jsi_rs)
+}
+
+pub fn parse_crate_path(lit: &LitStr) -> Result<Path> {
+    lit.parse()
+}
+
+impl ContainerAttrs {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = ContainerAttrs {
+            krate: default_crate(),
+            rename_all: None,
+        };
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("js")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("crate") {
+                    result.krate = parse_crate_path(&meta.value()?.parse()?)?;
+                    Ok(())
+                } else if meta.path.is_ident("rename_all") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    result.rename_all = Some(RenameRule::parse(&lit)?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported js container attribute"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+impl FieldAttrs {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = FieldAttrs::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("js")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    result.rename = Some(lit.value());
+                    Ok(())
+                } else if meta.path.is_ident("skip") {
+                    result.skip = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported js field attribute"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+impl VariantAttrs {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = VariantAttrs::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("js")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    result.rename = Some(lit.value());
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported js variant attribute"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+impl RenameRule {
+    fn parse(lit: &LitStr) -> Result<Self> {
+        match lit.value().as_str() {
+            "lowercase" => Ok(RenameRule::Lower),
+            "UPPERCASE" => Ok(RenameRule::Upper),
+            "camelCase" => Ok(RenameRule::Camel),
+            "PascalCase" => Ok(RenameRule::Pascal),
+            "snake_case" => Ok(RenameRule::Snake),
+            "SCREAMING_SNAKE_CASE" => Ok(RenameRule::ScreamingSnake),
+            "kebab-case" => Ok(RenameRule::Kebab),
+            _ => Err(syn::Error::new_spanned(lit, "unknown rename_all rule")),
+        }
+    }
+
+    /// Apply the rule to a Rust identifier, either snake_case (fields) or PascalCase (variants)
+    pub fn apply(self, name: &str) -> String {
+        let words = split_words(name);
+
+        match self {
+            RenameRule::Lower => words.concat(),
+            RenameRule::Upper => words.concat().to_uppercase(),
+            RenameRule::Camel => {
+                let mut result = String::new();
+                for (i, word) in words.iter().enumerate() {
+                    if i == 0 {
+                        result.push_str(word);
+                    } else {
+                        result.push_str(&capitalize(word));
+                    }
+                }
+                result
+            }
+            RenameRule::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+            RenameRule::Snake => words.join("_"),
+            RenameRule::ScreamingSnake => words.join("_").to_uppercase(),
+            RenameRule::Kebab => words.join("-"),
+        }
+    }
+}
+
+/// Split an identifier into lowercase words on underscores and case changes
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+
+    for ch in name.chars() {
+        if ch == '_' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+        } else if ch.is_uppercase() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            current.extend(ch.to_lowercase());
+        } else {
+            current.push(ch);
+        }
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,334 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{FnArg, ImplItem, ImplItemFn, ItemImpl, LitStr, Meta, Path, Result, ReturnType, Type};
+
+use crate::attr::{default_crate, parse_crate_path, RenameRule};
+
+/// Options from `#[js_method(...)]`
+#[derive(Default)]
+struct MethodAttrs {
+    name: Option<String>,
+    constructor: bool,
+}
+
+struct Method {
+    js_name: String,
+    function: ImplItemFn,
+    constructor: bool,
+}
+
+pub fn expand(args: TokenStream, mut item: ItemImpl) -> Result<TokenStream> {
+    let mut krate = default_crate();
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("crate") {
+            krate = parse_crate_path(&meta.value()?.parse()?)?;
+            Ok(())
+        } else {
+            Err(meta.error("unsupported js_class attribute"))
+        }
+    });
+    syn::parse::Parser::parse2(parser, args)?;
+
+    if !item.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &item.generics,
+            "js_class does not support generic impl blocks",
+        ));
+    }
+
+    let mut methods = Vec::new();
+    for impl_item in &mut item.items {
+        let ImplItem::Fn(function) = impl_item else {
+            continue;
+        };
+        let Some(position) = function.attrs.iter().position(is_js_method) else {
+            continue;
+        };
+
+        let attr = function.attrs.remove(position);
+        let options = MethodAttrs::parse(&attr.meta)?;
+        let js_name = options
+            .name
+            .unwrap_or_else(|| RenameRule::Camel.apply(&function.sig.ident.to_string()));
+
+        methods.push(Method {
+            js_name,
+            function: function.clone(),
+            constructor: options.constructor,
+        });
+    }
+
+    let self_ty = &item.self_ty;
+    let class_name = match &**self_ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_default(),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                self_ty,
+                "js_class must be applied to an impl block of a named type",
+            ))
+        }
+    };
+
+    let mut method_blocks = Vec::new();
+    let mut constructor = None;
+    for method in &methods {
+        if method.constructor {
+            if constructor.is_some() {
+                return Err(syn::Error::new_spanned(
+                    &method.function.sig,
+                    "js_class supports a single constructor",
+                ));
+            }
+            constructor = Some(expand_constructor(&krate, self_ty, &class_name, method)?);
+        } else {
+            method_blocks.push(expand_method(&krate, method)?);
+        }
+    }
+
+    Ok(quote! {
+        #item
+
+        impl #krate::IntoJs for #self_ty {
+            #[allow(unused_variables)]
+            fn into_js(
+                self,
+                runtime: &mut #krate::JSRuntime<'_>,
+            ) -> #krate::Result<#krate::JSValue> {
+                let this = ::std::rc::Rc::new(::std::cell::RefCell::new(self));
+                let object = #krate::JSObject::new(runtime);
+                #(#method_blocks)*
+                Ok(#krate::JSValue::from(object))
+            }
+        }
+
+        #constructor
+    })
+}
+
+fn is_js_method(attr: &syn::Attribute) -> bool {
+    attr.path()
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "js_method")
+}
+
+impl MethodAttrs {
+    fn parse(meta: &Meta) -> Result<Self> {
+        let mut result = MethodAttrs::default();
+
+        if let Meta::List(list) = meta {
+            list.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    result.name = Some(lit.value());
+                    Ok(())
+                } else if meta.path.is_ident("constructor") {
+                    result.constructor = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported js_method attribute"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+/// Converts the JS arguments and returns the Rust call arguments and their count in JS
+fn expand_arguments(
+    krate: &Path,
+    method: &Method,
+) -> Result<(Vec<TokenStream>, Vec<TokenStream>, u32)> {
+    let js_name = &method.js_name;
+    let mut conversions = Vec::new();
+    let mut call_args = Vec::new();
+    let mut index = 0usize;
+
+    for input in &method.function.sig.inputs {
+        let FnArg::Typed(arg) = input else {
+            continue;
+        };
+
+        if is_runtime_arg(&arg.ty) {
+            call_args.push(quote!(runtime));
+            continue;
+        }
+
+        let ty = &arg.ty;
+        let binding = format_ident!("__arg{}", index);
+        conversions.push(quote! {
+            let #binding: #ty = #krate::__private::argument(runtime, args, #index, #js_name)?;
+        });
+        call_args.push(quote!(#binding));
+        index += 1;
+    }
+
+    Ok((conversions, call_args, index as u32))
+}
+
+fn expand_method(krate: &Path, method: &Method) -> Result<TokenStream> {
+    let js_name = &method.js_name;
+    let ident = &method.function.sig.ident;
+
+    let borrow = match method.function.sig.receiver() {
+        Some(receiver) if receiver.reference.is_none() => {
+            return Err(syn::Error::new_spanned(
+                receiver,
+                "js_method cannot take self by value",
+            ))
+        }
+        Some(receiver) if receiver.mutability.is_some() => {
+            quote!(#krate::__private::borrow_mut(&this, #js_name)?)
+        }
+        Some(_) => quote!(#krate::__private::borrow(&this, #js_name)?),
+        None => {
+            return Err(syn::Error::new_spanned(
+                &method.function.sig,
+                "js_method requires a self receiver unless marked as constructor",
+            ))
+        }
+    };
+
+    let (conversions, call_args, param_count) = expand_arguments(krate, method)?;
+    let call = quote!(#borrow.#ident(#(#call_args),*));
+    let result = expand_return(krate, &method.function.sig.output, call);
+
+    Ok(quote! {
+        {
+            let this = ::std::rc::Rc::clone(&this);
+            let function = #krate::JSFunction::from_host_fn(
+                runtime,
+                #js_name,
+                #param_count,
+                move |runtime, _this, args| {
+                    #(#conversions)*
+                    #result
+                },
+            );
+            object.set(
+                runtime,
+                #js_name,
+                &#krate::JSValue::from(function),
+            );
+        }
+    })
+}
+
+fn expand_constructor(
+    krate: &Path,
+    self_ty: &Type,
+    class_name: &str,
+    method: &Method,
+) -> Result<TokenStream> {
+    if let Some(receiver) = method.function.sig.receiver() {
+        return Err(syn::Error::new_spanned(
+            receiver,
+            "js_method constructor cannot take a self receiver",
+        ));
+    }
+
+    let ident = &method.function.sig.ident;
+    let (conversions, call_args, param_count) = expand_arguments(krate, method)?;
+    let call = quote!(Self::#ident(#(#call_args),*));
+    let result = expand_return(krate, &method.function.sig.output, call);
+    let doc = format!(
+        "Create a JavaScript function constructing `{}` objects, callable with or without `new`",
+        class_name
+    );
+
+    Ok(quote! {
+        impl #self_ty {
+            #[doc = #doc]
+            #[allow(unused_variables)]
+            pub fn js_constructor(runtime: &mut #krate::JSRuntime<'_>) -> #krate::JSFunction {
+                #krate::JSFunction::from_host_fn(
+                    runtime,
+                    #class_name,
+                    #param_count,
+                    |runtime, _this, args| {
+                        #(#conversions)*
+                        #result
+                    },
+                )
+            }
+        }
+    })
+}
+
+/// Converts the result of `call` into `Result<JSValue>`
+fn expand_return(krate: &Path, output: &ReturnType, call: TokenStream) -> TokenStream {
+    let ty = match output {
+        ReturnType::Default => {
+            return quote! {
+                #call;
+                Ok(#krate::JSValue::undefined())
+            }
+        }
+        ReturnType::Type(_, ty) => ty,
+    };
+
+    match result_ok_type(ty) {
+        Some(ok) if is_unit_type(ok) => quote! {
+            #call.map_err(#krate::__private::error)?;
+            Ok(#krate::JSValue::undefined())
+        },
+        Some(_) => quote! {
+            let result = #call.map_err(#krate::__private::error)?;
+            #krate::IntoJs::into_js(result, runtime)
+        },
+        None if is_unit_type(ty) => quote! {
+            #call;
+            Ok(#krate::JSValue::undefined())
+        },
+        None => quote! {
+            let result = #call;
+            #krate::IntoJs::into_js(result, runtime)
+        },
+    }
+}
+
+/// Matches `&mut JSRuntime` arguments, which receive the calling runtime
+fn is_runtime_arg(ty: &Type) -> bool {
+    let Type::Reference(reference) = ty else {
+        return false;
+    };
+    let Type::Path(path) = &*reference.elem else {
+        return false;
+    };
+
+    reference.mutability.is_some()
+        && path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "JSRuntime")
+}
+
+/// Returns the `T` of a `Result<T>` or `Result<T, E>` return type
+fn result_ok_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        syn::GenericArgument::Type(ok) => Some(ok),
+        _ => None,
+    }
+}
+
+fn is_unit_type(ty: &Type) -> bool {
+    matches!(ty, Type::Tuple(tuple) if tuple.elems.is_empty())
+}
@@ -0,0 +1,137 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Path, Result};
+
+use crate::attr::{ContainerAttrs, FieldAttrs, RenameRule, VariantAttrs};
+use crate::{add_trait_bounds, js_name};
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let attrs = ContainerAttrs::parse(&input.attrs)?;
+    let krate = &attrs.krate;
+    let name = &input.ident;
+    let type_name = name.to_string();
+
+    let generics = add_trait_bounds(input.generics.clone(), &quote!(#krate::FromJs));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => fields_from_js(
+            krate,
+            &data.fields,
+            attrs.rename_all,
+            quote!(Self),
+            &type_name,
+        )?,
+        Data::Enum(data) => {
+            let mut unit_arms = Vec::new();
+            let mut tagged_arms = Vec::new();
+
+            for variant in &data.variants {
+                let variant_attrs = VariantAttrs::parse(&variant.attrs)?;
+                let ident = &variant.ident;
+                let tag = js_name(ident, variant_attrs.rename, attrs.rename_all);
+                let variant_name = format!("{}::{}", type_name, ident);
+
+                if let Fields::Unit = variant.fields {
+                    unit_arms.push(quote!(#tag => Ok(Self::#ident),));
+                }
+
+                let value = fields_from_js(
+                    krate,
+                    &variant.fields,
+                    None,
+                    quote!(Self::#ident),
+                    &variant_name,
+                )?;
+                tagged_arms.push(quote!(#tag => { #value }));
+            }
+
+            // Unit variants are plain strings, the others `{ "Variant": payload }`
+            quote! {
+                if let Some(tag) = #krate::__private::as_tag(runtime, value) {
+                    return match tag.as_str() {
+                        #(#unit_arms)*
+                        _ => Err(#krate::__private::unknown_variant(#type_name, &tag)),
+                    };
+                }
+
+                let (tag, payload) = #krate::__private::expect_tagged(runtime, value, #type_name)?;
+                let value = &payload;
+                match tag.as_str() {
+                    #(#tagged_arms)*
+                    _ => Err(#krate::__private::unknown_variant(#type_name, &tag)),
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                &input,
+                "FromJs cannot be derived for unions",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #krate::FromJs for #name #ty_generics #where_clause {
+            fn from_js(
+                runtime: &mut #krate::JSRuntime<'_>,
+                value: &#krate::JSValue,
+            ) -> #krate::Result<Self> {
+                #body
+            }
+        }
+    })
+}
+
+/// Returns an expression converting `value` into the fields of `path`
+fn fields_from_js(
+    krate: &Path,
+    fields: &Fields,
+    rename_all: Option<RenameRule>,
+    path: TokenStream,
+    type_name: &str,
+) -> Result<TokenStream> {
+    match fields {
+        Fields::Named(named) => {
+            let mut inits = Vec::new();
+
+            for field in &named.named {
+                let field_attrs = FieldAttrs::parse(&field.attrs)?;
+                let ident = field.ident.as_ref().expect("named field");
+
+                if field_attrs.skip {
+                    inits.push(quote!(#ident: ::std::default::Default::default()));
+                    continue;
+                }
+
+                let key = js_name(ident, field_attrs.rename, rename_all);
+                inits.push(quote! {
+                    #ident: #krate::__private::field(runtime, &object, #key, #type_name)?
+                });
+            }
+
+            Ok(quote! {
+                let object = #krate::__private::expect_object(runtime, value, #type_name)?;
+                Ok(#path { #(#inits),* })
+            })
+        }
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
+            Ok(quote!(Ok(#path(#krate::FromJs::from_js(runtime, value)?))))
+        }
+        Fields::Unnamed(unnamed) => {
+            let len = unnamed.unnamed.len();
+            let elements = (0..len).map(
+                |index| quote!(#krate::__private::element(runtime, &array, #index, #type_name)?),
+            );
+
+            Ok(quote! {
+                let array = #krate::__private::expect_array(runtime, value, #type_name, #len)?;
+                Ok(#path(#(#elements),*))
+            })
+        }
+        Fields::Unit => Ok(quote! {
+            #krate::__private::expect_unit(value, #type_name)?;
+            Ok(#path)
+        }),
+    }
+}
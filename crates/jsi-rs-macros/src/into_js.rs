@@ -0,0 +1,145 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Path, Result};
+
+use crate::attr::{ContainerAttrs, FieldAttrs, RenameRule, VariantAttrs};
+use crate::{add_trait_bounds, js_name};
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let attrs = ContainerAttrs::parse(&input.attrs)?;
+    let krate = &attrs.krate;
+    let name = &input.ident;
+
+    let generics = add_trait_bounds(input.generics.clone(), &quote!(#krate::IntoJs));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, value) =
+                fields_into_js(krate, &data.fields, attrs.rename_all, quote!(Self))?;
+            quote! {
+                let #pattern = self;
+                #value
+            }
+        }
+        Data::Enum(data) => {
+            let mut arms = Vec::new();
+            for variant in &data.variants {
+                let variant_attrs = VariantAttrs::parse(&variant.attrs)?;
+                let ident = &variant.ident;
+                let tag = js_name(ident, variant_attrs.rename, attrs.rename_all);
+
+                let arm = if let Fields::Unit = variant.fields {
+                    quote! {
+                        Self::#ident => #krate::IntoJs::into_js(#tag, runtime),
+                    }
+                } else {
+                    // Variant payloads are only renamed through their own field attributes
+                    let (pattern, value) =
+                        fields_into_js(krate, &variant.fields, None, quote!(Self::#ident))?;
+                    quote! {
+                        #pattern => {
+                            let payload: #krate::Result<#krate::JSValue> = #value;
+                            #krate::__private::tagged(runtime, #tag, payload?)
+                        }
+                    }
+                };
+                arms.push(arm);
+            }
+
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                &input,
+                "IntoJs cannot be derived for unions",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #krate::IntoJs for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn into_js(
+                self,
+                runtime: &mut #krate::JSRuntime<'_>,
+            ) -> #krate::Result<#krate::JSValue> {
+                #body
+            }
+        }
+    })
+}
+
+/// Returns a pattern binding the fields and an expression converting them into a `JSValue`
+fn fields_into_js(
+    krate: &Path,
+    fields: &Fields,
+    rename_all: Option<RenameRule>,
+    path: TokenStream,
+) -> Result<(TokenStream, TokenStream)> {
+    match fields {
+        Fields::Named(named) => {
+            let mut bindings = Vec::new();
+            let mut sets = Vec::new();
+
+            for (index, field) in named.named.iter().enumerate() {
+                let field_attrs = FieldAttrs::parse(&field.attrs)?;
+                if field_attrs.skip {
+                    continue;
+                }
+
+                let ident = field.ident.as_ref().expect("named field");
+                let binding = format_ident!("__field{}", index);
+                let key = js_name(ident, field_attrs.rename, rename_all);
+
+                bindings.push(quote!(#ident: #binding));
+                sets.push(quote! {
+                    let value = #krate::IntoJs::into_js(#binding, runtime)?;
+                    object.set(runtime, #key, &value);
+                });
+            }
+
+            let pattern = quote!(#path { #(#bindings,)* .. });
+            let value = quote! {{
+                let object = #krate::JSObject::new(runtime);
+                #(#sets)*
+                Ok(#krate::JSValue::from(object))
+            }};
+            Ok((pattern, value))
+        }
+        Fields::Unnamed(unnamed) => {
+            for field in &unnamed.unnamed {
+                if FieldAttrs::parse(&field.attrs)?.skip {
+                    return Err(syn::Error::new_spanned(
+                        field,
+                        "skip is only supported on named fields",
+                    ));
+                }
+            }
+
+            let bindings: Vec<_> = (0..unnamed.unnamed.len())
+                .map(|index| format_ident!("__field{}", index))
+                .collect();
+            let pattern = quote!(#path(#(#bindings),*));
+
+            // Newtypes are transparent, longer tuples become arrays
+            let value = if bindings.len() == 1 {
+                let binding = &bindings[0];
+                quote!(#krate::IntoJs::into_js(#binding, runtime))
+            } else {
+                quote! {{
+                    let values = ::std::vec![
+                        #(#krate::IntoJs::into_js(#bindings, runtime)?),*
+                    ];
+                    #krate::__private::array_from_values(runtime, values)
+                }}
+            };
+            Ok((pattern, value))
+        }
+        Fields::Unit => Ok((path, quote!(Ok(#krate::JSValue::null())))),
+    }
+}
@@ -0,0 +1,98 @@
+//! Derive and attribute macros for exposing Rust types to JavaScript through jsi-rs.
+//!
+//! Use them through the `derive` feature of `jsi-rs`, which re-exports everything here.
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, GenericParam, Generics, Ident, ItemImpl};
+
+mod attr;
+mod class;
+mod from_js;
+mod into_js;
+
+use attr::RenameRule;
+
+/// Derive `IntoJs`
+///
+/// Structs with named fields become plain objects, newtypes are transparent,
+/// tuple structs become arrays and unit structs become `null`. Enum unit variants
+/// become their name as a string, other variants `{ "Variant": payload }`.
+///
+/// Supported attributes:
+/// - `#[js(crate = "hermes_engine::jsi")]` - path to jsi-rs (defaults to `::jsi_rs`)
+/// - `#[js(rename_all = "camelCase")]` - rename all fields or variants
+/// - `#[js(rename = "name")]` - rename a single field or variant
+/// - `#[js(skip)]` - skip a field (filled with `Default::default()` by `FromJs`)
+#[proc_macro_derive(IntoJs, attributes(js))]
+pub fn derive_into_js(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    into_js::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `FromJs`, the inverse of `IntoJs` using the same representation and attributes
+#[proc_macro_derive(FromJs, attributes(js))]
+pub fn derive_from_js(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_js::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Expose the `#[js_method]` functions of an impl block to JavaScript
+///
+/// Implements `IntoJs` for the type, converting a value into an object whose
+/// methods are host functions sharing the value. Arguments are converted with
+/// `FromJs`, return values with `IntoJs`, and `Result` errors are thrown as
+/// JavaScript errors. A `&mut JSRuntime` argument receives the calling runtime.
+///
+/// Method names are converted to camelCase unless set with `#[js_method(name = "...")]`.
+/// An associated function marked `#[js_method(constructor)]` generates a
+/// `js_constructor(runtime)` function returning the class constructor.
+///
+/// Use `#[js_class(crate = "hermes_engine::jsi")]` to change the path to jsi-rs.
+#[proc_macro_attribute]
+pub fn js_class(args: TokenStream, input: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(input as ItemImpl);
+    class::expand(args.into(), item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Mark a method of a `#[js_class]` impl block as exposed to JavaScript
+#[proc_macro_attribute]
+pub fn js_method(_args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut output: proc_macro2::TokenStream = syn::Error::new(
+        proc_macro2::Span::call_site(),
+        "js_method must be used inside #[js_class]",
+    )
+    .into_compile_error();
+    output.extend(proc_macro2::TokenStream::from(input));
+    output.into()
+}
+
+/// Add `bound` to every type parameter
+fn add_trait_bounds(mut generics: Generics, bound: &proc_macro2::TokenStream) -> Generics {
+    for param in &mut generics.params {
+        if let GenericParam::Type(param) = param {
+            param.bounds.push(syn::parse_quote!(#bound));
+        }
+    }
+    generics
+}
+
+/// JavaScript name of a field or variant
+fn js_name(ident: &Ident, rename: Option<String>, rule: Option<RenameRule>) -> String {
+    use syn::ext::IdentExt;
+
+    if let Some(rename) = rename {
+        return rename;
+    }
+
+    let name = ident.unraw().to_string();
+    match rule {
+        Some(rule) => rule.apply(&name),
+        None => name,
+    }
+}
@@ -1,6 +1,7 @@
 fn main() {
-    // Compile the cxx bridge (bridge.h contains inline functions)
+    // Compile the cxx bridge (bridge.h contains inline functions, bridge.cc the host function glue)
     cxx_build::bridge("src/sys.rs")
+        .file("src/bridge.cc")
         .flag_if_supported("-std=c++17")
         .flag_if_supported("-stdlib=libc++")
         // Add JSI include paths from local include directory
@@ -9,5 +10,6 @@ fn main() {
 
     println!("cargo:rerun-if-changed=src/sys.rs");
     println!("cargo:rerun-if-changed=src/bridge.h");
+    println!("cargo:rerun-if-changed=src/bridge.cc");
     println!("cargo:rerun-if-changed=include");
 }
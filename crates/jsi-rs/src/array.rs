@@ -1,56 +1,116 @@
 use crate::JSRuntime;
-use crate::{Error, Result};
+use crate::Result;
 
 /// Wrapper around facebook::jsi::Array providing a safe Rust API
 pub struct JSArray {
-    pub(crate) inner: cxx::UniquePtr<crate::sys::ffi::JSIArray>,
+    pub(crate) inner: crate::handle::Handle<crate::sys::ffi::JSIArray>,
 }
 
 impl JSArray {
     /// Create a new JavaScript array with the specified length
     pub fn new(runtime: &mut JSRuntime<'_>, length: usize) -> Self {
         let ptr = crate::sys::ffi::create_array(runtime.pin_mut(), length);
-        Self { inner: ptr }
+        Self {
+            inner: runtime.handle(ptr),
+        }
     }
 
     pub fn get(&self, runtime: &mut JSRuntime<'_>, index: usize) -> crate::JSValue {
+        self.inner.check(runtime);
         if index >= self.len(runtime) {
             return crate::JSValue::undefined();
         }
 
         let value =
             crate::sys::ffi::array_get_value_at_index(runtime.pin_mut(), &self.inner, index);
-        crate::JSValue { inner: value }
+        crate::JSValue {
+            inner: runtime.handle(value),
+        }
     }
 
+    /// Set the element at `index`, growing the array when `index` is past the end
     pub fn set(
         &self,
         runtime: &mut JSRuntime<'_>,
         index: usize,
         value: &crate::JSValue,
     ) -> Result<()> {
-        if index >= self.len(runtime) {
-            return Err(Error::new(format!(
-                "Index {} out of bounds for array of length {}",
-                index,
-                self.len(runtime)
-            )));
-        }
-
+        self.inner.check(runtime);
+        value.inner.check(runtime);
         crate::sys::ffi::array_set_value_at_index(
             runtime.pin_mut(),
             &self.inner,
             index,
             value.inner(),
-        );
+        )?;
         Ok(())
     }
 
+    /// Create a JavaScript array holding copies of `values`
+    pub fn from_values(runtime: &mut JSRuntime<'_>, values: &[crate::JSValue]) -> Self {
+        let array = Self::new(runtime, values.len());
+        for (index, value) in values.iter().enumerate() {
+            value.inner.check(runtime);
+            // Within the bounds of the new array, stores can't fail
+            let _ = crate::sys::ffi::array_set_value_at_index(
+                runtime.pin_mut(),
+                &array.inner,
+                index,
+                value.inner(),
+            );
+        }
+        array
+    }
+
+    /// Append a value to the end of the array, growing it by one
+    pub fn push(&self, runtime: &mut JSRuntime<'_>, value: &crate::JSValue) -> Result<()> {
+        self.inner.check(runtime);
+        value.inner.check(runtime);
+        crate::sys::ffi::array_push(runtime.pin_mut(), &self.inner, value.inner())?;
+        Ok(())
+    }
+
+    /// Append every value produced by `values` to the end of the array
+    pub fn extend_from_iter<I>(&self, runtime: &mut JSRuntime<'_>, values: I) -> Result<()>
+    where
+        I: IntoIterator<Item = crate::JSValue>,
+    {
+        self.inner.check(runtime);
+        for value in values {
+            self.push(runtime, &value)?;
+        }
+        Ok(())
+    }
+
+    /// Iterate over the elements of the array
+    ///
+    /// The iterator borrows the runtime, collect it first when the loop body needs the runtime.
+    pub fn iter<'a, 'rt>(&'a self, runtime: &'a mut JSRuntime<'rt>) -> ArrayIter<'a, 'rt> {
+        let len = self.len(runtime);
+        ArrayIter {
+            array: self,
+            runtime,
+            index: 0,
+            len,
+        }
+    }
+
+    /// Create another handle to the same array
+    pub fn clone_in(&self, runtime: &mut JSRuntime<'_>) -> JSArray {
+        self.inner.check(runtime);
+        let inner = crate::sys::ffi::array_copy(runtime.pin_mut(), &self.inner);
+        JSArray {
+            inner: runtime.handle(inner),
+        }
+    }
+
     pub fn len(&self, runtime: &mut JSRuntime<'_>) -> usize {
+        self.inner.check(runtime);
         crate::sys::ffi::array_size(runtime.pin_mut(), &self.inner)
     }
 
     pub fn is_empty(&self, runtime: &mut JSRuntime<'_>) -> bool {
+        self.inner.check(runtime);
         self.len(runtime) == 0
     }
 
@@ -60,3 +120,40 @@ impl JSArray {
         &self.inner
     }
 }
+
+/// Iterator over the elements of a [`JSArray`], created by [`JSArray::iter`]
+pub struct ArrayIter<'a, 'rt> {
+    array: &'a JSArray,
+    runtime: &'a mut JSRuntime<'rt>,
+    index: usize,
+    len: usize,
+}
+
+impl Iterator for ArrayIter<'_, '_> {
+    type Item = crate::JSValue;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+
+        let value = self.array.get(self.runtime, self.index);
+        self.index += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for ArrayIter<'_, '_> {}
+
+impl From<JSArray> for crate::JSValue {
+    fn from(value: JSArray) -> Self {
+        crate::JSValue {
+            inner: value.inner.map(crate::sys::ffi::value_from_array),
+        }
+    }
+}
@@ -1,40 +1,203 @@
-use crate::{JSRuntime, JSString, Result};
+use crate::{Error, JSRuntime, JSString, JSValue, Result};
 
 /// Wrapper around facebook::jsi::BigInt providing a safe Rust API
 pub struct JSBigInt {
-    pub(crate) inner: cxx::UniquePtr<crate::sys::ffi::JSIBigInt>,
+    pub(crate) inner: crate::handle::Handle<crate::sys::ffi::JSIBigInt>,
 }
 
 impl JSBigInt {
     /// Create a new BigInt from a signed 64-bit integer
     pub fn from_i64(runtime: &mut JSRuntime<'_>, value: i64) -> Self {
         let ptr = crate::sys::ffi::create_bigint_from_i64(runtime.pin_mut(), value);
-        Self { inner: ptr }
+        Self {
+            inner: runtime.handle(ptr),
+        }
     }
 
     /// Create a new BigInt from an unsigned 64-bit integer
     pub fn from_u64(runtime: &mut JSRuntime<'_>, value: u64) -> Self {
         let ptr = crate::sys::ffi::create_bigint_from_u64(runtime.pin_mut(), value);
-        Self { inner: ptr }
+        Self {
+            inner: runtime.handle(ptr),
+        }
+    }
+
+    /// Create a new BigInt from a signed 128-bit integer
+    ///
+    /// Values outside of the 64-bit range go through the global `BigInt` function and fail
+    /// when a script has removed it before its first use.
+    pub fn from_i128(runtime: &mut JSRuntime<'_>, value: i128) -> Result<Self> {
+        match i64::try_from(value) {
+            Ok(value) => Ok(Self::from_i64(runtime, value)),
+            Err(_) => Self::from_literal(runtime, &value.to_string()),
+        }
+    }
+
+    /// Create a new BigInt from an unsigned 128-bit integer, see [`JSBigInt::from_i128`]
+    pub fn from_u128(runtime: &mut JSRuntime<'_>, value: u128) -> Result<Self> {
+        match u64::try_from(value) {
+            Ok(value) => Ok(Self::from_u64(runtime, value)),
+            Err(_) => Self::from_literal(runtime, &value.to_string()),
+        }
+    }
+
+    /// Parse a BigInt from a decimal or `0x` prefixed hexadecimal string
+    ///
+    /// An optional leading `-` is accepted for both forms, e.g. `"-0xff"`.
+    pub fn parse(runtime: &mut JSRuntime<'_>, text: &str) -> Result<Self> {
+        let text = text.trim();
+        let (negative, digits) = match text.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+
+        let is_hex = digits.starts_with("0x") || digits.starts_with("0X");
+        let valid = if is_hex {
+            digits.len() > 2 && digits[2..].bytes().all(|b| b.is_ascii_hexdigit())
+        } else {
+            !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+        };
+        if !valid {
+            return Err(Error::new(format!("invalid BigInt literal: {:?}", text)));
+        }
+
+        // BigInt() only accepts a sign on decimal literals
+        if negative && is_hex {
+            let magnitude = Self::from_literal(runtime, digits)?.to_string(runtime)?;
+            return Self::from_literal(runtime, &format!("-{}", magnitude));
+        }
+
+        if negative {
+            Self::from_literal(runtime, &format!("-{}", digits))
+        } else {
+            Self::from_literal(runtime, digits)
+        }
+    }
+
+    /// Create a BigInt through the global `BigInt` function from an already validated literal
+    ///
+    /// The function is looked up once per runtime, scripts replacing it later have no effect.
+    fn from_literal(runtime: &mut JSRuntime<'_>, literal: &str) -> Result<Self> {
+        let constructor = runtime.cached("BigInt", |runtime| runtime.global_function("BigInt"))?;
+        let literal = JSValue::from(JSString::new(runtime, literal));
+        constructor
+            .call(runtime, &[literal])?
+            .as_bigint(runtime)
+            .ok_or_else(|| Error::new("BigInt() did not return a bigint"))
+    }
+
+    /// Whether the value can be converted to `i64` without loss
+    pub fn is_i64(&self, runtime: &mut JSRuntime<'_>) -> bool {
+        self.inner.check(runtime);
+        crate::sys::ffi::bigint_is_int64(runtime.pin_mut(), &self.inner)
+    }
+
+    /// Whether the value can be converted to `u64` without loss
+    pub fn is_u64(&self, runtime: &mut JSRuntime<'_>) -> bool {
+        self.inner.check(runtime);
+        crate::sys::ffi::bigint_is_uint64(runtime.pin_mut(), &self.inner)
+    }
+
+    /// Return the value as `i64`, or `None` if it does not fit
+    pub fn as_i64(&self, runtime: &mut JSRuntime<'_>) -> Option<i64> {
+        self.inner.check(runtime);
+        if !self.is_i64(runtime) {
+            return None;
+        }
+
+        Some(self.truncate(runtime) as i64)
+    }
+
+    /// Return the value as `u64`, or `None` if it does not fit
+    pub fn as_u64(&self, runtime: &mut JSRuntime<'_>) -> Option<u64> {
+        self.inner.check(runtime);
+        if !self.is_u64(runtime) {
+            return None;
+        }
+
+        Some(self.truncate(runtime))
+    }
+
+    /// Return the value as `i128`, or `None` if it does not fit
+    pub fn as_i128(&self, runtime: &mut JSRuntime<'_>) -> Option<i128> {
+        self.inner.check(runtime);
+        if let Some(value) = self.as_i64(runtime) {
+            return Some(value as i128);
+        }
+
+        self.to_string(runtime).ok()?.parse().ok()
+    }
+
+    /// Return the value as `u128`, or `None` if it does not fit
+    pub fn as_u128(&self, runtime: &mut JSRuntime<'_>) -> Option<u128> {
+        self.inner.check(runtime);
+        if let Some(value) = self.as_u64(runtime) {
+            return Some(value as u128);
+        }
+
+        self.to_string(runtime).ok()?.parse().ok()
+    }
+
+    /// Return the low 64 bits of the two's complement representation
+    ///
+    /// Cast the result to `i64` to get the value wrapped into the signed range.
+    pub fn truncate(&self, runtime: &mut JSRuntime<'_>) -> u64 {
+        self.inner.check(runtime);
+        crate::sys::ffi::bigint_truncate(runtime.pin_mut(), &self.inner)
     }
 
     pub fn as_string_opt(&self, runtime: &mut JSRuntime<'_>, radix: i32) -> Result<JSString> {
+        self.inner.check(runtime);
         let inner = crate::sys::ffi::bigint_to_string(runtime.pin_mut(), &self.inner, radix)?;
-        Ok(JSString { inner })
+        Ok(JSString {
+            inner: runtime.handle(inner),
+        })
     }
 
     pub fn as_string(&self, runtime: &mut JSRuntime<'_>) -> Result<JSString> {
+        self.inner.check(runtime);
         self.as_string_opt(runtime, 10)
     }
 
     pub fn to_string(&self, runtime: &mut JSRuntime<'_>) -> Result<String> {
+        self.inner.check(runtime);
         let js_string = self.as_string(runtime)?;
         Ok(js_string.value(runtime))
     }
 
+    /// Convert into an arbitrary precision `num_bigint::BigInt`
+    #[cfg(feature = "num-bigint")]
+    pub fn to_num_bigint(&self, runtime: &mut JSRuntime<'_>) -> Result<num_bigint::BigInt> {
+        self.inner.check(runtime);
+        let hex = self.as_string_opt(runtime, 16)?.value(runtime);
+        num_bigint::BigInt::parse_bytes(hex.as_bytes(), 16)
+            .ok_or_else(|| Error::new(format!("invalid BigInt string: {}", hex)))
+    }
+
+    /// Create a BigInt from an arbitrary precision `num_bigint::BigInt`
+    #[cfg(feature = "num-bigint")]
+    pub fn from_num_bigint(
+        runtime: &mut JSRuntime<'_>,
+        value: &num_bigint::BigInt,
+    ) -> Result<Self> {
+        if let Ok(value) = i64::try_from(value) {
+            return Ok(Self::from_i64(runtime, value));
+        }
+
+        Self::from_literal(runtime, &value.to_string())
+    }
+
     /// Access the inner UniquePtr for advanced usage
     #[cfg(feature = "unsafe")]
     pub fn inner(&self) -> &cxx::UniquePtr<crate::sys::ffi::JSIBigInt> {
         &self.inner
     }
 }
+
+impl From<JSBigInt> for crate::JSValue {
+    fn from(value: JSBigInt) -> Self {
+        crate::JSValue {
+            inner: value.inner.map(crate::sys::ffi::value_from_bigint),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+#include "jsi-rs/src/sys.rs.h"
+
+namespace jsi_rs {
+
+std::unique_ptr<facebook::jsi::Function> create_host_function(
+    facebook::jsi::Runtime& runtime,
+    rust::Str name,
+    uint32_t param_count,
+    rust::Box<HostFunction> func) {
+    // std::function requires a copyable callable, so the Rust closure is shared
+    auto shared = std::make_shared<rust::Box<HostFunction>>(std::move(func));
+    auto prop_name = facebook::jsi::PropNameID::forUtf8(
+        runtime,
+        reinterpret_cast<const uint8_t*>(name.data()),
+        name.size());
+
+    facebook::jsi::Function function = facebook::jsi::Function::createFromHostFunction(
+        runtime,
+        prop_name,
+        param_count,
+        [shared](
+            facebook::jsi::Runtime& rt,
+            const facebook::jsi::Value& this_val,
+            const facebook::jsi::Value* args,
+            size_t count) -> facebook::jsi::Value {
+            ValueVec vec;
+            vec.values.reserve(count);
+            for (size_t i = 0; i < count; i++) {
+                vec.values.push_back(facebook::jsi::Value(rt, args[i]));
+            }
+
+            try {
+                auto result = call_host_function(
+                    **shared,
+                    rt,
+                    std::make_unique<facebook::jsi::Value>(rt, this_val),
+                    vec);
+                return std::move(*result);
+            } catch (const rust::Error& e) {
+                throw facebook::jsi::JSError(rt, e.what());
+            }
+        });
+
+    return std::make_unique<facebook::jsi::Function>(std::move(function));
+}
+
+// Forwards getStringData / getPropNameIdData chunks to the Rust visitor without copying
+static void visit_chunk(ChunkVisitor& visitor, bool ascii, const void* data, size_t num) {
+    if (num == 0) {
+        return;
+    }
+
+    if (ascii) {
+        visit_ascii_chunk(
+            visitor,
+            rust::Slice<const uint8_t>(static_cast<const uint8_t*>(data), num));
+    } else {
+        visit_utf16_chunk(
+            visitor,
+            rust::Slice<const uint16_t>(static_cast<const uint16_t*>(data), num));
+    }
+}
+
+void string_for_each_chunk(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::String>& str,
+    ChunkVisitor& visitor) {
+    auto callback = [&visitor](bool ascii, const void* data, size_t num) {
+        visit_chunk(visitor, ascii, data, num);
+    };
+    str->getStringData(runtime, callback);
+}
+
+void propnameid_for_each_chunk(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::PropNameID>& propnameid,
+    ChunkVisitor& visitor) {
+    auto callback = [&visitor](bool ascii, const void* data, size_t num) {
+        visit_chunk(visitor, ascii, data, num);
+    };
+    propnameid->getPropNameIdData(runtime, callback);
+}
+
+} // namespace jsi_rs
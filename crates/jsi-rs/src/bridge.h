@@ -8,6 +8,12 @@
 
 namespace jsi_rs {
 
+// Defined on the Rust side (see HostFunction in function.rs)
+struct HostFunction;
+
+// Defined on the Rust side (see ChunkVisitor in string.rs)
+struct ChunkVisitor;
+
 struct ValueVec {
     std::vector<facebook::jsi::Value> values;
 };
@@ -16,6 +22,15 @@ inline std::unique_ptr<facebook::jsi::Object> create_object(facebook::jsi::Runti
     return std::make_unique<facebook::jsi::Object>(runtime);
 }
 
+inline std::unique_ptr<facebook::jsi::Object> runtime_global(facebook::jsi::Runtime& runtime) {
+    return std::make_unique<facebook::jsi::Object>(runtime.global());
+}
+
+// Scope is neither copyable nor movable, it is constructed in place and popped on delete
+inline std::unique_ptr<facebook::jsi::Scope> create_scope(facebook::jsi::Runtime& runtime) {
+    return std::make_unique<facebook::jsi::Scope>(runtime);
+}
+
 inline std::unique_ptr<facebook::jsi::Array> create_array(facebook::jsi::Runtime& runtime, size_t length) {
     return std::make_unique<facebook::jsi::Array>(runtime, length);
 }
@@ -59,6 +74,46 @@ inline std::unique_ptr<facebook::jsi::Value> create_value_number(double value) {
     return std::make_unique<facebook::jsi::Value>(value);
 }
 
+inline std::unique_ptr<facebook::jsi::String> create_string_from_ascii(facebook::jsi::Runtime& runtime, rust::Slice<const uint8_t> data) {
+    return std::make_unique<facebook::jsi::String>(
+        facebook::jsi::String::createFromAscii(
+            runtime,
+            reinterpret_cast<const char*>(data.data()),
+            data.size()
+        )
+    );
+}
+
+inline std::unique_ptr<facebook::jsi::String> create_string_from_utf16(facebook::jsi::Runtime& runtime, rust::Slice<const uint16_t> data) {
+    return std::make_unique<facebook::jsi::String>(
+        facebook::jsi::String::createFromUtf16(
+            runtime,
+            reinterpret_cast<const char16_t*>(data.data()),
+            data.size()
+        )
+    );
+}
+
+inline std::unique_ptr<facebook::jsi::PropNameID> create_propnameid_from_ascii(facebook::jsi::Runtime& runtime, rust::Slice<const uint8_t> data) {
+    return std::make_unique<facebook::jsi::PropNameID>(
+        facebook::jsi::PropNameID::forAscii(
+            runtime,
+            reinterpret_cast<const char*>(data.data()),
+            data.size()
+        )
+    );
+}
+
+inline std::unique_ptr<facebook::jsi::PropNameID> create_propnameid_from_utf16(facebook::jsi::Runtime& runtime, rust::Slice<const uint16_t> data) {
+    return std::make_unique<facebook::jsi::PropNameID>(
+        facebook::jsi::PropNameID::forUtf16(
+            runtime,
+            reinterpret_cast<const char16_t*>(data.data()),
+            data.size()
+        )
+    );
+}
+
 inline rust::String string_to_utf8(facebook::jsi::Runtime& runtime, const std::unique_ptr<facebook::jsi::String>& str) {
     return rust::String(str->utf8(runtime));
 }
@@ -75,6 +130,18 @@ inline std::unique_ptr<facebook::jsi::String> bigint_to_string(
     return std::make_unique<facebook::jsi::String>(std::move(str));
 }
 
+inline bool bigint_is_int64(facebook::jsi::Runtime& runtime, const std::unique_ptr<facebook::jsi::BigInt>& bigint) {
+    return bigint->isInt64(runtime);
+}
+
+inline bool bigint_is_uint64(facebook::jsi::Runtime& runtime, const std::unique_ptr<facebook::jsi::BigInt>& bigint) {
+    return bigint->isUint64(runtime);
+}
+
+inline uint64_t bigint_truncate(facebook::jsi::Runtime& runtime, const std::unique_ptr<facebook::jsi::BigInt>& bigint) {
+    return bigint->getUint64(runtime);
+}
+
 inline bool value_as_bool(const std::unique_ptr<facebook::jsi::Value>& value) {
     return value->getBool();
 }
@@ -147,12 +214,26 @@ inline std::unique_ptr<facebook::jsi::Value> array_get_value_at_index(
     return std::make_unique<facebook::jsi::Value>(std::move(value));
 }
 
+// Array::setValueAtIndex rejects indices past the end, a plain property store grows the array
 inline void array_set_value_at_index(
     facebook::jsi::Runtime& runtime,
     const std::unique_ptr<facebook::jsi::Array>& array,
     size_t index,
     const std::unique_ptr<facebook::jsi::Value>& value) {
-    array->setValueAtIndex(runtime, index, facebook::jsi::Value(runtime, *value));
+    if (index < array->size(runtime)) {
+        array->setValueAtIndex(runtime, index, facebook::jsi::Value(runtime, *value));
+        return;
+    }
+    std::string name = std::to_string(index);
+    array->setProperty(runtime, name.c_str(), facebook::jsi::Value(runtime, *value));
+}
+
+inline void array_push(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Array>& array,
+    const std::unique_ptr<facebook::jsi::Value>& value) {
+    std::string index = std::to_string(array->size(runtime));
+    array->setProperty(runtime, index.c_str(), facebook::jsi::Value(runtime, *value));
 }
 
 inline size_t array_size(
@@ -194,7 +275,14 @@ inline void object_delete_property(
     const std::unique_ptr<facebook::jsi::Object>& obj,
     rust::Str name) {
     std::string str(name.data(), name.size());
-    obj->setProperty(runtime, str.c_str(), facebook::jsi::Value::undefined());
+    obj->deleteProperty(runtime, facebook::jsi::String::createFromUtf8(runtime, str));
+}
+
+inline void object_delete_property_with_id(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Object>& obj,
+    const std::unique_ptr<facebook::jsi::PropNameID>& name) {
+    obj->deleteProperty(runtime, *name);
 }
 
 inline std::unique_ptr<facebook::jsi::Array> object_get_property_names(
@@ -204,4 +292,224 @@ inline std::unique_ptr<facebook::jsi::Array> object_get_property_names(
     return std::make_unique<facebook::jsi::Array>(std::move(names));
 }
 
+// Object::getPropertyNames also lists inherited enumerable properties, like for-in
+inline std::unique_ptr<facebook::jsi::Array> object_get_own_property_names(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Object>& obj) {
+    facebook::jsi::Array names = obj->getPropertyNames(runtime);
+    facebook::jsi::Function hasOwnProperty = runtime.global()
+        .getPropertyAsObject(runtime, "Object")
+        .getPropertyAsObject(runtime, "prototype")
+        .getPropertyAsFunction(runtime, "hasOwnProperty");
+
+    size_t size = names.size(runtime);
+    std::vector<facebook::jsi::Value> own;
+    own.reserve(size);
+    for (size_t i = 0; i < size; i++) {
+        facebook::jsi::Value name = names.getValueAtIndex(runtime, i);
+        facebook::jsi::Value isOwn = hasOwnProperty.callWithThis(runtime, *obj, name);
+        if (isOwn.isBool() && isOwn.getBool()) {
+            own.push_back(std::move(name));
+        }
+    }
+
+    facebook::jsi::Array result(runtime, own.size());
+    for (size_t i = 0; i < own.size(); i++) {
+        result.setValueAtIndex(runtime, i, std::move(own[i]));
+    }
+    return std::make_unique<facebook::jsi::Array>(std::move(result));
+}
+
+inline std::unique_ptr<facebook::jsi::Value> object_get_property_with_id(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Object>& obj,
+    const std::unique_ptr<facebook::jsi::PropNameID>& name) {
+    return std::make_unique<facebook::jsi::Value>(obj->getProperty(runtime, *name));
+}
+
+inline void object_set_property_with_id(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Object>& obj,
+    const std::unique_ptr<facebook::jsi::PropNameID>& name,
+    const std::unique_ptr<facebook::jsi::Value>& value) {
+    obj->setProperty(runtime, *name, facebook::jsi::Value(runtime, *value));
+}
+
+inline std::unique_ptr<facebook::jsi::Value> object_get_property_with_value(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Object>& obj,
+    const std::unique_ptr<facebook::jsi::Value>& name) {
+    return std::make_unique<facebook::jsi::Value>(obj->getProperty(runtime, *name));
+}
+
+inline bool object_has_property_with_id(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Object>& obj,
+    const std::unique_ptr<facebook::jsi::PropNameID>& name) {
+    return obj->hasProperty(runtime, *name);
+}
+
+inline std::unique_ptr<facebook::jsi::Value> object_get_prototype(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Object>& obj) {
+    return std::make_unique<facebook::jsi::Value>(obj->getPrototype(runtime));
+}
+
+inline void object_set_prototype(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Object>& obj,
+    const std::unique_ptr<facebook::jsi::Value>& prototype) {
+    obj->setPrototype(runtime, *prototype);
+}
+
+inline bool object_instance_of(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Object>& obj,
+    const std::unique_ptr<facebook::jsi::Function>& ctor) {
+    return obj->instanceOf(runtime, *ctor);
+}
+
+inline std::unique_ptr<facebook::jsi::Value> object_to_value(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Object>& obj) {
+    return std::make_unique<facebook::jsi::Value>(runtime, *obj);
+}
+
+inline bool object_is_array(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Object>& obj) {
+    return obj->isArray(runtime);
+}
+
+inline bool object_is_function(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Object>& obj) {
+    return obj->isFunction(runtime);
+}
+
+inline bool object_is_array_buffer(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Object>& obj) {
+    return obj->isArrayBuffer(runtime);
+}
+
+inline std::unique_ptr<facebook::jsi::Array> object_as_array(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Object>& obj) {
+    facebook::jsi::Array array = obj->getArray(runtime);
+    return std::make_unique<facebook::jsi::Array>(std::move(array));
+}
+
+// Value conversions
+
+inline std::unique_ptr<facebook::jsi::Value> value_copy(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Value>& value) {
+    return std::make_unique<facebook::jsi::Value>(runtime, *value);
+}
+
+// New handles to the same JS object, jsi only copies through Value
+
+inline std::unique_ptr<facebook::jsi::Object> object_copy(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Object>& obj) {
+    return std::make_unique<facebook::jsi::Object>(
+        facebook::jsi::Value(runtime, *obj).getObject(runtime));
+}
+
+inline std::unique_ptr<facebook::jsi::Array> array_copy(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Array>& array) {
+    return std::make_unique<facebook::jsi::Array>(
+        facebook::jsi::Value(runtime, *array).getObject(runtime).getArray(runtime));
+}
+
+inline std::unique_ptr<facebook::jsi::PropNameID> propnameid_copy(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::PropNameID>& name) {
+    return std::make_unique<facebook::jsi::PropNameID>(runtime, *name);
+}
+
+inline std::unique_ptr<facebook::jsi::Function> function_copy(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Function>& func) {
+    return std::make_unique<facebook::jsi::Function>(
+        facebook::jsi::Value(runtime, *func).getObject(runtime).getFunction(runtime));
+}
+
+inline bool value_strict_equals(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Value>& a,
+    const std::unique_ptr<facebook::jsi::Value>& b) {
+    return facebook::jsi::Value::strictEquals(runtime, *a, *b);
+}
+
+inline std::unique_ptr<facebook::jsi::String> value_to_string(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Value>& value) {
+    return std::make_unique<facebook::jsi::String>(value->toString(runtime));
+}
+
+inline bool object_strict_equals(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::Object>& a,
+    const std::unique_ptr<facebook::jsi::Object>& b) {
+    return facebook::jsi::Object::strictEquals(runtime, *a, *b);
+}
+
+inline bool string_strict_equals(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::String>& a,
+    const std::unique_ptr<facebook::jsi::String>& b) {
+    return facebook::jsi::String::strictEquals(runtime, *a, *b);
+}
+
+inline std::unique_ptr<facebook::jsi::Value> value_from_string(std::unique_ptr<facebook::jsi::String> str) {
+    return std::make_unique<facebook::jsi::Value>(std::move(*str));
+}
+
+inline std::unique_ptr<facebook::jsi::Value> value_from_object(std::unique_ptr<facebook::jsi::Object> obj) {
+    return std::make_unique<facebook::jsi::Value>(std::move(*obj));
+}
+
+inline std::unique_ptr<facebook::jsi::Value> value_from_array(std::unique_ptr<facebook::jsi::Array> array) {
+    return std::make_unique<facebook::jsi::Value>(std::move(*array));
+}
+
+inline std::unique_ptr<facebook::jsi::Value> value_from_function(std::unique_ptr<facebook::jsi::Function> func) {
+    return std::make_unique<facebook::jsi::Value>(std::move(*func));
+}
+
+inline std::unique_ptr<facebook::jsi::Value> value_from_bigint(std::unique_ptr<facebook::jsi::BigInt> bigint) {
+    return std::make_unique<facebook::jsi::Value>(std::move(*bigint));
+}
+
+inline size_t value_vec_size(const ValueVec& vec) {
+    return vec.values.size();
+}
+
+inline std::unique_ptr<facebook::jsi::Value> value_vec_take(ValueVec& vec, size_t index) {
+    return std::make_unique<facebook::jsi::Value>(std::move(vec.values.at(index)));
+}
+
+// Host functions (implemented in bridge.cc, it needs the generated HostFunction definition)
+
+std::unique_ptr<facebook::jsi::Function> create_host_function(
+    facebook::jsi::Runtime& runtime,
+    rust::Str name,
+    uint32_t param_count,
+    rust::Box<HostFunction> func);
+
+// String data access (implemented in bridge.cc, it needs the generated ChunkVisitor callbacks)
+
+void string_for_each_chunk(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::String>& str,
+    ChunkVisitor& visitor);
+
+void propnameid_for_each_chunk(
+    facebook::jsi::Runtime& runtime,
+    const std::unique_ptr<facebook::jsi::PropNameID>& propnameid,
+    ChunkVisitor& visitor);
+
 } // namespace jsi_rs
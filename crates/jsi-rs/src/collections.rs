@@ -0,0 +1,316 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, Hash};
+
+use crate::convert::type_error;
+use crate::{for_of, Error, FromJs, IntoJs, JSFunction, JSObject, JSRuntime, JSValue, Result};
+
+/// `globalThis[class]` as it was when first used in this runtime
+fn constructor(runtime: &mut JSRuntime<'_>, class: &'static str) -> Result<JSFunction> {
+    runtime.cached(class, |runtime| runtime.global_function(class))
+}
+
+/// Object property `name` that must hold an object
+fn object_property(runtime: &mut JSRuntime<'_>, object: &JSObject, name: &str) -> Result<JSObject> {
+    let value = object.get(runtime, name);
+    value
+        .as_object(runtime)
+        .ok_or_else(|| type_error("object", &value))
+}
+
+fn prototype(runtime: &mut JSRuntime<'_>, class: &str) -> Result<JSObject> {
+    let global = runtime.global();
+    let constructor = object_property(runtime, &global, class)?;
+    object_property(runtime, &constructor, "prototype")
+}
+
+/// Builtin method like `"Map.prototype.get"`, cached per runtime so that scripts replacing
+/// it later don't change the behavior of the wrappers
+fn builtin_method(runtime: &mut JSRuntime<'_>, key: &'static str) -> Result<JSFunction> {
+    runtime.cached(key, |runtime| {
+        let (class, name) = key
+            .split_once(".prototype.")
+            .expect("builtin method key has the form Class.prototype.name");
+        let method = prototype(runtime, class)?.get(runtime, name);
+        method
+            .as_function(runtime)
+            .ok_or_else(|| Error::new(format!("{key} is not a function")))
+    })
+}
+
+/// Getter of a builtin accessor like `"Map.prototype.size"`, see [`builtin_method`]
+fn builtin_getter(runtime: &mut JSRuntime<'_>, key: &'static str) -> Result<JSFunction> {
+    runtime.cached(key, |runtime| {
+        let (class, name) = key
+            .split_once(".prototype.")
+            .expect("builtin getter key has the form Class.prototype.name");
+        let prototype = JSValue::from(prototype(runtime, class)?);
+        let name = JSValue::from(runtime.create_string(name));
+
+        let global = runtime.global();
+        let object = object_property(runtime, &global, "Object")?;
+        let descriptor = object
+            .get(runtime, "getOwnPropertyDescriptor")
+            .as_function(runtime)
+            .ok_or_else(|| Error::new("Object.getOwnPropertyDescriptor is not a function"))?
+            .call(runtime, &[prototype, name])?;
+        let descriptor = descriptor
+            .as_object(runtime)
+            .ok_or_else(|| Error::new(format!("{key} is not defined")))?;
+        descriptor
+            .get(runtime, "get")
+            .as_function(runtime)
+            .ok_or_else(|| Error::new(format!("{key} is not a getter")))
+    })
+}
+
+/// Call a builtin method on `this`, see [`builtin_method`]
+pub(crate) fn call_builtin(
+    runtime: &mut JSRuntime<'_>,
+    key: &'static str,
+    this: &JSObject,
+    args: &[JSValue],
+) -> Result<JSValue> {
+    builtin_method(runtime, key)?.call_with_this(runtime, this, args)
+}
+
+/// Check that `object` is a real instance of a builtin class by calling one of its methods,
+/// which throw for other receivers. Unlike `instanceof` this can't be fooled by prototypes.
+pub(crate) fn has_brand(runtime: &mut JSRuntime<'_>, object: &JSObject, key: &'static str) -> bool {
+    call_builtin(runtime, key, object, &[JSValue::undefined()]).is_ok()
+}
+
+pub(crate) fn construct(
+    runtime: &mut JSRuntime<'_>,
+    class: &'static str,
+    args: &[JSValue],
+) -> Result<JSObject> {
+    let value = constructor(runtime, class)?.call_as_constructor(runtime, args)?;
+    value
+        .as_object(runtime)
+        .ok_or_else(|| type_error("object", &value))
+}
+
+/// Wrapper around a JavaScript `Map`
+pub struct JSMap {
+    object: JSObject,
+}
+
+impl JSMap {
+    /// Create a new empty `Map`
+    pub fn new(runtime: &mut JSRuntime<'_>) -> Result<Self> {
+        let object = construct(runtime, "Map", &[])?;
+        Ok(Self { object })
+    }
+
+    /// Create a `Map` from Rust key/value pairs, keeping their iteration order
+    pub fn from_entries<K, V, I>(runtime: &mut JSRuntime<'_>, entries: I) -> Result<Self>
+    where
+        K: IntoJs,
+        V: IntoJs,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let map = Self::new(runtime)?;
+        for (key, value) in entries {
+            let key = key.into_js(runtime)?;
+            let value = value.into_js(runtime)?;
+            map.set(runtime, &key, &value)?;
+        }
+        Ok(map)
+    }
+
+    /// Wrap `object` if it is a `Map`
+    pub fn from_object(runtime: &mut JSRuntime<'_>, object: JSObject) -> Option<Self> {
+        if !has_brand(runtime, &object, "Map.prototype.has") {
+            return None;
+        }
+        Some(Self { object })
+    }
+
+    pub fn get(&self, runtime: &mut JSRuntime<'_>, key: &JSValue) -> Result<JSValue> {
+        let key = key.clone_in(runtime);
+        call_builtin(runtime, "Map.prototype.get", &self.object, &[key])
+    }
+
+    pub fn set(&self, runtime: &mut JSRuntime<'_>, key: &JSValue, value: &JSValue) -> Result<()> {
+        let key = key.clone_in(runtime);
+        let value = value.clone_in(runtime);
+        call_builtin(runtime, "Map.prototype.set", &self.object, &[key, value])?;
+        Ok(())
+    }
+
+    pub fn has(&self, runtime: &mut JSRuntime<'_>, key: &JSValue) -> Result<bool> {
+        let key = key.clone_in(runtime);
+        call_builtin(runtime, "Map.prototype.has", &self.object, &[key])?.try_as_bool()
+    }
+
+    /// Remove an entry, returns whether the key was present
+    pub fn delete(&self, runtime: &mut JSRuntime<'_>, key: &JSValue) -> Result<bool> {
+        let key = key.clone_in(runtime);
+        call_builtin(runtime, "Map.prototype.delete", &self.object, &[key])?.try_as_bool()
+    }
+
+    pub fn clear(&self, runtime: &mut JSRuntime<'_>) -> Result<()> {
+        call_builtin(runtime, "Map.prototype.clear", &self.object, &[])?;
+        Ok(())
+    }
+
+    pub fn size(&self, runtime: &mut JSRuntime<'_>) -> Result<usize> {
+        let size = builtin_getter(runtime, "Map.prototype.size")?.call_with_this(
+            runtime,
+            &self.object,
+            &[],
+        )?;
+        usize::from_js(runtime, &size)
+    }
+
+    /// Get all entries in insertion order
+    pub fn entries(&self, runtime: &mut JSRuntime<'_>) -> Result<Vec<(JSValue, JSValue)>> {
+        let mut entries = Vec::new();
+        let iterator = call_builtin(runtime, "Map.prototype.entries", &self.object, &[])?;
+        for_of(runtime, &iterator, |runtime, entry| {
+            entries.push(<(JSValue, JSValue)>::from_js(runtime, &entry)?);
+            Ok(())
+        })?;
+        Ok(entries)
+    }
+
+    /// Convert the entries into a Rust map
+    pub fn to_hash_map<K, V, S>(&self, runtime: &mut JSRuntime<'_>) -> Result<HashMap<K, V, S>>
+    where
+        K: FromJs + Eq + Hash,
+        V: FromJs,
+        S: BuildHasher + Default,
+    {
+        let mut map = HashMap::default();
+        for (key, value) in self.entries(runtime)? {
+            map.insert(K::from_js(runtime, &key)?, V::from_js(runtime, &value)?);
+        }
+        Ok(map)
+    }
+
+    pub fn as_object(&self) -> &JSObject {
+        &self.object
+    }
+}
+
+impl From<JSMap> for JSValue {
+    fn from(map: JSMap) -> Self {
+        JSValue::from(map.object)
+    }
+}
+
+/// Wrapper around a JavaScript `Set`
+pub struct JSSet {
+    object: JSObject,
+}
+
+impl JSSet {
+    /// Create a new empty `Set`
+    pub fn new(runtime: &mut JSRuntime<'_>) -> Result<Self> {
+        let object = construct(runtime, "Set", &[])?;
+        Ok(Self { object })
+    }
+
+    /// Create a `Set` from Rust values, keeping their iteration order
+    pub fn from_values<T, I>(runtime: &mut JSRuntime<'_>, values: I) -> Result<Self>
+    where
+        T: IntoJs,
+        I: IntoIterator<Item = T>,
+    {
+        let set = Self::new(runtime)?;
+        for value in values {
+            let value = value.into_js(runtime)?;
+            set.add(runtime, &value)?;
+        }
+        Ok(set)
+    }
+
+    /// Wrap `object` if it is a `Set`
+    pub fn from_object(runtime: &mut JSRuntime<'_>, object: JSObject) -> Option<Self> {
+        if !has_brand(runtime, &object, "Set.prototype.has") {
+            return None;
+        }
+        Some(Self { object })
+    }
+
+    pub fn add(&self, runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<()> {
+        let value = value.clone_in(runtime);
+        call_builtin(runtime, "Set.prototype.add", &self.object, &[value])?;
+        Ok(())
+    }
+
+    pub fn has(&self, runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<bool> {
+        let value = value.clone_in(runtime);
+        call_builtin(runtime, "Set.prototype.has", &self.object, &[value])?.try_as_bool()
+    }
+
+    /// Remove a value, returns whether it was present
+    pub fn delete(&self, runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<bool> {
+        let value = value.clone_in(runtime);
+        call_builtin(runtime, "Set.prototype.delete", &self.object, &[value])?.try_as_bool()
+    }
+
+    pub fn clear(&self, runtime: &mut JSRuntime<'_>) -> Result<()> {
+        call_builtin(runtime, "Set.prototype.clear", &self.object, &[])?;
+        Ok(())
+    }
+
+    pub fn size(&self, runtime: &mut JSRuntime<'_>) -> Result<usize> {
+        let size = builtin_getter(runtime, "Set.prototype.size")?.call_with_this(
+            runtime,
+            &self.object,
+            &[],
+        )?;
+        usize::from_js(runtime, &size)
+    }
+
+    /// Get all values in insertion order
+    pub fn values(&self, runtime: &mut JSRuntime<'_>) -> Result<Vec<JSValue>> {
+        let mut values = Vec::new();
+        let iterator = call_builtin(runtime, "Set.prototype.values", &self.object, &[])?;
+        for_of(runtime, &iterator, |_runtime, value| {
+            values.push(value);
+            Ok(())
+        })?;
+        Ok(values)
+    }
+
+    /// Convert the values into a Rust set
+    pub fn to_hash_set<T, S>(&self, runtime: &mut JSRuntime<'_>) -> Result<HashSet<T, S>>
+    where
+        T: FromJs + Eq + Hash,
+        S: BuildHasher + Default,
+    {
+        let mut set = HashSet::default();
+        for value in self.values(runtime)? {
+            set.insert(T::from_js(runtime, &value)?);
+        }
+        Ok(set)
+    }
+
+    pub fn as_object(&self) -> &JSObject {
+        &self.object
+    }
+}
+
+impl From<JSSet> for JSValue {
+    fn from(set: JSSet) -> Self {
+        JSValue::from(set.object)
+    }
+}
+
+/// Sets are converted to and from JavaScript `Set` objects
+impl<T: IntoJs, S> IntoJs for HashSet<T, S> {
+    fn into_js(self, runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+        JSSet::from_values(runtime, self).map(JSValue::from)
+    }
+}
+
+impl<T: FromJs + Eq + Hash, S: BuildHasher + Default> FromJs for HashSet<T, S> {
+    fn from_js(runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self> {
+        let set = value
+            .as_set(runtime)
+            .ok_or_else(|| type_error("Set", value))?;
+        set.to_hash_set(runtime)
+    }
+}
@@ -0,0 +1,394 @@
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+use crate::{Error, JSArray, JSBigInt, JSObject, JSRuntime, JSString, JSValue, Result};
+
+/// Largest integer that a JavaScript number can represent exactly (`Number.MAX_SAFE_INTEGER`)
+const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+
+/// Conversion of a Rust value into a JavaScript value
+///
+/// Implemented for the basic value types and derivable with `#[derive(IntoJs)]`
+/// when the `derive` feature is enabled.
+pub trait IntoJs {
+    fn into_js(self, runtime: &mut JSRuntime<'_>) -> Result<JSValue>;
+}
+
+/// Conversion of a JavaScript value into a Rust value
+///
+/// Returns an error when the JavaScript value has an unexpected type.
+/// Derivable with `#[derive(FromJs)]` when the `derive` feature is enabled.
+pub trait FromJs: Sized {
+    fn from_js(runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self>;
+}
+
+pub(crate) fn type_error(expected: &str, value: &JSValue) -> Error {
+    Error::new(format!("expected {}, got {}", expected, value.type_name()))
+}
+
+/// Prefix an error from a nested conversion with the location it happened at
+fn nested_error(location: std::fmt::Arguments<'_>, error: Error) -> Error {
+    Error::new(format!("{}: {}", location, error.message()))
+}
+
+impl IntoJs for JSValue {
+    fn into_js(self, _runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+        Ok(self)
+    }
+}
+
+impl FromJs for JSValue {
+    fn from_js(runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self> {
+        Ok(value.clone_in(runtime))
+    }
+}
+
+impl IntoJs for bool {
+    fn into_js(self, _runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+        Ok(JSValue::bool(self))
+    }
+}
+
+impl FromJs for bool {
+    fn from_js(_runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self> {
+        value.try_as_bool()
+    }
+}
+
+impl IntoJs for f64 {
+    fn into_js(self, _runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+        Ok(JSValue::number(self))
+    }
+}
+
+impl FromJs for f64 {
+    fn from_js(_runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self> {
+        value.try_as_number()
+    }
+}
+
+impl IntoJs for f32 {
+    fn into_js(self, _runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+        Ok(JSValue::number(self as f64))
+    }
+}
+
+impl FromJs for f32 {
+    fn from_js(runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self> {
+        f64::from_js(runtime, value).map(|number| number as f32)
+    }
+}
+
+/// Read an integral number, rejecting fractions, NaN and values outside of `min..=max`
+fn integer_from_js(value: &JSValue, type_name: &str, min: f64, max: f64) -> Result<f64> {
+    let number = value.try_as_number()?;
+    if number.fract() != 0.0 || !number.is_finite() {
+        return Err(Error::new(format!(
+            "expected an integer for {}, got {}",
+            type_name, number
+        )));
+    }
+
+    if number < min || number > max {
+        return Err(Error::new(format!(
+            "number {} is out of range for {}",
+            number, type_name
+        )));
+    }
+
+    Ok(number)
+}
+
+/// Integers which always fit into a JavaScript number
+macro_rules! impl_small_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoJs for $ty {
+                fn into_js(self, _runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+                    Ok(JSValue::number(self as f64))
+                }
+            }
+
+            impl FromJs for $ty {
+                fn from_js(_runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self> {
+                    let number = integer_from_js(
+                        value,
+                        stringify!($ty),
+                        <$ty>::MIN as f64,
+                        <$ty>::MAX as f64,
+                    )?;
+                    Ok(number as $ty)
+                }
+            }
+        )*
+    };
+}
+
+impl_small_integer!(i8, i16, i32, u8, u16, u32);
+
+/// Integers which are only exact up to `Number.MAX_SAFE_INTEGER`
+macro_rules! impl_large_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoJs for $ty {
+                fn into_js(self, _runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+                    let number = self as f64;
+                    if number.abs() > MAX_SAFE_INTEGER {
+                        return Err(Error::new(format!(
+                            "{} {} cannot be represented exactly as a JavaScript number",
+                            stringify!($ty),
+                            self
+                        )));
+                    }
+
+                    Ok(JSValue::number(number))
+                }
+            }
+
+            impl FromJs for $ty {
+                fn from_js(_runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self> {
+                    let min = (<$ty>::MIN as f64).max(-MAX_SAFE_INTEGER);
+                    let number = integer_from_js(value, stringify!($ty), min, MAX_SAFE_INTEGER)?;
+                    Ok(number as $ty)
+                }
+            }
+        )*
+    };
+}
+
+impl_large_integer!(i64, u64, isize, usize);
+
+/// 128-bit integers are converted to `BigInt`, reading also accepts integral numbers
+macro_rules! impl_bigint_integer {
+    ($($ty:ty => $from:ident, $as:ident),*) => {
+        $(
+            impl IntoJs for $ty {
+                fn into_js(self, runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+                    JSBigInt::$from(runtime, self).map(JSValue::from)
+                }
+            }
+
+            impl FromJs for $ty {
+                fn from_js(runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self> {
+                    if value.is_number() {
+                        let min = (<$ty>::MIN as f64).max(-MAX_SAFE_INTEGER);
+                        let number =
+                            integer_from_js(value, stringify!($ty), min, MAX_SAFE_INTEGER)?;
+                        return Ok(number as $ty);
+                    }
+
+                    let bigint = value
+                        .as_bigint(runtime)
+                        .ok_or_else(|| type_error("bigint", value))?;
+                    bigint.$as(runtime).ok_or_else(|| {
+                        Error::new(format!("bigint is out of range for {}", stringify!($ty)))
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_bigint_integer!(i128 => from_i128, as_i128, u128 => from_u128, as_u128);
+
+#[cfg(feature = "num-bigint")]
+impl IntoJs for num_bigint::BigInt {
+    fn into_js(self, runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+        JSBigInt::from_num_bigint(runtime, &self).map(JSValue::from)
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl FromJs for num_bigint::BigInt {
+    fn from_js(runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self> {
+        match value.as_bigint(runtime) {
+            Some(bigint) => bigint.to_num_bigint(runtime),
+            None => Err(type_error("bigint", value)),
+        }
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl IntoJs for num_bigint::BigUint {
+    fn into_js(self, runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+        num_bigint::BigInt::from(self).into_js(runtime)
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl FromJs for num_bigint::BigUint {
+    fn from_js(runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self> {
+        num_bigint::BigInt::from_js(runtime, value)?
+            .to_biguint()
+            .ok_or_else(|| Error::new("expected a non-negative bigint"))
+    }
+}
+
+impl IntoJs for &str {
+    fn into_js(self, runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+        let string = JSString::new(runtime, self);
+        Ok(JSValue::from(string))
+    }
+}
+
+impl IntoJs for String {
+    fn into_js(self, runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+        self.as_str().into_js(runtime)
+    }
+}
+
+impl FromJs for String {
+    fn from_js(runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self> {
+        match value.as_string(runtime) {
+            Some(string) => Ok(string.value(runtime)),
+            None => Err(type_error("string", value)),
+        }
+    }
+}
+
+impl IntoJs for () {
+    fn into_js(self, _runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+        Ok(JSValue::undefined())
+    }
+}
+
+impl FromJs for () {
+    fn from_js(_runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self> {
+        if !value.is_undefined() && !value.is_null() {
+            return Err(type_error("undefined", value));
+        }
+
+        Ok(())
+    }
+}
+
+/// `None` is converted to `null`, both `null` and `undefined` are read as `None`
+impl<T: IntoJs> IntoJs for Option<T> {
+    fn into_js(self, runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+        match self {
+            Some(value) => value.into_js(runtime),
+            None => Ok(JSValue::null()),
+        }
+    }
+}
+
+impl<T: FromJs> FromJs for Option<T> {
+    fn from_js(runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self> {
+        if value.is_undefined() || value.is_null() {
+            return Ok(None);
+        }
+
+        T::from_js(runtime, value).map(Some)
+    }
+}
+
+impl<T: IntoJs> IntoJs for Vec<T> {
+    fn into_js(self, runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+        let array = JSArray::new(runtime, self.len());
+        for (index, item) in self.into_iter().enumerate() {
+            let value = item.into_js(runtime)?;
+            array.set(runtime, index, &value)?;
+        }
+
+        Ok(JSValue::from(array))
+    }
+}
+
+impl<T: FromJs> FromJs for Vec<T> {
+    fn from_js(runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self> {
+        let array = value
+            .as_array(runtime)
+            .ok_or_else(|| type_error("array", value))?;
+
+        let len = array.len(runtime);
+        let mut result = Vec::with_capacity(len);
+        for index in 0..len {
+            let item = array.get(runtime, index);
+            let item = T::from_js(runtime, &item)
+                .map_err(|e| nested_error(format_args!("[{}]", index), e))?;
+            result.push(item);
+        }
+
+        Ok(result)
+    }
+}
+
+/// Maps are converted to and from plain objects keyed by their own enumerable properties
+impl<T: IntoJs, S: BuildHasher> IntoJs for HashMap<String, T, S> {
+    fn into_js(self, runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+        let object = JSObject::new(runtime);
+        for (key, item) in self {
+            let value = item.into_js(runtime)?;
+            object.set(runtime, &key, &value);
+        }
+
+        Ok(JSValue::from(object))
+    }
+}
+
+impl<T: FromJs, S: BuildHasher + Default> FromJs for HashMap<String, T, S> {
+    fn from_js(runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self> {
+        let object = value
+            .as_object(runtime)
+            .ok_or_else(|| type_error("object", value))?;
+        let entries = object.entries(runtime)?;
+
+        let mut result = HashMap::with_capacity_and_hasher(entries.len(), S::default());
+        for (key, item) in entries {
+            let item =
+                T::from_js(runtime, &item).map_err(|e| nested_error(format_args!("{}", key), e))?;
+            result.insert(key, item);
+        }
+
+        Ok(result)
+    }
+}
+
+/// Tuples are converted to and from arrays of the same length
+macro_rules! impl_tuple {
+    ($len:expr => $($name:ident $index:tt),+) => {
+        impl<$($name: IntoJs),+> IntoJs for ($($name,)+) {
+            fn into_js(self, runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+                let array = JSArray::new(runtime, $len);
+                $(
+                    let value = self.$index.into_js(runtime)?;
+                    array.set(runtime, $index, &value)?;
+                )+
+
+                Ok(JSValue::from(array))
+            }
+        }
+
+        impl<$($name: FromJs),+> FromJs for ($($name,)+) {
+            fn from_js(runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self> {
+                let array =
+                    value.as_array(runtime).ok_or_else(|| type_error("array", value))?;
+
+                let len = array.len(runtime);
+                if len != $len {
+                    return Err(Error::new(format!(
+                        "expected array of length {}, got {}",
+                        $len, len
+                    )));
+                }
+
+                Ok(($(
+                    {
+                        let item = array.get(runtime, $index);
+                        $name::from_js(runtime, &item)
+                            .map_err(|e| nested_error(format_args!("[{}]", $index), e))?
+                    },
+                )+))
+            }
+        }
+    };
+}
+
+impl_tuple!(1 => A 0);
+impl_tuple!(2 => A 0, B 1);
+impl_tuple!(3 => A 0, B 1, C 2);
+impl_tuple!(4 => A 0, B 1, C 2, D 3);
+impl_tuple!(5 => A 0, B 1, C 2, D 3, E 4);
+impl_tuple!(6 => A 0, B 1, C 2, D 3, E 4, F 5);
+impl_tuple!(7 => A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_tuple!(8 => A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
@@ -0,0 +1,103 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::collections::{call_builtin, construct, has_brand};
+use crate::convert::type_error;
+use crate::{Error, FromJs, IntoJs, JSObject, JSRuntime, JSValue, Result};
+
+/// Wrapper around a JavaScript `Date`
+pub struct JSDate {
+    object: JSObject,
+}
+
+impl JSDate {
+    /// Create a `Date` from milliseconds since the Unix epoch
+    pub fn from_millis(runtime: &mut JSRuntime<'_>, millis: f64) -> Result<Self> {
+        let object = construct(runtime, "Date", &[JSValue::number(millis)])?;
+        Ok(Self { object })
+    }
+
+    /// Create a `Date` from a Rust timestamp, truncated to millisecond precision
+    pub fn from_system_time(runtime: &mut JSRuntime<'_>, time: SystemTime) -> Result<Self> {
+        let millis = match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => after.as_millis() as f64,
+            Err(before) => -(before.duration().as_millis() as f64),
+        };
+        Self::from_millis(runtime, millis)
+    }
+
+    /// Wrap `object` if it is a `Date`
+    pub fn from_object(runtime: &mut JSRuntime<'_>, object: JSObject) -> Option<Self> {
+        if !has_brand(runtime, &object, "Date.prototype.getTime") {
+            return None;
+        }
+        Some(Self { object })
+    }
+
+    /// Milliseconds since the Unix epoch (`Date.prototype.getTime`), NaN for invalid dates
+    pub fn millis(&self, runtime: &mut JSRuntime<'_>) -> Result<f64> {
+        call_builtin(runtime, "Date.prototype.getTime", &self.object, &[])?.try_as_number()
+    }
+
+    /// Convert to a Rust timestamp, fails for invalid dates
+    pub fn to_system_time(&self, runtime: &mut JSRuntime<'_>) -> Result<SystemTime> {
+        let millis = self.millis(runtime)?;
+        if !millis.is_finite() {
+            return Err(Error::new("invalid Date"));
+        }
+
+        let offset = Duration::from_millis(millis.abs() as u64);
+        if millis >= 0.0 {
+            Ok(UNIX_EPOCH + offset)
+        } else {
+            Ok(UNIX_EPOCH - offset)
+        }
+    }
+
+    pub fn as_object(&self) -> &JSObject {
+        &self.object
+    }
+}
+
+impl From<JSDate> for JSValue {
+    fn from(date: JSDate) -> Self {
+        JSValue::from(date.object)
+    }
+}
+
+impl IntoJs for SystemTime {
+    fn into_js(self, runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+        JSDate::from_system_time(runtime, self).map(JSValue::from)
+    }
+}
+
+impl FromJs for SystemTime {
+    fn from_js(runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self> {
+        let date = value
+            .as_date(runtime)
+            .ok_or_else(|| type_error("Date", value))?;
+        date.to_system_time(runtime)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl IntoJs for chrono::DateTime<chrono::Utc> {
+    fn into_js(self, runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+        JSDate::from_millis(runtime, self.timestamp_millis() as f64).map(JSValue::from)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromJs for chrono::DateTime<chrono::Utc> {
+    fn from_js(runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self> {
+        let date = value
+            .as_date(runtime)
+            .ok_or_else(|| type_error("Date", value))?;
+        let millis = date.millis(runtime)?;
+        if !millis.is_finite() {
+            return Err(Error::new("invalid Date"));
+        }
+
+        chrono::DateTime::from_timestamp_millis(millis as i64)
+            .ok_or_else(|| Error::new(format!("Date {} is out of range", millis)))
+    }
+}
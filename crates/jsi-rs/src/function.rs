@@ -1,24 +1,94 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::Arc;
+
 use crate::sys::ffi;
 use crate::value::JSValue;
 use crate::JSRuntime;
-use crate::Result;
+use crate::{Error, Result, RuntimeState};
+
+type HostCallback = dyn Fn(&mut JSRuntime<'_>, &JSValue, &[JSValue]) -> Result<JSValue>;
+
+/// Rust closure backing a JavaScript host function, owned by the C++ side
+pub struct HostFunction {
+    callback: Box<HostCallback>,
+    state: Option<Arc<RuntimeState>>,
+}
+
+pub(crate) fn call_host_function(
+    func: &HostFunction,
+    runtime: Pin<&mut ffi::JSIRuntime>,
+    this_val: cxx::UniquePtr<ffi::JSIValue>,
+    mut args: Pin<&mut ffi::ValueVec>,
+) -> Result<cxx::UniquePtr<ffi::JSIValue>> {
+    let ptr = unsafe { runtime.get_unchecked_mut() };
+    let mut runtime = match &func.state {
+        Some(state) => unsafe { JSRuntime::from_raw_with_state(ptr, state.clone()) },
+        None => unsafe { JSRuntime::from_raw(ptr) },
+    };
+    let this = JSValue {
+        inner: runtime.handle(this_val),
+    };
+    let args: Vec<JSValue> = (0..ffi::value_vec_size(&args))
+        .map(|index| JSValue {
+            inner: runtime.handle(ffi::value_vec_take(args.as_mut(), index)),
+        })
+        .collect();
+
+    // Unwinding into C++ is not allowed, report panics as JS errors instead
+    match panic::catch_unwind(AssertUnwindSafe(|| {
+        let value = (func.callback)(&mut runtime, &this, &args)?;
+        value.inner.check(&runtime);
+        Ok(value.inner.into_inner())
+    })) {
+        Ok(result) => result,
+        Err(_) => Err(Error::new("host function panicked")),
+    }
+}
 
 /// Wrapper around facebook::jsi::Function providing a safe Rust API
 pub struct JSFunction {
-    pub(crate) inner: cxx::UniquePtr<ffi::JSIFunction>,
+    pub(crate) inner: crate::handle::Handle<ffi::JSIFunction>,
 }
 
 impl JSFunction {
+    /// Create a function backed by a Rust closure
+    ///
+    /// The closure receives the runtime, the `this` value and the call arguments.
+    /// Returning an error throws a JavaScript `Error` with the error message.
+    pub fn from_host_fn<F>(
+        runtime: &mut JSRuntime<'_>,
+        name: &str,
+        param_count: u32,
+        func: F,
+    ) -> Self
+    where
+        F: Fn(&mut JSRuntime<'_>, &JSValue, &[JSValue]) -> Result<JSValue> + 'static,
+    {
+        let host = Box::new(HostFunction {
+            callback: Box::new(func),
+            state: runtime.state().cloned(),
+        });
+        let inner = ffi::create_host_function(runtime.pin_mut(), name, param_count, host);
+        Self {
+            inner: runtime.handle(inner),
+        }
+    }
+
     /// Call the function with the given arguments
     pub fn call(&self, runtime: &mut JSRuntime<'_>, args: &[JSValue]) -> Result<JSValue> {
+        self.inner.check(runtime);
         let mut vec = ffi::value_vec_create();
         for arg in args {
+            arg.inner.check(runtime);
             ffi::value_vec_push(vec.pin_mut(), runtime.pin_mut(), arg.inner());
         }
 
         let result = ffi::function_call(runtime.pin_mut(), &self.inner, &vec)?;
 
-        Ok(JSValue { inner: result })
+        Ok(JSValue {
+            inner: runtime.handle(result),
+        })
     }
 
     /// Call the function with explicit 'this' object
@@ -28,8 +98,11 @@ impl JSFunction {
         this_obj: &crate::JSObject,
         args: &[JSValue],
     ) -> Result<JSValue> {
+        self.inner.check(runtime);
+        this_obj.inner.check(runtime);
         let mut vec = ffi::value_vec_create();
         for arg in args {
+            arg.inner.check(runtime);
             ffi::value_vec_push(vec.pin_mut(), runtime.pin_mut(), arg.inner());
         }
 
@@ -40,7 +113,9 @@ impl JSFunction {
             &vec,
         )?;
 
-        Ok(JSValue { inner: result })
+        Ok(JSValue {
+            inner: runtime.handle(result),
+        })
     }
 
     /// Call the function as a constructor (using 'new')
@@ -49,14 +124,27 @@ impl JSFunction {
         runtime: &mut JSRuntime<'_>,
         args: &[JSValue],
     ) -> Result<JSValue> {
+        self.inner.check(runtime);
         let mut vec = ffi::value_vec_create();
         for arg in args {
+            arg.inner.check(runtime);
             ffi::value_vec_push(vec.pin_mut(), runtime.pin_mut(), arg.inner());
         }
 
         let result = ffi::function_call_as_constructor(runtime.pin_mut(), &self.inner, &vec)?;
 
-        Ok(JSValue { inner: result })
+        Ok(JSValue {
+            inner: runtime.handle(result),
+        })
+    }
+
+    /// Create another handle to the same function
+    pub fn clone_in(&self, runtime: &mut JSRuntime<'_>) -> JSFunction {
+        self.inner.check(runtime);
+        let inner = ffi::function_copy(runtime.pin_mut(), &self.inner);
+        JSFunction {
+            inner: runtime.handle(inner),
+        }
     }
 
     /// Access the inner UniquePtr for advanced usage
@@ -65,3 +153,11 @@ impl JSFunction {
         &self.inner
     }
 }
+
+impl From<JSFunction> for crate::JSValue {
+    fn from(value: JSFunction) -> Self {
+        crate::JSValue {
+            inner: value.inner.map(crate::sys::ffi::value_from_function),
+        }
+    }
+}
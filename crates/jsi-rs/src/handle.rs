@@ -0,0 +1,224 @@
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Weak;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, RwLock};
+
+use cxx::memory::UniquePtrTarget;
+use cxx::UniquePtr;
+
+use crate::JSRuntime;
+
+static NEXT_RUNTIME_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Persistent handle storage emptied when its runtime shuts down
+pub(crate) trait Release {
+    fn release(&self);
+}
+
+thread_local! {
+    /// Persistent handles registered on this thread, by runtime id
+    ///
+    /// Persistent handles can't leave the thread they were created on, neither can the
+    /// registry. A runtime invalidated on another thread doesn't release them, they are
+    /// leaked on drop like other handles outliving their runtime.
+    static PERSISTENTS: RefCell<HashMap<u64, Vec<Weak<dyn Release>>>> = RefCell::new(HashMap::new());
+}
+
+/// Handles kept for the lifetime of a runtime, see [`JSRuntime::cached`]
+#[derive(Default)]
+pub(crate) struct Cache(HashMap<(TypeId, &'static str), Box<dyn Any>>);
+
+// SAFETY: the cache only holds JSI handles, which are created, duplicated and dropped with
+// exclusive access to their runtime (`&mut JSRuntime`), never shared with callers, and
+// dropped by `RuntimeState::invalidate` on the runtime's thread
+unsafe impl Send for Cache {}
+
+impl Cache {
+    pub(crate) fn get<T: 'static>(&self, key: &'static str) -> Option<&T> {
+        self.0.get(&(TypeId::of::<T>(), key))?.downcast_ref()
+    }
+
+    pub(crate) fn insert<T: 'static>(&mut self, key: &'static str, value: T) {
+        self.0.insert((TypeId::of::<T>(), key), Box::new(value));
+    }
+}
+
+/// Identity and liveness of a runtime, shared with every handle created from it
+///
+/// Embedders create one per runtime, attach it with [`JSRuntime::from_raw_with_state`] and call
+/// [`RuntimeState::invalidate`] before destroying the runtime. Handles checked against a
+/// different runtime panic, handles dropped after invalidation are leaked instead of
+/// touching freed runtime memory.
+pub struct RuntimeState {
+    id: u64,
+    alive: AtomicBool,
+    cache: Mutex<Cache>,
+    /// Set for runtimes shared between threads, see [`RuntimeState::new_shared`]
+    shutdown: Option<RwLock<()>>,
+}
+
+impl RuntimeState {
+    pub fn new() -> Arc<Self> {
+        Self::with_shutdown(None)
+    }
+
+    /// State for a runtime driven from several threads, e.g. behind a lock
+    ///
+    /// Handles may then be dropped on one thread while another destroys the runtime, so
+    /// dropping a handle holds off [`RuntimeState::invalidate`] until it is done. Persistent
+    /// handles aren't released on shutdown since they may live on any thread, ones outliving
+    /// the runtime are leaked like other handles.
+    pub fn new_shared() -> Arc<Self> {
+        Self::with_shutdown(Some(RwLock::new(())))
+    }
+
+    fn with_shutdown(shutdown: Option<RwLock<()>>) -> Arc<Self> {
+        Arc::new(Self {
+            id: NEXT_RUNTIME_ID.fetch_add(1, Ordering::Relaxed),
+            alive: AtomicBool::new(true),
+            cache: Mutex::new(Cache::default()),
+            shutdown,
+        })
+    }
+
+    /// Unique identifier of the runtime within the process
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.alive.load(Ordering::Acquire)
+    }
+
+    /// Release persistent and cached handles and mark the runtime as destroyed
+    ///
+    /// Must be called on the runtime's thread while it is still alive, right before it is deleted.
+    /// Only persistent handles created on that thread are released.
+    pub fn invalidate(&self) {
+        let persistents = PERSISTENTS
+            .try_with(|persistents| persistents.borrow_mut().remove(&self.id))
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        for persistent in persistents.iter().filter_map(Weak::upgrade) {
+            persistent.release();
+        }
+        drop(std::mem::take(&mut *self.cache()));
+
+        let _shutdown = self
+            .shutdown
+            .as_ref()
+            .map(|lock| lock.write().unwrap_or_else(PoisonError::into_inner));
+        self.alive.store(false, Ordering::Release);
+    }
+
+    pub(crate) fn register(&self, persistent: Weak<dyn Release>) {
+        if self.shutdown.is_some() {
+            return;
+        }
+
+        PERSISTENTS.with(|persistents| {
+            let mut persistents = persistents.borrow_mut();
+            if !persistents.contains_key(&self.id) {
+                // Forget runtimes whose handles are all gone, e.g. ones invalidated elsewhere
+                persistents
+                    .retain(|_, list| list.iter().any(|persistent| persistent.strong_count() > 0));
+            }
+
+            let list = persistents.entry(self.id).or_default();
+            // Forget dropped handles before growing, keeps the list proportional to live handles
+            if list.len() == list.capacity() {
+                list.retain(|persistent| persistent.strong_count() > 0);
+            }
+            list.push(persistent);
+        });
+    }
+
+    pub(crate) fn cache(&self) -> MutexGuard<'_, Cache> {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl fmt::Debug for RuntimeState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RuntimeState")
+            .field("id", &self.id)
+            .field("alive", &self.is_alive())
+            .finish()
+    }
+}
+
+/// Owned JSI object together with the runtime it belongs to
+///
+/// Values that don't reference runtime memory (undefined, null, booleans and numbers) and
+/// handles created through a runtime without [`RuntimeState`] are detached and never checked.
+pub(crate) struct Handle<T: UniquePtrTarget> {
+    ptr: UniquePtr<T>,
+    state: Option<Arc<RuntimeState>>,
+}
+
+impl<T: UniquePtrTarget> Handle<T> {
+    pub(crate) fn new(ptr: UniquePtr<T>, state: Option<Arc<RuntimeState>>) -> Self {
+        Self { ptr, state }
+    }
+
+    pub(crate) fn detached(ptr: UniquePtr<T>) -> Self {
+        Self { ptr, state: None }
+    }
+
+    /// Convert the pointer, e.g. wrap an object into a value, keeping the runtime it belongs to
+    pub(crate) fn map<U: UniquePtrTarget>(
+        mut self,
+        f: impl FnOnce(UniquePtr<T>) -> UniquePtr<U>,
+    ) -> Handle<U> {
+        let state = self.state.take();
+        Handle {
+            ptr: f(std::mem::replace(&mut self.ptr, UniquePtr::null())),
+            state,
+        }
+    }
+
+    pub(crate) fn into_inner(mut self) -> UniquePtr<T> {
+        std::mem::replace(&mut self.ptr, UniquePtr::null())
+    }
+
+    /// Panic if the handle belongs to a different runtime than `runtime`
+    pub(crate) fn check(&self, runtime: &JSRuntime<'_>) {
+        if let (Some(state), Some(runtime)) = (&self.state, runtime.state()) {
+            assert!(
+                state.id == runtime.id,
+                "JSI handle from runtime #{} used with runtime #{}",
+                state.id,
+                runtime.id
+            );
+        }
+    }
+}
+
+impl<T: UniquePtrTarget> Deref for Handle<T> {
+    type Target = UniquePtr<T>;
+
+    fn deref(&self) -> &UniquePtr<T> {
+        &self.ptr
+    }
+}
+
+impl<T: UniquePtrTarget> Drop for Handle<T> {
+    fn drop(&mut self) {
+        if let Some(state) = &self.state {
+            let _shutdown = state
+                .shutdown
+                .as_ref()
+                .map(|lock| lock.read().unwrap_or_else(PoisonError::into_inner));
+            let ptr = std::mem::replace(&mut self.ptr, UniquePtr::null());
+            if !state.is_alive() {
+                // The runtime is gone, running the destructor would touch freed memory
+                let _ = ptr.into_raw();
+            }
+        }
+    }
+}
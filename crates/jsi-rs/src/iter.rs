@@ -0,0 +1,103 @@
+use crate::convert::type_error;
+use crate::{Error, JSObject, JSPropNameID, JSRuntime, JSValue, Result};
+
+fn property(runtime: &mut JSRuntime<'_>, object: &JSObject, name: &str) -> Result<JSValue> {
+    let name = JSPropNameID::new(runtime, name);
+    object.get_with_id(runtime, &name)
+}
+
+/// JavaScript truthiness of an iterator result's `done` flag
+fn is_truthy(runtime: &mut JSRuntime<'_>, value: &JSValue) -> bool {
+    if let Some(value) = value.as_bool() {
+        return value;
+    }
+    if let Some(number) = value.as_number() {
+        return number != 0.0 && !number.is_nan();
+    }
+    if let Some(string) = value.as_string(runtime) {
+        return !string.value(runtime).is_empty();
+    }
+    if let Some(bigint) = value.as_bigint(runtime) {
+        return bigint.as_i64(runtime) != Some(0);
+    }
+
+    !value.is_undefined() && !value.is_null()
+}
+
+/// Look up `iterable[Symbol.iterator]` and call it, strings are boxed like `for...of` does
+fn get_iterator(runtime: &mut JSRuntime<'_>, iterable: &JSValue) -> Result<JSObject> {
+    let iterable = if iterable.is_string() {
+        let object = runtime.cached("Object", |runtime| runtime.global_function("Object"))?;
+        let string = iterable.clone_in(runtime);
+        object.call(runtime, &[string])?
+    } else {
+        iterable.clone_in(runtime)
+    };
+
+    let object = iterable
+        .as_object(runtime)
+        .ok_or_else(|| type_error("iterable", &iterable))?;
+
+    // Cached per runtime, scripts replacing `Symbol` later don't change the behavior
+    let symbol_iterator = runtime.cached::<JSValue>("Symbol.iterator", |runtime| {
+        let global = runtime.global();
+        let symbol = property(runtime, &global, "Symbol")?
+            .as_object(runtime)
+            .ok_or_else(|| Error::new("Symbol is not available"))?;
+        property(runtime, &symbol, "iterator")
+    })?;
+
+    let method = crate::sys::ffi::object_get_property_with_value(
+        runtime.pin_mut(),
+        &object.inner,
+        symbol_iterator.inner(),
+    )?;
+    let method = JSValue {
+        inner: runtime.handle(method),
+    }
+    .as_function(runtime)
+    .ok_or_else(|| Error::new("value is not iterable"))?;
+
+    method
+        .call_with_this(runtime, &object, &[])?
+        .as_object(runtime)
+        .ok_or_else(|| Error::new("Symbol.iterator did not return an object"))
+}
+
+/// Consume any JavaScript iterable (arrays, strings, `Map`, `Set`, generators, ...)
+/// like a `for...of` loop, calling `f` with every produced value
+///
+/// When `f` fails the iterator is closed through its `return` method and the error
+/// is passed on, matching an exception thrown out of a `for...of` body.
+pub fn for_of<F>(runtime: &mut JSRuntime<'_>, iterable: &JSValue, mut f: F) -> Result<()>
+where
+    F: FnMut(&mut JSRuntime<'_>, JSValue) -> Result<()>,
+{
+    let iterator = get_iterator(runtime, iterable)?;
+    let next = property(runtime, &iterator, "next")?
+        .as_function(runtime)
+        .ok_or_else(|| Error::new("iterator.next is not a function"))?;
+
+    loop {
+        let result = next
+            .call_with_this(runtime, &iterator, &[])?
+            .as_object(runtime)
+            .ok_or_else(|| Error::new("iterator result is not an object"))?;
+
+        let done = property(runtime, &result, "done")?;
+        if is_truthy(runtime, &done) {
+            return Ok(());
+        }
+
+        let value = property(runtime, &result, "value")?;
+        if let Err(err) = f(runtime, value) {
+            // Errors from closing the iterator are dropped in favour of the original one
+            if let Ok(close) = property(runtime, &iterator, "return") {
+                if let Some(close) = close.as_function(runtime) {
+                    let _ = close.call_with_this(runtime, &iterator, &[]);
+                }
+            }
+            return Err(err);
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::{Error, FromJs, IntoJs, JSRuntime, JSValue, Result};
+
+/// Converted through JSON text so the result matches `JSON.parse`
+impl IntoJs for serde_json::Value {
+    fn into_js(self, runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+        let text = serde_json::to_string(&self).map_err(|e| Error::new(e.to_string()))?;
+        runtime.json_parse(&text)
+    }
+}
+
+/// Converted through JSON text, so `toJSON` is honored and values like functions are
+/// dropped from objects exactly as `JSON.stringify` does
+impl FromJs for serde_json::Value {
+    fn from_js(runtime: &mut JSRuntime<'_>, value: &JSValue) -> Result<Self> {
+        let text = runtime.json_stringify(value, None)?.ok_or_else(|| {
+            Error::new(format!("{} has no JSON representation", value.type_name()))
+        })?;
+        serde_json::from_str(&text).map_err(|e| Error::new(e.to_string()))
+    }
+}
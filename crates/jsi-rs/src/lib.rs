@@ -9,20 +9,44 @@ pub mod sys;
 // Public API modules
 mod array;
 mod bigint;
+mod collections;
+mod convert;
+mod date;
 mod function;
+mod handle;
+mod iter;
+#[cfg(feature = "serde_json")]
+mod json;
 mod object;
+mod persistent;
 mod propnameid;
 mod runtime;
+mod scope;
 mod string;
 mod value;
 
+#[doc(hidden)]
+#[path = "private.rs"]
+pub mod __private;
+
 // Re-export public types
-pub use array::JSArray;
+pub use array::{ArrayIter, JSArray};
 pub use bigint::JSBigInt;
+pub use collections::{JSMap, JSSet};
+pub use convert::{FromJs, IntoJs};
+pub use date::JSDate;
 pub use error::{Error, Result};
 pub use function::JSFunction;
-pub use object::JSObject;
+pub use handle::RuntimeState;
+pub use iter::for_of;
+pub use object::{JSObject, PropertyDescriptor};
+pub use persistent::{Persistent, PersistentValue};
 pub use propnameid::JSPropNameID;
 pub use runtime::{IntoJSIBigInt, JSRuntime};
-pub use string::JSString;
-pub use value::JSValue;
+pub use scope::{Local, Scope, ScopeOutput};
+pub use string::{JSString, StringChunk};
+pub use value::{JSValue, ValueKind};
+
+// Derive and attribute macros
+#[cfg(feature = "derive")]
+pub use jsi_rs_macros::{js_class, js_method, FromJs, IntoJs};
@@ -1,42 +1,354 @@
-use crate::{JSArray, JSRuntime, JSValue};
+use crate::{JSArray, JSFunction, JSPropNameID, JSRuntime, JSValue, Result};
+
+/// Attributes for [`JSObject::define_property`], mirroring a JavaScript property descriptor
+///
+/// Attributes that are not set are left out of the descriptor, so `Object.defineProperty`
+/// applies its defaults (`false` for the flags).
+#[derive(Default)]
+pub struct PropertyDescriptor {
+    value: Option<JSValue>,
+    getter: Option<JSFunction>,
+    setter: Option<JSFunction>,
+    writable: Option<bool>,
+    enumerable: Option<bool>,
+    configurable: Option<bool>,
+}
+
+impl PropertyDescriptor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Define a data property holding `value`
+    pub fn value(mut self, value: JSValue) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Define an accessor property read through `getter`
+    pub fn getter(mut self, getter: JSFunction) -> Self {
+        self.getter = Some(getter);
+        self
+    }
+
+    /// Define an accessor property written through `setter`
+    pub fn setter(mut self, setter: JSFunction) -> Self {
+        self.setter = Some(setter);
+        self
+    }
+
+    pub fn writable(mut self, writable: bool) -> Self {
+        self.writable = Some(writable);
+        self
+    }
+
+    pub fn enumerable(mut self, enumerable: bool) -> Self {
+        self.enumerable = Some(enumerable);
+        self
+    }
+
+    pub fn configurable(mut self, configurable: bool) -> Self {
+        self.configurable = Some(configurable);
+        self
+    }
+
+    fn into_object(self, runtime: &mut JSRuntime<'_>) -> JSObject {
+        let object = JSObject::new(runtime);
+
+        if let Some(value) = self.value {
+            object.set(runtime, "value", &value);
+        }
+        if let Some(getter) = self.getter {
+            object.set(runtime, "get", &JSValue::from(getter));
+        }
+        if let Some(setter) = self.setter {
+            object.set(runtime, "set", &JSValue::from(setter));
+        }
+
+        let flags = [
+            ("writable", self.writable),
+            ("enumerable", self.enumerable),
+            ("configurable", self.configurable),
+        ];
+        for (name, flag) in flags {
+            if let Some(flag) = flag {
+                object.set(runtime, name, &JSValue::bool(flag));
+            }
+        }
+
+        object
+    }
+}
 
 /// Wrapper around facebook::jsi::Object providing a safe Rust API
 pub struct JSObject {
-    pub(crate) inner: cxx::UniquePtr<crate::sys::ffi::JSIObject>,
+    pub(crate) inner: crate::handle::Handle<crate::sys::ffi::JSIObject>,
 }
 
 impl JSObject {
     /// Create a new empty JavaScript object
     pub fn new(runtime: &mut JSRuntime<'_>) -> Self {
         let ptr = crate::sys::ffi::create_object(runtime.pin_mut());
-        Self { inner: ptr }
+        Self {
+            inner: runtime.handle(ptr),
+        }
     }
 
     /// Get a property value by name
     pub fn get(&self, runtime: &mut JSRuntime<'_>, name: &str) -> JSValue {
+        self.inner.check(runtime);
         let value = crate::sys::ffi::object_get_property(runtime.pin_mut(), &self.inner, name);
-        JSValue { inner: value }
+        JSValue {
+            inner: runtime.handle(value),
+        }
     }
 
     /// Set a property value by name
     pub fn set(&self, runtime: &mut JSRuntime<'_>, name: &str, value: &JSValue) {
+        self.inner.check(runtime);
+        value.inner.check(runtime);
         crate::sys::ffi::object_set_property(runtime.pin_mut(), &self.inner, name, value.inner());
     }
 
     /// Check if the object has a property with the given name
     pub fn has(&self, runtime: &mut JSRuntime<'_>, name: &str) -> bool {
+        self.inner.check(runtime);
         crate::sys::ffi::object_has_property(runtime.pin_mut(), &self.inner, name)
     }
 
-    /// Delete a property by name (sets it to undefined)
-    pub fn delete(&self, runtime: &mut JSRuntime<'_>, name: &str) {
-        crate::sys::ffi::object_delete_property(runtime.pin_mut(), &self.inner, name);
+    /// Delete a property by name
+    ///
+    /// Fails when the property can't be deleted, e.g. because it is non-configurable.
+    /// Deleting a missing property succeeds.
+    pub fn delete(&self, runtime: &mut JSRuntime<'_>, name: &str) -> Result<()> {
+        self.inner.check(runtime);
+        Ok(crate::sys::ffi::object_delete_property(
+            runtime.pin_mut(),
+            &self.inner,
+            name,
+        )?)
+    }
+
+    /// Delete a property by an interned name, see [`JSObject::delete`]
+    pub fn delete_with_id(&self, runtime: &mut JSRuntime<'_>, name: &JSPropNameID) -> Result<()> {
+        self.inner.check(runtime);
+        name.inner.check(runtime);
+        Ok(crate::sys::ffi::object_delete_property_with_id(
+            runtime.pin_mut(),
+            &self.inner,
+            &name.inner,
+        )?)
+    }
+
+    /// Get a property value by an interned name
+    pub fn get_with_id(&self, runtime: &mut JSRuntime<'_>, name: &JSPropNameID) -> Result<JSValue> {
+        self.inner.check(runtime);
+        name.inner.check(runtime);
+        let value = crate::sys::ffi::object_get_property_with_id(
+            runtime.pin_mut(),
+            &self.inner,
+            &name.inner,
+        )?;
+        Ok(JSValue {
+            inner: runtime.handle(value),
+        })
+    }
+
+    /// Set a property value by an interned name
+    pub fn set_with_id(
+        &self,
+        runtime: &mut JSRuntime<'_>,
+        name: &JSPropNameID,
+        value: &JSValue,
+    ) -> Result<()> {
+        self.inner.check(runtime);
+        name.inner.check(runtime);
+        value.inner.check(runtime);
+        crate::sys::ffi::object_set_property_with_id(
+            runtime.pin_mut(),
+            &self.inner,
+            &name.inner,
+            value.inner(),
+        )?;
+        Ok(())
+    }
+
+    /// Check if the object has a property with the given interned name
+    pub fn has_with_id(&self, runtime: &mut JSRuntime<'_>, name: &JSPropNameID) -> Result<bool> {
+        self.inner.check(runtime);
+        name.inner.check(runtime);
+        Ok(crate::sys::ffi::object_has_property_with_id(
+            runtime.pin_mut(),
+            &self.inner,
+            &name.inner,
+        )?)
+    }
+
+    /// Define or modify a property like `Object.defineProperty`
+    pub fn define_property(
+        &self,
+        runtime: &mut JSRuntime<'_>,
+        name: &str,
+        descriptor: PropertyDescriptor,
+    ) -> Result<()> {
+        self.inner.check(runtime);
+        // Cached per runtime, scripts replacing it later don't change the behavior
+        let define = runtime.cached("Object.defineProperty", |runtime| {
+            let global = runtime.global();
+            let name = JSPropNameID::new(runtime, "Object");
+            let object = global.get_with_id(runtime, &name)?.as_object(runtime);
+            let name = JSPropNameID::new(runtime, "defineProperty");
+            let define = match object {
+                Some(object) => object.get_with_id(runtime, &name)?.as_function(runtime),
+                None => None,
+            };
+            define.ok_or_else(|| crate::Error::new("Object.defineProperty is not available"))
+        })?;
+
+        let target = self.to_value(runtime);
+        let name = JSValue::from(crate::JSString::new(runtime, name));
+        let descriptor = JSValue::from(descriptor.into_object(runtime));
+        define.call(runtime, &[target, name, descriptor])?;
+        Ok(())
+    }
+
+    /// Get the prototype of the object, `null` for objects without one
+    pub fn get_prototype(&self, runtime: &mut JSRuntime<'_>) -> Result<JSValue> {
+        self.inner.check(runtime);
+        let value = crate::sys::ffi::object_get_prototype(runtime.pin_mut(), &self.inner)?;
+        Ok(JSValue {
+            inner: runtime.handle(value),
+        })
     }
 
-    /// Get an array of all property names on this object
+    /// Set the prototype of the object to another object or `null`
+    pub fn set_prototype(&self, runtime: &mut JSRuntime<'_>, prototype: &JSValue) -> Result<()> {
+        self.inner.check(runtime);
+        prototype.inner.check(runtime);
+        crate::sys::ffi::object_set_prototype(runtime.pin_mut(), &self.inner, prototype.inner())?;
+        Ok(())
+    }
+
+    /// Evaluate `object instanceof ctor`
+    pub fn instance_of(&self, runtime: &mut JSRuntime<'_>, ctor: &JSFunction) -> Result<bool> {
+        self.inner.check(runtime);
+        ctor.inner.check(runtime);
+        Ok(crate::sys::ffi::object_instance_of(
+            runtime.pin_mut(),
+            &self.inner,
+            &ctor.inner,
+        )?)
+    }
+
+    /// Create another handle to the same object
+    pub fn clone_in(&self, runtime: &mut JSRuntime<'_>) -> JSObject {
+        self.inner.check(runtime);
+        let inner = crate::sys::ffi::object_copy(runtime.pin_mut(), &self.inner);
+        JSObject {
+            inner: runtime.handle(inner),
+        }
+    }
+
+    /// Create a value referring to the same object
+    pub(crate) fn to_value(&self, runtime: &mut JSRuntime<'_>) -> JSValue {
+        self.inner.check(runtime);
+        let value = crate::sys::ffi::object_to_value(runtime.pin_mut(), &self.inner);
+        JSValue {
+            inner: runtime.handle(value),
+        }
+    }
+
+    /// Get the names of the own enumerable string-keyed properties
+    pub fn keys(&self, runtime: &mut JSRuntime<'_>) -> Result<Vec<String>> {
+        self.inner.check(runtime);
+        let names = crate::sys::ffi::object_get_own_property_names(runtime.pin_mut(), &self.inner)?;
+        let names = JSArray {
+            inner: runtime.handle(names),
+        };
+        let len = names.len(runtime);
+
+        let mut keys = Vec::with_capacity(len);
+        for index in 0..len {
+            let name = names.get(runtime, index);
+            if let Some(name) = name.as_string(runtime) {
+                keys.push(name.value(runtime));
+            }
+        }
+        Ok(keys)
+    }
+
+    /// Get the own enumerable string-keyed properties as `(name, value)` pairs
+    pub fn entries(&self, runtime: &mut JSRuntime<'_>) -> Result<Vec<(String, JSValue)>> {
+        self.inner.check(runtime);
+        let keys = self.keys(runtime)?;
+
+        let mut entries = Vec::with_capacity(keys.len());
+        for key in keys {
+            let name = JSPropNameID::new(runtime, &key);
+            let value = self.get_with_id(runtime, &name)?;
+            entries.push((key, value));
+        }
+        Ok(entries)
+    }
+
+    /// Get the names of the enumerable properties, including inherited ones, like `for...in`
     pub fn get_property_names(&self, runtime: &mut JSRuntime<'_>) -> JSArray {
+        self.inner.check(runtime);
         let names = crate::sys::ffi::object_get_property_names(runtime.pin_mut(), &self.inner);
-        JSArray { inner: names }
+        JSArray {
+            inner: runtime.handle(names),
+        }
+    }
+
+    /// Check if both handles refer to the same object (JavaScript `===`)
+    pub fn strict_equals(&self, runtime: &mut JSRuntime<'_>, other: &JSObject) -> bool {
+        self.inner.check(runtime);
+        other.inner.check(runtime);
+        crate::sys::ffi::object_strict_equals(runtime.pin_mut(), &self.inner, &other.inner)
+    }
+
+    /// Check if the object is an array
+    pub fn is_array(&self, runtime: &mut JSRuntime<'_>) -> bool {
+        self.inner.check(runtime);
+        crate::sys::ffi::object_is_array(runtime.pin_mut(), &self.inner)
+    }
+
+    /// Check if the object is callable
+    pub fn is_function(&self, runtime: &mut JSRuntime<'_>) -> bool {
+        self.inner.check(runtime);
+        crate::sys::ffi::object_is_function(runtime.pin_mut(), &self.inner)
+    }
+
+    /// Check if the object is an ArrayBuffer
+    pub fn is_array_buffer(&self, runtime: &mut JSRuntime<'_>) -> bool {
+        self.inner.check(runtime);
+        crate::sys::ffi::object_is_array_buffer(runtime.pin_mut(), &self.inner)
+    }
+
+    /// Returns the object as an array, `None` if it is not an array
+    pub fn as_array(&self, runtime: &mut JSRuntime<'_>) -> Option<JSArray> {
+        self.inner.check(runtime);
+        if !self.is_array(runtime) {
+            return None;
+        }
+
+        let array = crate::sys::ffi::object_as_array(runtime.pin_mut(), &self.inner);
+        Some(JSArray {
+            inner: runtime.handle(array),
+        })
+    }
+
+    /// Returns the object as a function, `None` if it is not callable
+    pub fn as_function(&self, runtime: &mut JSRuntime<'_>) -> Option<JSFunction> {
+        self.inner.check(runtime);
+        if !self.is_function(runtime) {
+            return None;
+        }
+
+        let func = crate::sys::ffi::object_as_function(runtime.pin_mut(), &self.inner);
+        Some(JSFunction {
+            inner: runtime.handle(func),
+        })
     }
 
     /// Access the inner UniquePtr for advanced usage
@@ -45,3 +357,11 @@ impl JSObject {
         &self.inner
     }
 }
+
+impl From<JSObject> for JSValue {
+    fn from(object: JSObject) -> Self {
+        JSValue {
+            inner: object.inner.map(crate::sys::ffi::value_from_object),
+        }
+    }
+}
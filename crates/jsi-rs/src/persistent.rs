@@ -0,0 +1,135 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::handle::{Release, RuntimeState};
+use crate::{Error, JSArray, JSFunction, JSObject, JSPropNameID, JSRuntime, JSValue, Result};
+
+/// Handle types that can be kept in a [`Persistent`]
+pub trait PersistentValue: Sized + 'static {
+    /// Create another handle to the same JavaScript value
+    fn duplicate(&self, runtime: &mut JSRuntime<'_>) -> Self;
+}
+
+impl PersistentValue for JSValue {
+    fn duplicate(&self, runtime: &mut JSRuntime<'_>) -> Self {
+        self.clone_in(runtime)
+    }
+}
+
+impl PersistentValue for JSObject {
+    fn duplicate(&self, runtime: &mut JSRuntime<'_>) -> Self {
+        self.clone_in(runtime)
+    }
+}
+
+impl PersistentValue for JSArray {
+    fn duplicate(&self, runtime: &mut JSRuntime<'_>) -> Self {
+        self.clone_in(runtime)
+    }
+}
+
+impl PersistentValue for JSFunction {
+    fn duplicate(&self, runtime: &mut JSRuntime<'_>) -> Self {
+        self.clone_in(runtime)
+    }
+}
+
+impl PersistentValue for JSPropNameID {
+    fn duplicate(&self, runtime: &mut JSRuntime<'_>) -> Self {
+        self.clone_in(runtime)
+    }
+}
+
+struct Slot<T> {
+    value: RefCell<Option<T>>,
+}
+
+impl<T> Release for Slot<T> {
+    fn release(&self) {
+        let value = self.value.borrow_mut().take();
+        drop(value);
+    }
+}
+
+/// A handle that can be stored beyond the host call it was received in
+///
+/// Keeps the JavaScript value alive until the last clone is dropped or the runtime shuts
+/// down, whichever comes first. Cloning is a reference count increment. When the runtime
+/// tracks its lifetime (see [`crate::RuntimeState`]) the value is released as part of the
+/// runtime shutdown, afterwards [`Persistent::get`] returns an error. Persistent handles
+/// stay on the thread they were created on, when the runtime is destroyed on another
+/// thread the value is leaked instead of released.
+///
+/// ```ignore
+/// let listeners: Rc<RefCell<Vec<Persistent<JSFunction>>>> = Default::default();
+/// let registry = listeners.clone();
+/// let on_message = runtime.create_host_function("onMessage", 1, move |rt, _this, args| {
+///     let callback = args[0].as_function(rt).ok_or_else(|| Error::new("expected a function"))?;
+///     registry.borrow_mut().push(Persistent::new(rt, callback));
+///     Ok(JSValue::undefined())
+/// });
+/// ```
+pub struct Persistent<T: PersistentValue> {
+    slot: Rc<Slot<T>>,
+    state: Option<Arc<RuntimeState>>,
+}
+
+impl<T: PersistentValue> Persistent<T> {
+    /// Keep `value` alive, registering it with `runtime` to be released on shutdown
+    pub fn new(runtime: &JSRuntime<'_>, value: T) -> Self {
+        let slot = Rc::new(Slot {
+            value: RefCell::new(Some(value)),
+        });
+
+        let state = runtime.state().cloned();
+        if let Some(state) = &state {
+            let slot: Rc<dyn Release> = slot.clone();
+            state.register(Rc::downgrade(&slot));
+        }
+
+        Self { slot, state }
+    }
+
+    /// Create a new local handle to the stored value
+    pub fn get(&self, runtime: &mut JSRuntime<'_>) -> Result<T> {
+        if self.is_released() {
+            return Err(Error::new("persistent handle has been released"));
+        }
+        match &*self.slot.value.borrow() {
+            Some(value) => Ok(value.duplicate(runtime)),
+            None => Err(Error::new("persistent handle has been released")),
+        }
+    }
+
+    /// Release the value now instead of when the last clone is dropped
+    ///
+    /// Affects all clones of this handle.
+    pub fn release(&self) {
+        self.slot.release();
+    }
+
+    /// Whether the value was released or its runtime destroyed
+    pub fn is_released(&self) -> bool {
+        let runtime_gone = self.state.as_ref().is_some_and(|state| !state.is_alive());
+        runtime_gone || self.slot.value.borrow().is_none()
+    }
+}
+
+impl<T: PersistentValue> Clone for Persistent<T> {
+    fn clone(&self) -> Self {
+        Self {
+            slot: self.slot.clone(),
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<T: PersistentValue> fmt::Debug for Persistent<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Persistent")
+            .field("released", &self.is_released())
+            .finish()
+    }
+}
@@ -0,0 +1,160 @@
+//! Support functions for code generated by `jsi-rs-macros`, not a public API.
+
+use std::cell::{Ref, RefCell, RefMut};
+use std::fmt::Display;
+
+use crate::convert::type_error;
+use crate::{Error, FromJs, JSArray, JSObject, JSPropNameID, JSRuntime, JSValue, Result};
+
+pub fn error<E: Display>(error: E) -> Error {
+    Error::new(error.to_string())
+}
+
+pub fn expect_object(
+    runtime: &mut JSRuntime<'_>,
+    value: &JSValue,
+    type_name: &str,
+) -> Result<JSObject> {
+    value.as_object(runtime).ok_or_else(|| {
+        Error::new(format!(
+            "{}: {}",
+            type_name,
+            type_error("object", value).message()
+        ))
+    })
+}
+
+pub fn expect_array(
+    runtime: &mut JSRuntime<'_>,
+    value: &JSValue,
+    type_name: &str,
+    len: usize,
+) -> Result<JSArray> {
+    let array = value.as_array(runtime).ok_or_else(|| {
+        Error::new(format!(
+            "{}: {}",
+            type_name,
+            type_error("array", value).message()
+        ))
+    })?;
+    let actual = array.len(runtime);
+    if actual != len {
+        return Err(Error::new(format!(
+            "{}: expected array of length {}, got {}",
+            type_name, len, actual
+        )));
+    }
+
+    Ok(array)
+}
+
+pub fn expect_unit(value: &JSValue, type_name: &str) -> Result<()> {
+    if value.is_null() || value.is_undefined() {
+        return Ok(());
+    }
+
+    Err(Error::new(format!(
+        "{}: {}",
+        type_name,
+        type_error("null", value).message()
+    )))
+}
+
+pub fn field<T: FromJs>(
+    runtime: &mut JSRuntime<'_>,
+    object: &JSObject,
+    name: &str,
+    type_name: &str,
+) -> Result<T> {
+    let prefix = |e: Error| Error::new(format!("{}.{}: {}", type_name, name, e.message()));
+    let id = JSPropNameID::new(runtime, name);
+    let value = object.get_with_id(runtime, &id).map_err(prefix)?;
+    T::from_js(runtime, &value).map_err(prefix)
+}
+
+pub fn element<T: FromJs>(
+    runtime: &mut JSRuntime<'_>,
+    array: &JSArray,
+    index: usize,
+    type_name: &str,
+) -> Result<T> {
+    let value = array.get(runtime, index);
+    T::from_js(runtime, &value)
+        .map_err(|e| Error::new(format!("{}[{}]: {}", type_name, index, e.message())))
+}
+
+pub fn array_from_values(runtime: &mut JSRuntime<'_>, values: Vec<JSValue>) -> Result<JSValue> {
+    Ok(JSValue::from(JSArray::from_values(runtime, &values)))
+}
+
+pub fn tagged(runtime: &mut JSRuntime<'_>, tag: &str, payload: JSValue) -> Result<JSValue> {
+    let object = JSObject::new(runtime);
+    object.set(runtime, tag, &payload);
+    Ok(JSValue::from(object))
+}
+
+/// Returns the variant name of an enum encoded as a plain string
+pub fn as_tag(runtime: &mut JSRuntime<'_>, value: &JSValue) -> Option<String> {
+    value.as_string(runtime).map(|string| string.value(runtime))
+}
+
+/// Splits an enum encoded as `{ "Variant": payload }` into its tag and payload
+pub fn expect_tagged(
+    runtime: &mut JSRuntime<'_>,
+    value: &JSValue,
+    type_name: &str,
+) -> Result<(String, JSValue)> {
+    let object = expect_object(runtime, value, type_name)?;
+    let mut keys = object
+        .keys(runtime)
+        .map_err(|e| Error::new(format!("{}: {}", type_name, e.message())))?;
+    if keys.len() != 1 {
+        return Err(Error::new(format!(
+            "{}: expected an object with a single variant key",
+            type_name
+        )));
+    }
+
+    let tag = keys.remove(0);
+    let id = JSPropNameID::new(runtime, &tag);
+    let payload = object
+        .get_with_id(runtime, &id)
+        .map_err(|e| Error::new(format!("{}.{}: {}", type_name, tag, e.message())))?;
+    Ok((tag, payload))
+}
+
+pub fn unknown_variant(type_name: &str, tag: &str) -> Error {
+    Error::new(format!("{}: unknown variant `{}`", type_name, tag))
+}
+
+/// Converts a host function argument, missing arguments are read as `undefined`
+pub fn argument<T: FromJs>(
+    runtime: &mut JSRuntime<'_>,
+    args: &[JSValue],
+    index: usize,
+    function_name: &str,
+) -> Result<T> {
+    let result = match args.get(index) {
+        Some(value) => T::from_js(runtime, value),
+        None => T::from_js(runtime, &JSValue::undefined()),
+    };
+
+    result.map_err(|e| {
+        Error::new(format!(
+            "{}: argument {}: {}",
+            function_name,
+            index,
+            e.message()
+        ))
+    })
+}
+
+pub fn borrow<'a, T>(this: &'a RefCell<T>, function_name: &str) -> Result<Ref<'a, T>> {
+    this.try_borrow()
+        .map_err(|_| Error::new(format!("{}: object is already in use", function_name)))
+}
+
+pub fn borrow_mut<'a, T>(this: &'a RefCell<T>, function_name: &str) -> Result<RefMut<'a, T>> {
+    this.try_borrow_mut()
+        .map_err(|_| Error::new(format!("{}: object is already in use", function_name)))
+}
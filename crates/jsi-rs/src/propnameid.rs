@@ -1,21 +1,77 @@
-use crate::JSRuntime;
+use crate::string::{push_utf16, ChunkVisitor};
+use crate::{Error, JSRuntime, Result, StringChunk};
 
 /// Wrapper around facebook::jsi::PropNameID providing a safe Rust API
 pub struct JSPropNameID {
-    pub(crate) inner: cxx::UniquePtr<crate::sys::ffi::JSIPropNameID>,
+    pub(crate) inner: crate::handle::Handle<crate::sys::ffi::JSIPropNameID>,
 }
 
 impl JSPropNameID {
     /// Create a new PropNameID from UTF-8 data
     pub fn new(runtime: &mut JSRuntime<'_>, name: &str) -> Self {
         let ptr = crate::sys::ffi::create_propnameid_from_utf8(runtime.pin_mut(), name);
-        Self { inner: ptr }
+        Self {
+            inner: runtime.handle(ptr),
+        }
+    }
+
+    /// Create a new PropNameID from ASCII data, skipping UTF-8 decoding
+    pub fn from_ascii(runtime: &mut JSRuntime<'_>, name: &[u8]) -> Result<Self> {
+        if !name.is_ascii() {
+            return Err(Error::new("property name is not ASCII"));
+        }
+
+        let ptr = crate::sys::ffi::create_propnameid_from_ascii(runtime.pin_mut(), name);
+        Ok(Self {
+            inner: runtime.handle(ptr),
+        })
+    }
+
+    /// Create a new PropNameID from UTF-16 code units
+    ///
+    /// Unpaired surrogates are kept as they are.
+    pub fn from_utf16(runtime: &mut JSRuntime<'_>, name: &[u16]) -> Self {
+        let ptr = crate::sys::ffi::create_propnameid_from_utf16(runtime.pin_mut(), name);
+        Self {
+            inner: runtime.handle(ptr),
+        }
+    }
+
+    /// Create another handle to the same name
+    pub fn clone_in(&self, runtime: &mut JSRuntime<'_>) -> JSPropNameID {
+        self.inner.check(runtime);
+        let inner = crate::sys::ffi::propnameid_copy(runtime.pin_mut(), &self.inner);
+        JSPropNameID {
+            inner: runtime.handle(inner),
+        }
     }
 
     pub fn value(&self, runtime: &mut JSRuntime<'_>) -> String {
+        self.inner.check(runtime);
         crate::sys::ffi::propnameid_to_utf8(runtime.pin_mut(), &self.inner)
     }
 
+    /// Copy the name as UTF-16 code units, preserving unpaired surrogates
+    pub fn to_utf16(&self, runtime: &mut JSRuntime<'_>) -> Vec<u16> {
+        self.inner.check(runtime);
+        let mut buffer = Vec::new();
+        self.for_each_chunk(runtime, |chunk| push_utf16(&mut buffer, chunk));
+        buffer
+    }
+
+    /// Visit the name in the engine's representation without copying
+    ///
+    /// The callback can be invoked several times with different chunk formats.
+    pub fn for_each_chunk<F>(&self, runtime: &mut JSRuntime<'_>, mut callback: F)
+    where
+        F: FnMut(StringChunk<'_>),
+    {
+        self.inner.check(runtime);
+        ChunkVisitor::run(&mut callback, |visitor| {
+            crate::sys::ffi::propnameid_for_each_chunk(runtime.pin_mut(), &self.inner, visitor)
+        });
+    }
+
     /// Access the inner UniquePtr for advanced usage
     #[cfg(feature = "unsafe")]
     pub fn inner(&self) -> &cxx::UniquePtr<crate::sys::ffi::JSIPropNameID> {
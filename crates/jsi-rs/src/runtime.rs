@@ -1,42 +1,142 @@
 use std::marker::PhantomData;
 use std::pin::Pin;
+use std::sync::Arc;
 
+use crate::handle::{Handle, RuntimeState};
+use crate::scope::{Scope, ScopeOutput};
 use crate::sys::ffi;
 
+/// Integers that can be converted to a JavaScript BigInt, see [`JSRuntime::create_bigint`]
+///
+/// 128-bit values outside of the 64-bit range fail when a script removed the global `BigInt`
+/// function before its first use, see [`crate::JSBigInt::from_i128`].
 pub trait IntoJSIBigInt {
-    fn create_jsi_bigint(self, runtime: &mut JSRuntime<'_>) -> crate::JSBigInt;
+    fn create_jsi_bigint(self, runtime: &mut JSRuntime<'_>) -> crate::Result<crate::JSBigInt>;
 }
 
 impl IntoJSIBigInt for i64 {
-    fn create_jsi_bigint(self, runtime: &mut JSRuntime<'_>) -> crate::JSBigInt {
-        crate::JSBigInt::from_i64(runtime, self)
+    fn create_jsi_bigint(self, runtime: &mut JSRuntime<'_>) -> crate::Result<crate::JSBigInt> {
+        Ok(crate::JSBigInt::from_i64(runtime, self))
     }
 }
 
 impl IntoJSIBigInt for u64 {
-    fn create_jsi_bigint(self, runtime: &mut JSRuntime<'_>) -> crate::JSBigInt {
-        crate::JSBigInt::from_u64(runtime, self)
+    fn create_jsi_bigint(self, runtime: &mut JSRuntime<'_>) -> crate::Result<crate::JSBigInt> {
+        Ok(crate::JSBigInt::from_u64(runtime, self))
+    }
+}
+
+impl IntoJSIBigInt for i128 {
+    fn create_jsi_bigint(self, runtime: &mut JSRuntime<'_>) -> crate::Result<crate::JSBigInt> {
+        crate::JSBigInt::from_i128(runtime, self)
+    }
+}
+
+impl IntoJSIBigInt for u128 {
+    fn create_jsi_bigint(self, runtime: &mut JSRuntime<'_>) -> crate::Result<crate::JSBigInt> {
+        crate::JSBigInt::from_u128(runtime, self)
     }
 }
 
 /// Wrapper around facebook::jsi::Runtime providing a safe Rust API
 pub struct JSRuntime<'a> {
     pub(crate) ptr: *mut ffi::JSIRuntime,
+    state: Option<Arc<RuntimeState>>,
     _marker: PhantomData<&'a ()>,
 }
 
 impl<'a> JSRuntime<'a> {
+    /// Wrap a raw `facebook::jsi::Runtime` pointer
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a live runtime that outlives the returned wrapper.
+    /// Handles created through this wrapper are not tied to the runtime, prefer
+    /// [`JSRuntime::from_raw_with_state`] when the embedder tracks the runtime's lifetime.
     pub unsafe fn from_raw(ptr: *mut ffi::JSIRuntime) -> Self {
         Self {
             ptr,
+            state: None,
             _marker: PhantomData,
         }
     }
 
+    /// Wrap a raw `facebook::jsi::Runtime` pointer, tying created handles to `state`
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a live runtime that outlives the returned wrapper, and `state`
+    /// must be the state that embedder uses for this runtime and no other.
+    pub unsafe fn from_raw_with_state(ptr: *mut ffi::JSIRuntime, state: Arc<RuntimeState>) -> Self {
+        Self {
+            ptr,
+            state: Some(state),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Identity and liveness of the underlying runtime, if the embedder tracks it
+    pub fn state(&self) -> Option<&Arc<RuntimeState>> {
+        self.state.as_ref()
+    }
+
     pub(crate) fn pin_mut(&mut self) -> Pin<&mut ffi::JSIRuntime> {
         unsafe { Pin::new_unchecked(&mut *self.ptr) }
     }
 
+    /// Take ownership of a pointer created by this runtime
+    pub(crate) fn handle<T: cxx::memory::UniquePtrTarget>(
+        &self,
+        ptr: cxx::UniquePtr<T>,
+    ) -> Handle<T> {
+        Handle::new(ptr, self.state.clone())
+    }
+
+    /// Get the handle cached under `key` for this runtime, creating it with `init` on first use
+    ///
+    /// Used for values that must not follow later changes by scripts, e.g. builtin methods.
+    /// Runtimes without [`RuntimeState`] have no cache, `init` then runs on every call.
+    pub(crate) fn cached<T: crate::PersistentValue>(
+        &mut self,
+        key: &'static str,
+        init: impl FnOnce(&mut Self) -> crate::Result<T>,
+    ) -> crate::Result<T> {
+        let Some(state) = self.state.clone() else {
+            return init(self);
+        };
+        if let Some(value) = state.cache().get::<T>(key) {
+            return Ok(value.duplicate(self));
+        }
+
+        // Not holding the lock, `init` may run scripts that use the cache
+        let value = init(self)?;
+        let mut cache = state.cache();
+        if let Some(existing) = cache.get::<T>(key) {
+            return Ok(existing.duplicate(self));
+        }
+        let copy = value.duplicate(self);
+        cache.insert(key, value);
+        Ok(copy)
+    }
+
+    /// Run `f` inside a `jsi::Scope`
+    ///
+    /// Handles created inside the scope can be reclaimed by the engine as soon as it ends,
+    /// instead of at the end of the outer host call. Use it around loop bodies that create
+    /// many temporary values.
+    ///
+    /// `f` receives a [`Scope`] with a lifetime of its own and can only return
+    /// [`ScopeOutput`] data, no JSI handles. Convert values into Rust data before leaving
+    /// the scope.
+    pub fn scope<F, R>(&mut self, f: F) -> R
+    where
+        F: for<'s> FnOnce(&mut Scope<'s, 'a>) -> R,
+        R: ScopeOutput,
+    {
+        let _scope = ffi::create_scope(self.pin_mut());
+        f(&mut Scope::new(self))
+    }
+
     /// Access the inner raw pointer for advanced usage
     #[cfg(feature = "unsafe")]
     pub fn inner(&self) -> *mut ffi::JSIRuntime {
@@ -69,6 +169,24 @@ impl<'a> JSRuntime<'a> {
         crate::JSObject::new(self)
     }
 
+    /// Get the global object
+    pub fn global(&mut self) -> crate::JSObject {
+        let ptr = ffi::runtime_global(self.pin_mut());
+        crate::JSObject {
+            inner: self.handle(ptr),
+        }
+    }
+
+    /// Look up a global function such as a builtin constructor
+    pub(crate) fn global_function(&mut self, name: &str) -> crate::Result<crate::JSFunction> {
+        let global = self.global();
+        let id = crate::JSPropNameID::new(self, name);
+        global
+            .get_with_id(self, &id)?
+            .as_function(self)
+            .ok_or_else(|| crate::Error::new(format!("{} is not available", name)))
+    }
+
     pub fn create_array_empty(&mut self) -> crate::JSArray {
         crate::JSArray::new(self, 0)
     }
@@ -81,9 +199,73 @@ impl<'a> JSRuntime<'a> {
         crate::JSPropNameID::new(self, name)
     }
 
-    pub fn create_bigint<T: IntoJSIBigInt>(&mut self, value: T) -> crate::JSBigInt {
+    pub fn create_bigint<T: IntoJSIBigInt>(&mut self, value: T) -> crate::Result<crate::JSBigInt> {
         value.create_jsi_bigint(self)
     }
+
+    pub fn create_host_function<F>(
+        &mut self,
+        name: &str,
+        param_count: u32,
+        func: F,
+    ) -> crate::JSFunction
+    where
+        F: Fn(
+                &mut JSRuntime<'_>,
+                &crate::JSValue,
+                &[crate::JSValue],
+            ) -> crate::Result<crate::JSValue>
+            + 'static,
+    {
+        crate::JSFunction::from_host_fn(self, name, param_count, func)
+    }
+}
+
+impl<'a> JSRuntime<'a> {
+    /// Parse JSON text with the runtime's native `JSON.parse`
+    ///
+    /// Syntax errors are reported as errors, the text is never evaluated as code.
+    pub fn json_parse(&mut self, text: &str) -> crate::Result<crate::JSValue> {
+        let parse = self.json_function("parse")?;
+        let text = crate::JSValue::from(crate::JSString::new(self, text));
+        parse.call(self, &[text])
+    }
+
+    /// Serialize a value with the runtime's native `JSON.stringify`
+    ///
+    /// `indent` is the number of spaces used to pretty print nested values.
+    /// Returns `None` for values without a JSON representation, such as `undefined` or functions.
+    pub fn json_stringify(
+        &mut self,
+        value: &crate::JSValue,
+        indent: Option<u32>,
+    ) -> crate::Result<Option<String>> {
+        let stringify = self.json_function("stringify")?;
+
+        let mut args = vec![value.clone_in(self)];
+        if let Some(indent) = indent {
+            args.push(crate::JSValue::undefined());
+            args.push(crate::JSValue::number(indent as f64));
+        }
+
+        let result = stringify.call(self, &args)?;
+        Ok(result.as_string(self).map(|string| string.value(self)))
+    }
+
+    /// Look up `JSON[name]`, the property names are created once per runtime
+    fn json_function(&mut self, name: &'static str) -> crate::Result<crate::JSFunction> {
+        let json_id = self.cached("JSON", |rt| Ok(crate::JSPropNameID::new(rt, "JSON")))?;
+        let name_id = self.cached(name, |rt| Ok(crate::JSPropNameID::new(rt, name)))?;
+
+        let global = self.global();
+        let json = global
+            .get_with_id(self, &json_id)?
+            .as_object(self)
+            .ok_or_else(|| crate::Error::new("JSON is not available"))?;
+        json.get_with_id(self, &name_id)?
+            .as_function(self)
+            .ok_or_else(|| crate::Error::new("JSON function is not available"))
+    }
 }
 
 // JSRuntime is Send but not Sync
@@ -0,0 +1,142 @@
+use std::cell::Cell;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::time::{Duration, SystemTime};
+
+use crate::JSRuntime;
+
+/// Invariant marker, `'s` can't be shortened or extended to unify with another lifetime
+type Brand<'s> = PhantomData<Cell<&'s ()>>;
+
+/// Runtime access inside [`JSRuntime::scope`]
+///
+/// Every scope has its own lifetime `'s`, handles wrapped with [`Scope::local`] are tied to it
+/// and can't be stored outside of it. Only [`ScopeOutput`] values can be returned, handles
+/// can't leave the scope either way:
+///
+/// ```compile_fail
+/// # use jsi_rs::{JSRuntime, JSValue};
+/// fn escape(runtime: &mut JSRuntime<'_>) {
+///     let leaked = runtime.scope(|scope| {
+///         let string = scope.create_string("scoped");
+///         scope.local(JSValue::from(string))
+///     });
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # use jsi_rs::JSRuntime;
+/// fn escape(runtime: &mut JSRuntime<'_>) {
+///     let leaked = runtime.scope(|scope| scope.create_string("scoped"));
+/// }
+/// ```
+pub struct Scope<'s, 'a> {
+    runtime: &'s mut JSRuntime<'a>,
+    _brand: Brand<'s>,
+}
+
+impl<'s, 'a> Scope<'s, 'a> {
+    pub(crate) fn new(runtime: &'s mut JSRuntime<'a>) -> Self {
+        Self {
+            runtime,
+            _brand: PhantomData,
+        }
+    }
+
+    /// Tie a handle created inside the scope to it
+    pub fn local<T>(&self, value: T) -> Local<'s, T> {
+        Local {
+            value,
+            _brand: PhantomData,
+        }
+    }
+}
+
+impl<'a> Deref for Scope<'_, 'a> {
+    type Target = JSRuntime<'a>;
+
+    fn deref(&self) -> &JSRuntime<'a> {
+        self.runtime
+    }
+}
+
+impl<'a> DerefMut for Scope<'_, 'a> {
+    fn deref_mut(&mut self) -> &mut JSRuntime<'a> {
+        self.runtime
+    }
+}
+
+/// Handle that can't leave the [`Scope`] it was created in, see [`Scope::local`]
+pub struct Local<'s, T> {
+    value: T,
+    _brand: Brand<'s>,
+}
+
+impl<T> Deref for Local<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+/// Values that can be returned from [`JSRuntime::scope`]: Rust data holding no JSI handles
+///
+/// Implement it for your own types built from such data.
+pub trait ScopeOutput {}
+
+macro_rules! impl_scope_output {
+    ($($ty:ty),* $(,)?) => {
+        $(impl ScopeOutput for $ty {})*
+    };
+}
+
+impl_scope_output!(
+    (),
+    bool,
+    char,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    f32,
+    f64,
+    str,
+    String,
+    Duration,
+    SystemTime,
+    crate::Error,
+);
+
+impl<T: ScopeOutput + ?Sized> ScopeOutput for &T {}
+impl<T: ScopeOutput + ?Sized> ScopeOutput for Box<T> {}
+impl<T: ScopeOutput> ScopeOutput for [T] {}
+impl<T: ScopeOutput> ScopeOutput for Vec<T> {}
+impl<T: ScopeOutput> ScopeOutput for Option<T> {}
+impl<T: ScopeOutput, E: ScopeOutput> ScopeOutput for Result<T, E> {}
+impl<K: ScopeOutput, V: ScopeOutput, S> ScopeOutput for HashMap<K, V, S> {}
+impl<K: ScopeOutput, V: ScopeOutput> ScopeOutput for BTreeMap<K, V> {}
+impl<T: ScopeOutput, S> ScopeOutput for HashSet<T, S> {}
+impl<T: ScopeOutput> ScopeOutput for BTreeSet<T> {}
+
+macro_rules! impl_scope_output_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: ScopeOutput),+> ScopeOutput for ($($name,)+) {}
+    };
+}
+
+impl_scope_output_tuple!(A);
+impl_scope_output_tuple!(A, B);
+impl_scope_output_tuple!(A, B, C);
+impl_scope_output_tuple!(A, B, C, D);
+impl_scope_output_tuple!(A, B, C, D, E);
+impl_scope_output_tuple!(A, B, C, D, E, F);
@@ -1,24 +1,144 @@
-use crate::JSRuntime;
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{Error, JSRuntime, Result};
+
+/// A piece of string data borrowed from the engine's internal representation
+///
+/// Engines store strings either as one byte ASCII or as UTF-16 code units, a single string
+/// may be reported as several chunks in different formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringChunk<'a> {
+    Ascii(&'a [u8]),
+    Utf16(&'a [u16]),
+}
+
+/// Rust callback receiving string chunks from C++
+pub struct ChunkVisitor<'a> {
+    callback: &'a mut dyn FnMut(StringChunk<'_>),
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+impl<'a> ChunkVisitor<'a> {
+    /// Run `visit` with a visitor forwarding to `callback`, re-raising its panic afterwards
+    ///
+    /// Unwinding through the C++ frames of getStringData is not allowed, so a panic is held
+    /// until the engine has returned and the remaining chunks are skipped.
+    pub(crate) fn run(
+        callback: &'a mut dyn FnMut(StringChunk<'_>),
+        visit: impl FnOnce(&mut ChunkVisitor<'a>),
+    ) {
+        let mut visitor = ChunkVisitor {
+            callback,
+            panic: None,
+        };
+        visit(&mut visitor);
+
+        if let Some(payload) = visitor.panic {
+            panic::resume_unwind(payload);
+        }
+    }
+
+    pub(crate) fn visit(&mut self, chunk: StringChunk<'_>) {
+        if self.panic.is_some() {
+            return;
+        }
+
+        let callback = &mut self.callback;
+        if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| callback(chunk))) {
+            self.panic = Some(payload);
+        }
+    }
+}
+
+/// Append chunks to a UTF-16 buffer, widening ASCII data
+pub(crate) fn push_utf16(buffer: &mut Vec<u16>, chunk: StringChunk<'_>) {
+    match chunk {
+        StringChunk::Ascii(data) => buffer.extend(data.iter().map(|&byte| byte as u16)),
+        StringChunk::Utf16(data) => buffer.extend_from_slice(data),
+    }
+}
 
 /// Wrapper around facebook::jsi::String providing a safe Rust API
 pub struct JSString {
-    pub(crate) inner: cxx::UniquePtr<crate::sys::ffi::JSIString>,
+    pub(crate) inner: crate::handle::Handle<crate::sys::ffi::JSIString>,
 }
 
 impl JSString {
     /// Create a new JavaScript string from UTF-8 data
     pub fn new(runtime: &mut JSRuntime<'_>, data: &str) -> Self {
         let ptr = crate::sys::ffi::create_string_from_utf8(runtime.pin_mut(), data);
-        Self { inner: ptr }
+        Self {
+            inner: runtime.handle(ptr),
+        }
+    }
+
+    /// Create a new JavaScript string from ASCII data, skipping UTF-8 decoding
+    pub fn from_ascii(runtime: &mut JSRuntime<'_>, data: &[u8]) -> Result<Self> {
+        if !data.is_ascii() {
+            return Err(Error::new("string data is not ASCII"));
+        }
+
+        let ptr = crate::sys::ffi::create_string_from_ascii(runtime.pin_mut(), data);
+        Ok(Self {
+            inner: runtime.handle(ptr),
+        })
+    }
+
+    /// Create a new JavaScript string from UTF-16 code units
+    ///
+    /// Unpaired surrogates are kept as they are.
+    pub fn from_utf16(runtime: &mut JSRuntime<'_>, data: &[u16]) -> Self {
+        let ptr = crate::sys::ffi::create_string_from_utf16(runtime.pin_mut(), data);
+        Self {
+            inner: runtime.handle(ptr),
+        }
     }
 
     pub fn value(&self, runtime: &mut JSRuntime<'_>) -> String {
+        self.inner.check(runtime);
         crate::sys::ffi::string_to_utf8(runtime.pin_mut(), &self.inner)
     }
 
+    /// Copy the string as UTF-16 code units, preserving unpaired surrogates
+    pub fn to_utf16(&self, runtime: &mut JSRuntime<'_>) -> Vec<u16> {
+        self.inner.check(runtime);
+        let mut buffer = Vec::new();
+        self.for_each_chunk(runtime, |chunk| push_utf16(&mut buffer, chunk));
+        buffer
+    }
+
+    /// Visit the string contents in the engine's representation without copying
+    ///
+    /// The callback can be invoked several times with different chunk formats.
+    pub fn for_each_chunk<F>(&self, runtime: &mut JSRuntime<'_>, mut callback: F)
+    where
+        F: FnMut(StringChunk<'_>),
+    {
+        self.inner.check(runtime);
+        ChunkVisitor::run(&mut callback, |visitor| {
+            crate::sys::ffi::string_for_each_chunk(runtime.pin_mut(), &self.inner, visitor)
+        });
+    }
+
+    /// Check if both strings have the same contents (JavaScript `===`)
+    pub fn strict_equals(&self, runtime: &mut JSRuntime<'_>, other: &JSString) -> bool {
+        self.inner.check(runtime);
+        other.inner.check(runtime);
+        crate::sys::ffi::string_strict_equals(runtime.pin_mut(), &self.inner, &other.inner)
+    }
+
     /// Access the inner UniquePtr for advanced usage
     #[cfg(feature = "unsafe")]
     pub fn inner(&self) -> &cxx::UniquePtr<crate::sys::ffi::JSIString> {
         &self.inner
     }
 }
+
+impl From<JSString> for crate::JSValue {
+    fn from(value: JSString) -> Self {
+        crate::JSValue {
+            inner: value.inner.map(crate::sys::ffi::value_from_string),
+        }
+    }
+}
@@ -1,3 +1,10 @@
+use std::pin::Pin;
+
+use cxx::UniquePtr;
+
+use crate::function::HostFunction;
+use crate::string::ChunkVisitor;
+
 #[cxx::bridge]
 pub mod ffi {
     // Opaque C++ types from facebook::jsi namespace
@@ -36,6 +43,9 @@ pub mod ffi {
         #[namespace = "facebook::jsi"]
         fn isBigInt(self: &JSIValue) -> bool;
 
+        #[namespace = "facebook::jsi"]
+        fn isSymbol(self: &JSIValue) -> bool;
+
         #[namespace = "facebook::jsi"]
         #[cxx_name = "String"]
         type JSIString;
@@ -60,6 +70,10 @@ pub mod ffi {
         #[cxx_name = "BigInt"]
         type JSIBigInt;
 
+        #[namespace = "facebook::jsi"]
+        #[cxx_name = "Scope"]
+        type JSIScope;
+
         // Helper functions for type conversions
         #[namespace = "jsi_rs"]
         fn value_as_object(
@@ -102,6 +116,12 @@ pub mod ffi {
         #[namespace = "jsi_rs"]
         fn create_object(runtime: Pin<&mut JSIRuntime>) -> UniquePtr<JSIObject>;
 
+        #[namespace = "jsi_rs"]
+        fn runtime_global(runtime: Pin<&mut JSIRuntime>) -> UniquePtr<JSIObject>;
+
+        #[namespace = "jsi_rs"]
+        fn create_scope(runtime: Pin<&mut JSIRuntime>) -> UniquePtr<JSIScope>;
+
         #[namespace = "jsi_rs"]
         fn create_array(runtime: Pin<&mut JSIRuntime>, length: usize) -> UniquePtr<JSIArray>;
 
@@ -144,6 +164,30 @@ pub mod ffi {
         #[namespace = "jsi_rs"]
         fn string_to_utf8(runtime: Pin<&mut JSIRuntime>, str: &UniquePtr<JSIString>) -> String;
 
+        #[namespace = "jsi_rs"]
+        fn create_string_from_ascii(
+            runtime: Pin<&mut JSIRuntime>,
+            data: &[u8],
+        ) -> UniquePtr<JSIString>;
+
+        #[namespace = "jsi_rs"]
+        fn create_string_from_utf16(
+            runtime: Pin<&mut JSIRuntime>,
+            data: &[u16],
+        ) -> UniquePtr<JSIString>;
+
+        #[namespace = "jsi_rs"]
+        fn create_propnameid_from_ascii(
+            runtime: Pin<&mut JSIRuntime>,
+            data: &[u8],
+        ) -> UniquePtr<JSIPropNameID>;
+
+        #[namespace = "jsi_rs"]
+        fn create_propnameid_from_utf16(
+            runtime: Pin<&mut JSIRuntime>,
+            data: &[u16],
+        ) -> UniquePtr<JSIPropNameID>;
+
         #[namespace = "jsi_rs"]
         fn propnameid_to_utf8(
             runtime: Pin<&mut JSIRuntime>,
@@ -157,6 +201,15 @@ pub mod ffi {
             radix: i32,
         ) -> Result<UniquePtr<JSIString>>;
 
+        #[namespace = "jsi_rs"]
+        fn bigint_is_int64(runtime: Pin<&mut JSIRuntime>, bigint: &UniquePtr<JSIBigInt>) -> bool;
+
+        #[namespace = "jsi_rs"]
+        fn bigint_is_uint64(runtime: Pin<&mut JSIRuntime>, bigint: &UniquePtr<JSIBigInt>) -> bool;
+
+        #[namespace = "jsi_rs"]
+        fn bigint_truncate(runtime: Pin<&mut JSIRuntime>, bigint: &UniquePtr<JSIBigInt>) -> u64;
+
         #[namespace = "jsi_rs"]
         fn value_vec_create() -> UniquePtr<ValueVec>;
 
@@ -202,7 +255,14 @@ pub mod ffi {
             array: &UniquePtr<JSIArray>,
             index: usize,
             value: &UniquePtr<JSIValue>,
-        );
+        ) -> Result<()>;
+
+        #[namespace = "jsi_rs"]
+        fn array_push(
+            runtime: Pin<&mut JSIRuntime>,
+            array: &UniquePtr<JSIArray>,
+            value: &UniquePtr<JSIValue>,
+        ) -> Result<()>;
 
         #[namespace = "jsi_rs"]
         fn array_size(runtime: Pin<&mut JSIRuntime>, array: &UniquePtr<JSIArray>) -> usize;
@@ -234,12 +294,238 @@ pub mod ffi {
             runtime: Pin<&mut JSIRuntime>,
             obj: &UniquePtr<JSIObject>,
             name: &str,
-        );
+        ) -> Result<()>;
+
+        #[namespace = "jsi_rs"]
+        fn object_delete_property_with_id(
+            runtime: Pin<&mut JSIRuntime>,
+            obj: &UniquePtr<JSIObject>,
+            name: &UniquePtr<JSIPropNameID>,
+        ) -> Result<()>;
 
         #[namespace = "jsi_rs"]
         fn object_get_property_names(
             runtime: Pin<&mut JSIRuntime>,
             obj: &UniquePtr<JSIObject>,
         ) -> UniquePtr<JSIArray>;
+
+        #[namespace = "jsi_rs"]
+        fn object_get_own_property_names(
+            runtime: Pin<&mut JSIRuntime>,
+            obj: &UniquePtr<JSIObject>,
+        ) -> Result<UniquePtr<JSIArray>>;
+
+        #[namespace = "jsi_rs"]
+        fn object_get_property_with_id(
+            runtime: Pin<&mut JSIRuntime>,
+            obj: &UniquePtr<JSIObject>,
+            name: &UniquePtr<JSIPropNameID>,
+        ) -> Result<UniquePtr<JSIValue>>;
+
+        #[namespace = "jsi_rs"]
+        fn object_set_property_with_id(
+            runtime: Pin<&mut JSIRuntime>,
+            obj: &UniquePtr<JSIObject>,
+            name: &UniquePtr<JSIPropNameID>,
+            value: &UniquePtr<JSIValue>,
+        ) -> Result<()>;
+
+        #[namespace = "jsi_rs"]
+        fn object_get_property_with_value(
+            runtime: Pin<&mut JSIRuntime>,
+            obj: &UniquePtr<JSIObject>,
+            name: &UniquePtr<JSIValue>,
+        ) -> Result<UniquePtr<JSIValue>>;
+
+        #[namespace = "jsi_rs"]
+        fn object_has_property_with_id(
+            runtime: Pin<&mut JSIRuntime>,
+            obj: &UniquePtr<JSIObject>,
+            name: &UniquePtr<JSIPropNameID>,
+        ) -> Result<bool>;
+
+        #[namespace = "jsi_rs"]
+        fn object_get_prototype(
+            runtime: Pin<&mut JSIRuntime>,
+            obj: &UniquePtr<JSIObject>,
+        ) -> Result<UniquePtr<JSIValue>>;
+
+        #[namespace = "jsi_rs"]
+        fn object_set_prototype(
+            runtime: Pin<&mut JSIRuntime>,
+            obj: &UniquePtr<JSIObject>,
+            prototype: &UniquePtr<JSIValue>,
+        ) -> Result<()>;
+
+        #[namespace = "jsi_rs"]
+        fn object_instance_of(
+            runtime: Pin<&mut JSIRuntime>,
+            obj: &UniquePtr<JSIObject>,
+            ctor: &UniquePtr<JSIFunction>,
+        ) -> Result<bool>;
+
+        #[namespace = "jsi_rs"]
+        fn object_to_value(
+            runtime: Pin<&mut JSIRuntime>,
+            obj: &UniquePtr<JSIObject>,
+        ) -> UniquePtr<JSIValue>;
+
+        #[namespace = "jsi_rs"]
+        fn object_is_array(runtime: Pin<&mut JSIRuntime>, obj: &UniquePtr<JSIObject>) -> bool;
+
+        #[namespace = "jsi_rs"]
+        fn object_is_function(runtime: Pin<&mut JSIRuntime>, obj: &UniquePtr<JSIObject>) -> bool;
+
+        #[namespace = "jsi_rs"]
+        fn object_is_array_buffer(
+            runtime: Pin<&mut JSIRuntime>,
+            obj: &UniquePtr<JSIObject>,
+        ) -> bool;
+
+        #[namespace = "jsi_rs"]
+        fn object_as_array(
+            runtime: Pin<&mut JSIRuntime>,
+            obj: &UniquePtr<JSIObject>,
+        ) -> UniquePtr<JSIArray>;
+
+        #[namespace = "jsi_rs"]
+        fn value_copy(
+            runtime: Pin<&mut JSIRuntime>,
+            value: &UniquePtr<JSIValue>,
+        ) -> UniquePtr<JSIValue>;
+
+        #[namespace = "jsi_rs"]
+        fn object_copy(
+            runtime: Pin<&mut JSIRuntime>,
+            obj: &UniquePtr<JSIObject>,
+        ) -> UniquePtr<JSIObject>;
+
+        #[namespace = "jsi_rs"]
+        fn array_copy(
+            runtime: Pin<&mut JSIRuntime>,
+            array: &UniquePtr<JSIArray>,
+        ) -> UniquePtr<JSIArray>;
+
+        #[namespace = "jsi_rs"]
+        fn propnameid_copy(
+            runtime: Pin<&mut JSIRuntime>,
+            name: &UniquePtr<JSIPropNameID>,
+        ) -> UniquePtr<JSIPropNameID>;
+
+        #[namespace = "jsi_rs"]
+        fn function_copy(
+            runtime: Pin<&mut JSIRuntime>,
+            func: &UniquePtr<JSIFunction>,
+        ) -> UniquePtr<JSIFunction>;
+
+        #[namespace = "jsi_rs"]
+        fn value_strict_equals(
+            runtime: Pin<&mut JSIRuntime>,
+            a: &UniquePtr<JSIValue>,
+            b: &UniquePtr<JSIValue>,
+        ) -> bool;
+
+        #[namespace = "jsi_rs"]
+        fn value_to_string(
+            runtime: Pin<&mut JSIRuntime>,
+            value: &UniquePtr<JSIValue>,
+        ) -> Result<UniquePtr<JSIString>>;
+
+        #[namespace = "jsi_rs"]
+        fn object_strict_equals(
+            runtime: Pin<&mut JSIRuntime>,
+            a: &UniquePtr<JSIObject>,
+            b: &UniquePtr<JSIObject>,
+        ) -> bool;
+
+        #[namespace = "jsi_rs"]
+        fn string_strict_equals(
+            runtime: Pin<&mut JSIRuntime>,
+            a: &UniquePtr<JSIString>,
+            b: &UniquePtr<JSIString>,
+        ) -> bool;
+
+        #[namespace = "jsi_rs"]
+        fn value_from_string(str: UniquePtr<JSIString>) -> UniquePtr<JSIValue>;
+
+        #[namespace = "jsi_rs"]
+        fn value_from_object(obj: UniquePtr<JSIObject>) -> UniquePtr<JSIValue>;
+
+        #[namespace = "jsi_rs"]
+        fn value_from_array(array: UniquePtr<JSIArray>) -> UniquePtr<JSIValue>;
+
+        #[namespace = "jsi_rs"]
+        fn value_from_function(func: UniquePtr<JSIFunction>) -> UniquePtr<JSIValue>;
+
+        #[namespace = "jsi_rs"]
+        fn value_from_bigint(bigint: UniquePtr<JSIBigInt>) -> UniquePtr<JSIValue>;
+
+        #[namespace = "jsi_rs"]
+        fn value_vec_size(vec: &ValueVec) -> usize;
+
+        #[namespace = "jsi_rs"]
+        fn value_vec_take(vec: Pin<&mut ValueVec>, index: usize) -> UniquePtr<JSIValue>;
+
+        #[namespace = "jsi_rs"]
+        fn create_host_function(
+            runtime: Pin<&mut JSIRuntime>,
+            name: &str,
+            param_count: u32,
+            func: Box<HostFunction>,
+        ) -> UniquePtr<JSIFunction>;
+
+        #[namespace = "jsi_rs"]
+        fn string_for_each_chunk(
+            runtime: Pin<&mut JSIRuntime>,
+            str: &UniquePtr<JSIString>,
+            visitor: &mut ChunkVisitor<'_>,
+        );
+
+        #[namespace = "jsi_rs"]
+        fn propnameid_for_each_chunk(
+            runtime: Pin<&mut JSIRuntime>,
+            propnameid: &UniquePtr<JSIPropNameID>,
+            visitor: &mut ChunkVisitor<'_>,
+        );
     }
+
+    // Rust callbacks invoked from C++
+    extern "Rust" {
+        #[namespace = "jsi_rs"]
+        type HostFunction;
+
+        #[namespace = "jsi_rs"]
+        fn call_host_function(
+            func: &HostFunction,
+            runtime: Pin<&mut JSIRuntime>,
+            this_val: UniquePtr<JSIValue>,
+            args: Pin<&mut ValueVec>,
+        ) -> Result<UniquePtr<JSIValue>>;
+
+        #[namespace = "jsi_rs"]
+        type ChunkVisitor<'a>;
+
+        #[namespace = "jsi_rs"]
+        fn visit_ascii_chunk(visitor: &mut ChunkVisitor<'_>, data: &[u8]);
+
+        #[namespace = "jsi_rs"]
+        fn visit_utf16_chunk(visitor: &mut ChunkVisitor<'_>, data: &[u16]);
+    }
+}
+
+fn call_host_function(
+    func: &HostFunction,
+    runtime: Pin<&mut ffi::JSIRuntime>,
+    this_val: UniquePtr<ffi::JSIValue>,
+    args: Pin<&mut ffi::ValueVec>,
+) -> crate::Result<UniquePtr<ffi::JSIValue>> {
+    crate::function::call_host_function(func, runtime, this_val, args)
+}
+
+fn visit_ascii_chunk(visitor: &mut ChunkVisitor<'_>, data: &[u8]) {
+    visitor.visit(crate::StringChunk::Ascii(data));
+}
+
+fn visit_utf16_chunk(visitor: &mut ChunkVisitor<'_>, data: &[u16]) {
+    visitor.visit(crate::StringChunk::Utf16(data));
 }
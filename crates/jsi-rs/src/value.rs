@@ -1,36 +1,102 @@
+use std::fmt;
+
+use crate::convert::type_error;
+use crate::handle::Handle;
+use crate::Result;
+
+/// Nesting depth up to which [`JSValue::to_debug_string`] expands objects and arrays
+const DEBUG_MAX_DEPTH: usize = 2;
+
+/// The type of a JavaScript value, see [`JSValue::kind`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueKind {
+    Undefined,
+    Null,
+    Bool,
+    Number,
+    String,
+    BigInt,
+    Symbol,
+    Object,
+}
+
+impl ValueKind {
+    /// Name of the type as reported by `typeof` (`null` is reported as "null")
+    pub fn name(self) -> &'static str {
+        match self {
+            ValueKind::Undefined => "undefined",
+            ValueKind::Null => "null",
+            ValueKind::Bool => "boolean",
+            ValueKind::Number => "number",
+            ValueKind::String => "string",
+            ValueKind::BigInt => "bigint",
+            ValueKind::Symbol => "symbol",
+            ValueKind::Object => "object",
+        }
+    }
+}
+
 /// Represents a JavaScript value that can hold any JS type (undefined, null, boolean, number, string, object, etc.)
 pub struct JSValue {
-    pub(crate) inner: cxx::UniquePtr<crate::sys::ffi::JSIValue>,
+    pub(crate) inner: Handle<crate::sys::ffi::JSIValue>,
 }
 
 impl JSValue {
     /// Create an undefined JavaScript value
     pub fn undefined() -> Self {
         let ptr = crate::sys::ffi::create_value_undefined();
-        Self { inner: ptr }
+        Self {
+            inner: Handle::detached(ptr),
+        }
     }
 
     /// Create a null JavaScript value
     pub fn null() -> Self {
         let ptr = crate::sys::ffi::create_value_null();
-        Self { inner: ptr }
+        Self {
+            inner: Handle::detached(ptr),
+        }
     }
 
     /// Create a boolean JavaScript value
     pub fn bool(value: bool) -> Self {
         let ptr = crate::sys::ffi::create_value_bool(value);
-        Self { inner: ptr }
+        Self {
+            inner: Handle::detached(ptr),
+        }
     }
 
     /// Create a number JavaScript value
     pub fn number(value: f64) -> Self {
         let ptr = crate::sys::ffi::create_value_number(value);
-        Self { inner: ptr }
+        Self {
+            inner: Handle::detached(ptr),
+        }
     }
 
+    /// Take ownership of a heap allocated `facebook::jsi::Value`
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must come from `std::unique_ptr<facebook::jsi::Value>::release` (or be null)
+    /// and must not be owned by anything else.
+    ///
+    /// The value is not tied to a runtime, so it is not checked against the runtime it is
+    /// used with and must be dropped before its runtime is destroyed.
     pub unsafe fn from_raw(ptr: *mut crate::sys::ffi::JSIValue) -> Self {
         Self {
-            inner: cxx::UniquePtr::from_raw(ptr),
+            inner: Handle::detached(cxx::UniquePtr::from_raw(ptr)),
+        }
+    }
+
+    /// Take ownership of a value returned by `runtime`'s C++ API
+    #[cfg(feature = "unsafe")]
+    pub fn from_unique_ptr(
+        runtime: &crate::JSRuntime<'_>,
+        inner: cxx::UniquePtr<crate::sys::ffi::JSIValue>,
+    ) -> Self {
+        Self {
+            inner: runtime.handle(inner),
         }
     }
 
@@ -76,42 +142,266 @@ impl JSValue {
         self.as_ref().isBigInt()
     }
 
+    pub fn is_symbol(&self) -> bool {
+        self.as_ref().isSymbol()
+    }
+
+    /// Type of the value, for exhaustive matching
+    pub fn kind(&self) -> ValueKind {
+        if self.is_undefined() {
+            ValueKind::Undefined
+        } else if self.is_null() {
+            ValueKind::Null
+        } else if self.is_bool() {
+            ValueKind::Bool
+        } else if self.is_number() {
+            ValueKind::Number
+        } else if self.is_string() {
+            ValueKind::String
+        } else if self.is_bigint() {
+            ValueKind::BigInt
+        } else if self.is_symbol() {
+            ValueKind::Symbol
+        } else {
+            ValueKind::Object
+        }
+    }
+
+    pub(crate) fn type_name(&self) -> &'static str {
+        self.kind().name()
+    }
+
+    /// Returns the function, `None` if the value is not a callable object
     pub fn as_function(&self, runtime: &mut crate::JSRuntime<'_>) -> Option<crate::JSFunction> {
+        self.inner.check(runtime);
+        self.as_object(runtime)?.as_function(runtime)
+    }
+
+    /// Returns the boolean, `None` if the value is not a boolean
+    pub fn as_bool(&self) -> Option<bool> {
+        if !self.is_bool() {
+            return None;
+        }
+
+        Some(crate::sys::ffi::value_as_bool(self.inner()))
+    }
+
+    /// Returns the number, `None` if the value is not a number
+    pub fn as_number(&self) -> Option<f64> {
+        if !self.is_number() {
+            return None;
+        }
+
+        Some(crate::sys::ffi::value_as_number(self.inner()))
+    }
+
+    /// Like [`JSValue::as_bool`] but returns a type error for other values
+    pub fn try_as_bool(&self) -> Result<bool> {
+        self.as_bool().ok_or_else(|| type_error("boolean", self))
+    }
+
+    /// Like [`JSValue::as_number`] but returns a type error for other values
+    pub fn try_as_number(&self) -> Result<f64> {
+        self.as_number().ok_or_else(|| type_error("number", self))
+    }
+
+    /// Returns the object, `None` if the value is not an object
+    pub fn as_object(&self, runtime: &mut crate::JSRuntime<'_>) -> Option<crate::JSObject> {
+        self.inner.check(runtime);
         if !self.is_object() {
             return None;
         }
 
         let obj = crate::sys::ffi::value_as_object(runtime.pin_mut(), self.inner());
-        let func = crate::sys::ffi::object_as_function(runtime.pin_mut(), &obj);
 
-        Some(crate::JSFunction { inner: func })
+        Some(crate::JSObject {
+            inner: runtime.handle(obj),
+        })
     }
 
-    pub fn as_bool(&self) -> bool {
-        crate::sys::ffi::value_as_bool(self.inner())
+    /// Returns the array, `None` if the value is not an array
+    pub fn as_array(&self, runtime: &mut crate::JSRuntime<'_>) -> Option<crate::JSArray> {
+        self.inner.check(runtime);
+        self.as_object(runtime)?.as_array(runtime)
     }
 
-    pub fn as_number(&self) -> f64 {
-        crate::sys::ffi::value_as_number(self.inner())
+    /// Create another handle to the same value (jsi `Value(Runtime&, const Value&)`)
+    ///
+    /// Primitives are copied and objects are shared, like assigning a value in JavaScript.
+    pub fn clone_in(&self, runtime: &mut crate::JSRuntime<'_>) -> JSValue {
+        self.inner.check(runtime);
+        let inner = crate::sys::ffi::value_copy(runtime.pin_mut(), self.inner());
+        JSValue {
+            inner: runtime.handle(inner),
+        }
+    }
+
+    /// Compare two values with JavaScript `===` semantics
+    pub fn strict_equals(&self, runtime: &mut crate::JSRuntime<'_>, other: &JSValue) -> bool {
+        self.inner.check(runtime);
+        other.inner.check(runtime);
+        crate::sys::ffi::value_strict_equals(runtime.pin_mut(), self.inner(), other.inner())
+    }
+
+    /// Convert the value to a string with JavaScript `String(value)` semantics
+    ///
+    /// Fails when the conversion throws, e.g. from a user defined `toString`.
+    pub fn to_js_string(&self, runtime: &mut crate::JSRuntime<'_>) -> Result<crate::JSString> {
+        self.inner.check(runtime);
+        let str = crate::sys::ffi::value_to_string(runtime.pin_mut(), self.inner())?;
+        Ok(crate::JSString {
+            inner: runtime.handle(str),
+        })
+    }
+
+    /// Render the value for debugging, expanding the contents of objects and arrays
+    ///
+    /// Unlike the `Debug` implementation this reads strings and properties through the runtime.
+    pub fn to_debug_string(&self, runtime: &mut crate::JSRuntime<'_>) -> String {
+        self.inner.check(runtime);
+        let mut out = String::new();
+        self.write_debug(runtime, &mut out, 0);
+        out
+    }
+
+    fn write_debug(&self, runtime: &mut crate::JSRuntime<'_>, out: &mut String, depth: usize) {
+        match self.kind() {
+            ValueKind::String => {
+                let value = self.as_string(runtime).map(|s| s.value(runtime));
+                out.push_str(&format!("{:?}", value.unwrap_or_default()));
+            }
+            ValueKind::BigInt => {
+                self.write_js_string(runtime, out);
+                out.push('n');
+            }
+            ValueKind::Object => {
+                let obj = crate::sys::ffi::value_as_object(runtime.pin_mut(), self.inner());
+                let obj = crate::JSObject {
+                    inner: runtime.handle(obj),
+                };
+
+                if obj.is_function(runtime) {
+                    out.push_str("[Function]");
+                } else if let Some(array) = obj.as_array(runtime) {
+                    if depth >= DEBUG_MAX_DEPTH {
+                        out.push_str("[Array]");
+                        return;
+                    }
+
+                    out.push('[');
+                    for index in 0..array.len(runtime) {
+                        if index > 0 {
+                            out.push_str(", ");
+                        }
+                        array
+                            .get(runtime, index)
+                            .write_debug(runtime, out, depth + 1);
+                    }
+                    out.push(']');
+                } else {
+                    if depth >= DEBUG_MAX_DEPTH {
+                        out.push_str("[Object]");
+                        return;
+                    }
+
+                    let names = obj.get_property_names(runtime);
+                    let len = names.len(runtime);
+                    if len == 0 {
+                        out.push_str("{}");
+                        return;
+                    }
+
+                    out.push_str("{ ");
+                    for index in 0..len {
+                        if index > 0 {
+                            out.push_str(", ");
+                        }
+
+                        let name = names.get(runtime, index);
+                        name.write_js_string(runtime, out);
+                        out.push_str(": ");
+
+                        let key = name
+                            .to_js_string(runtime)
+                            .map(|s| s.value(runtime))
+                            .unwrap_or_default();
+                        obj.get(runtime, &key).write_debug(runtime, out, depth + 1);
+                    }
+                    out.push_str(" }");
+                }
+            }
+            _ => self.write_js_string(runtime, out),
+        }
+    }
+
+    fn write_js_string(&self, runtime: &mut crate::JSRuntime<'_>, out: &mut String) {
+        match self.to_js_string(runtime) {
+            Ok(string) => out.push_str(&string.value(runtime)),
+            Err(_) => out.push_str("<error>"),
+        }
+    }
+
+    /// Returns the `Map`, `None` if the value is not a `Map`
+    pub fn as_map(&self, runtime: &mut crate::JSRuntime<'_>) -> Option<crate::JSMap> {
+        self.inner.check(runtime);
+        let obj = self.as_object(runtime)?;
+        crate::JSMap::from_object(runtime, obj)
+    }
+
+    /// Returns the `Set`, `None` if the value is not a `Set`
+    pub fn as_set(&self, runtime: &mut crate::JSRuntime<'_>) -> Option<crate::JSSet> {
+        self.inner.check(runtime);
+        let obj = self.as_object(runtime)?;
+        crate::JSSet::from_object(runtime, obj)
+    }
+
+    /// Returns the `Date`, `None` if the value is not a `Date`
+    pub fn as_date(&self, runtime: &mut crate::JSRuntime<'_>) -> Option<crate::JSDate> {
+        self.inner.check(runtime);
+        let obj = self.as_object(runtime)?;
+        crate::JSDate::from_object(runtime, obj)
     }
 
     pub fn as_string(&self, runtime: &mut crate::JSRuntime<'_>) -> Option<crate::JSString> {
+        self.inner.check(runtime);
         if !self.is_string() {
             return None;
         }
 
         let str = crate::sys::ffi::value_as_string(runtime.pin_mut(), self.inner());
 
-        Some(crate::JSString { inner: str })
+        Some(crate::JSString {
+            inner: runtime.handle(str),
+        })
     }
 
     pub fn as_bigint(&self, runtime: &mut crate::JSRuntime<'_>) -> Option<crate::JSBigInt> {
+        self.inner.check(runtime);
         if !self.is_bigint() {
             return None;
         }
 
         let bigint = crate::sys::ffi::value_as_bigint(runtime.pin_mut(), self.inner());
 
-        Some(crate::JSBigInt { inner: bigint })
+        Some(crate::JSBigInt {
+            inner: runtime.handle(bigint),
+        })
+    }
+}
+
+/// Shows the type and primitive payload, use [`JSValue::to_debug_string`] for the full contents
+impl fmt::Debug for JSValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind() {
+            ValueKind::Bool => write!(f, "Bool({})", crate::sys::ffi::value_as_bool(self.inner())),
+            ValueKind::Number => {
+                write!(
+                    f,
+                    "Number({})",
+                    crate::sys::ffi::value_as_number(self.inner())
+                )
+            }
+            kind => write!(f, "{:?}", kind),
+        }
     }
 }